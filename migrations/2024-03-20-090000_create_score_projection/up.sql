@@ -0,0 +1,18 @@
+CREATE TABLE scores (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    metric_id BINARY(16),
+    metric_key TEXT NOT NULL,
+    metric_name TEXT,
+    vm_name TEXT NOT NULL,
+    role TINYTEXT,
+    value DECIMAL(30, 17) NOT NULL,
+    timestamp TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id),
+    INDEX scores_deployment_timestamp (deployment_id, timestamp)
+);
@@ -0,0 +1,3 @@
+ALTER TABLE exercises
+ADD COLUMN hide_leaderboard_names TINYINT(1) NOT NULL DEFAULT 0
+AFTER group_name;
@@ -0,0 +1,15 @@
+CREATE TABLE scoring_strategies (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    strategy TINYTEXT NOT NULL,
+    bonus_score INT UNSIGNED,
+    decay_per_minute DECIMAL(30, 17),
+    minimum_ratio DECIMAL(30, 17),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, metric_key, deleted_at)
+);
@@ -0,0 +1,2 @@
+ALTER TABLE scores DROP COLUMN reason;
+DROP TABLE penalties;
@@ -0,0 +1,18 @@
+CREATE TABLE penalties (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    entity_selector TEXT NOT NULL,
+    value DECIMAL(30, 17) NOT NULL,
+    reason TEXT NOT NULL,
+    issued_by TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id)
+);
+
+ALTER TABLE scores
+ADD COLUMN reason TEXT
+AFTER value;
@@ -0,0 +1,13 @@
+CREATE TABLE metric_gradings (
+    id BINARY(16) NOT NULL,
+    metric_id BINARY(16) NOT NULL,
+    grader_id TEXT NOT NULL,
+    previous_score INT UNSIGNED,
+    score INT UNSIGNED NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (metric_id) REFERENCES metrics(id)
+);
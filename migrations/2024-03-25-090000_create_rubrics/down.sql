@@ -0,0 +1,4 @@
+DROP TABLE rubric_grades;
+DROP TABLE rubric_levels;
+DROP TABLE rubric_criteria;
+DROP TABLE rubrics;
@@ -0,0 +1,50 @@
+CREATE TABLE rubrics (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    name TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, metric_key, deleted_at)
+);
+
+CREATE TABLE rubric_criteria (
+    id BINARY(16) NOT NULL,
+    rubric_id BINARY(16) NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    weight DECIMAL(30, 17) NOT NULL,
+    position INT UNSIGNED NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (rubric_id) REFERENCES rubrics(id) ON DELETE CASCADE
+);
+
+CREATE TABLE rubric_levels (
+    id BINARY(16) NOT NULL,
+    rubric_criterion_id BINARY(16) NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    value DECIMAL(30, 17) NOT NULL,
+    position INT UNSIGNED NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (rubric_criterion_id) REFERENCES rubric_criteria(id) ON DELETE CASCADE
+);
+
+CREATE TABLE rubric_grades (
+    id BINARY(16) NOT NULL,
+    metric_id BINARY(16) NOT NULL,
+    rubric_criterion_id BINARY(16) NOT NULL,
+    rubric_level_id BINARY(16) NOT NULL,
+    grader_id TEXT NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (metric_id) REFERENCES metrics(id),
+    FOREIGN KEY (rubric_criterion_id) REFERENCES rubric_criteria(id),
+    FOREIGN KEY (rubric_level_id) REFERENCES rubric_levels(id)
+);
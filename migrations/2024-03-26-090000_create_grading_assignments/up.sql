@@ -0,0 +1,18 @@
+CREATE TABLE grading_assignments (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    metric_id BINARY(16) NOT NULL,
+    grader_id TEXT NOT NULL,
+    disagreement_threshold INT UNSIGNED,
+    score INT UNSIGNED,
+    comment TEXT,
+    graded_at TIMESTAMP NULL DEFAULT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id),
+    FOREIGN KEY (metric_id) REFERENCES metrics(id),
+    UNIQUE (metric_id, grader_id, deleted_at)
+);
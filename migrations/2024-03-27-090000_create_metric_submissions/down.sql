@@ -0,0 +1,2 @@
+ALTER TABLE exercises DROP COLUMN allow_late_resubmission;
+DROP TABLE metric_submissions;
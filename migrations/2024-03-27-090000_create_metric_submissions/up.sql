@@ -0,0 +1,18 @@
+CREATE TABLE metric_submissions (
+    id BINARY(16) NOT NULL,
+    metric_id BINARY(16) NOT NULL,
+    version INT UNSIGNED NOT NULL,
+    text_submission TEXT,
+    artifact_id BINARY(16),
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (metric_id) REFERENCES metrics(id),
+    FOREIGN KEY (artifact_id) REFERENCES artifacts(id),
+    UNIQUE (metric_id, version, deleted_at)
+);
+
+ALTER TABLE exercises
+ADD COLUMN allow_late_resubmission TINYINT(1) NOT NULL DEFAULT 0
+AFTER hide_leaderboard_names;
@@ -0,0 +1,14 @@
+CREATE TABLE submission_windows (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    opens_at TIMESTAMP NULL DEFAULT NULL,
+    closes_at TIMESTAMP NULL DEFAULT NULL,
+    event_name TINYTEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, metric_key, deleted_at)
+);
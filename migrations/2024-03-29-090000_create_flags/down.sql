@@ -0,0 +1,2 @@
+DROP TABLE flag_attempts;
+DROP TABLE flags;
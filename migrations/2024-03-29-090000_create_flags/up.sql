@@ -0,0 +1,30 @@
+CREATE TABLE flags (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    entity_selector TEXT,
+    answer_salt CHAR(32) NOT NULL,
+    answer_hash CHAR(64) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id)
+);
+
+CREATE TABLE flag_attempts (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    entity_selector TEXT NOT NULL,
+    submitter_id TEXT,
+    answer TEXT NOT NULL,
+    is_correct TINYINT(1) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id)
+);
@@ -0,0 +1,2 @@
+DROP TABLE deployment_secrets;
+DROP TABLE secret_definitions;
@@ -0,0 +1,28 @@
+CREATE TABLE secret_definitions (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    name TINYTEXT NOT NULL,
+    per_entity TINYINT(1) NOT NULL DEFAULT 0,
+    length INT UNSIGNED NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, name, deleted_at)
+);
+
+CREATE TABLE deployment_secrets (
+    id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    secret_definition_id BINARY(16) NOT NULL,
+    name TINYTEXT NOT NULL,
+    entity_selector TEXT,
+    value TEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id),
+    FOREIGN KEY (secret_definition_id) REFERENCES secret_definitions(id)
+);
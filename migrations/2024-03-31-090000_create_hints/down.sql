@@ -0,0 +1,2 @@
+DROP TABLE hint_releases;
+DROP TABLE hints;
@@ -0,0 +1,34 @@
+CREATE TABLE hints (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT,
+    training_objective_key TEXT,
+    title TINYTEXT NOT NULL,
+    content TEXT NOT NULL,
+    cost DECIMAL(30, 17),
+    release_type TINYTEXT NOT NULL,
+    release_after_minutes INT UNSIGNED,
+    event_name TINYTEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id)
+);
+
+CREATE TABLE hint_releases (
+    id BINARY(16) NOT NULL,
+    hint_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    entity_selector TEXT NOT NULL,
+    penalty_id BINARY(16),
+    requested_by TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (hint_id) REFERENCES hints(id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id),
+    FOREIGN KEY (penalty_id) REFERENCES penalties(id),
+    UNIQUE (hint_id, deployment_id, entity_selector, deleted_at)
+);
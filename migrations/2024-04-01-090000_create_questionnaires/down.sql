@@ -0,0 +1,3 @@
+DROP TABLE questionnaire_choices;
+DROP TABLE questionnaire_questions;
+DROP TABLE questionnaires;
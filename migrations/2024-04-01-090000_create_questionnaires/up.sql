@@ -0,0 +1,36 @@
+CREATE TABLE questionnaires (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    metric_key TEXT NOT NULL,
+    name TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, metric_key, deleted_at)
+);
+
+CREATE TABLE questionnaire_questions (
+    id BINARY(16) NOT NULL,
+    questionnaire_id BINARY(16) NOT NULL,
+    prompt TEXT NOT NULL,
+    question_type TINYTEXT NOT NULL,
+    expected_answer TEXT,
+    answer_matching TINYTEXT NOT NULL,
+    case_sensitive BOOLEAN NOT NULL DEFAULT FALSE,
+    points DECIMAL(30, 17) NOT NULL,
+    position INT UNSIGNED NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (questionnaire_id) REFERENCES questionnaires(id) ON DELETE CASCADE
+);
+
+CREATE TABLE questionnaire_choices (
+    id BINARY(16) NOT NULL,
+    questionnaire_question_id BINARY(16) NOT NULL,
+    text TEXT NOT NULL,
+    is_correct BOOLEAN NOT NULL DEFAULT FALSE,
+    position INT UNSIGNED NOT NULL,
+    PRIMARY KEY (id),
+    FOREIGN KEY (questionnaire_question_id) REFERENCES questionnaire_questions(id) ON DELETE CASCADE
+);
@@ -0,0 +1,34 @@
+CREATE TABLE certificate_templates (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    name TINYTEXT NOT NULL,
+    content MEDIUMTEXT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id)
+);
+
+CREATE TABLE certificates (
+    id BINARY(16) NOT NULL,
+    certificate_template_id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    deployment_id BINARY(16) NOT NULL,
+    entity_selector TEXT NOT NULL,
+    user_id TEXT,
+    recipient_name TINYTEXT NOT NULL,
+    recipient_email TINYTEXT,
+    exercise_name TINYTEXT NOT NULL,
+    training_objectives TEXT NOT NULL,
+    content MEDIUMTEXT NOT NULL,
+    signature TINYTEXT NOT NULL,
+    issued_at TIMESTAMP NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (certificate_template_id) REFERENCES certificate_templates(id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    FOREIGN KEY (deployment_id) REFERENCES deployments(id)
+);
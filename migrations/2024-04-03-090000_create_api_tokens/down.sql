@@ -0,0 +1,3 @@
+DROP TABLE api_token_audit_logs;
+DROP TABLE api_token_exercises;
+DROP TABLE api_tokens;
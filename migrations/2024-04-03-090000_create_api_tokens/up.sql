@@ -0,0 +1,35 @@
+CREATE TABLE api_tokens (
+    id BINARY(16) NOT NULL,
+    name TINYTEXT NOT NULL,
+    token_hash VARCHAR(64) NOT NULL,
+    roles TINYTEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    expires_at TIMESTAMP NULL DEFAULT NULL,
+    last_used_at TIMESTAMP NULL DEFAULT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    UNIQUE (token_hash)
+);
+
+CREATE TABLE api_token_exercises (
+    id BINARY(16) NOT NULL,
+    api_token_id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    FOREIGN KEY (api_token_id) REFERENCES api_tokens(id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id)
+);
+
+CREATE TABLE api_token_audit_logs (
+    id BINARY(16) NOT NULL,
+    api_token_id BINARY(16) NOT NULL,
+    method TINYTEXT NOT NULL,
+    path TEXT NOT NULL,
+    status_code SMALLINT UNSIGNED NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (id),
+    FOREIGN KEY (api_token_id) REFERENCES api_tokens(id)
+);
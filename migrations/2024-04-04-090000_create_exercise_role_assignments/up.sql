@@ -0,0 +1,13 @@
+CREATE TABLE exercise_role_assignments (
+    id BINARY(16) NOT NULL,
+    exercise_id BINARY(16) NOT NULL,
+    user_id VARCHAR(255) NOT NULL,
+    role TINYTEXT NOT NULL,
+    assigned_by TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP,
+    deleted_at TIMESTAMP NOT NULL DEFAULT '1970-01-01 00:00:01',
+    PRIMARY KEY (id),
+    FOREIGN KEY (exercise_id) REFERENCES exercises(id),
+    UNIQUE (exercise_id, user_id, deleted_at)
+);
@@ -0,0 +1,2 @@
+UPDATE flag_attempts SET answer = '' WHERE answer IS NULL;
+ALTER TABLE flag_attempts MODIFY answer TEXT NOT NULL;
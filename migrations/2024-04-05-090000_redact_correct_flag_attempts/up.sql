@@ -0,0 +1,2 @@
+ALTER TABLE flag_attempts MODIFY answer TEXT;
+UPDATE flag_attempts SET answer = NULL WHERE is_correct = 1;
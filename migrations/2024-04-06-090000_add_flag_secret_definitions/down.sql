@@ -0,0 +1,6 @@
+DELETE FROM flags WHERE secret_definition_id IS NOT NULL;
+ALTER TABLE flags
+    DROP FOREIGN KEY flags_secret_definition_id,
+    DROP COLUMN secret_definition_id,
+    MODIFY answer_salt CHAR(32) NOT NULL,
+    MODIFY answer_hash CHAR(64) NOT NULL;
@@ -0,0 +1,5 @@
+ALTER TABLE flags
+    ADD COLUMN secret_definition_id BINARY(16) AFTER entity_selector,
+    MODIFY answer_salt CHAR(32),
+    MODIFY answer_hash CHAR(64),
+    ADD CONSTRAINT flags_secret_definition_id FOREIGN KEY (secret_definition_id) REFERENCES secret_definitions(id);
@@ -0,0 +1,49 @@
+export type ScoreBucket = {
+  lowerBound: number;
+  upperBound: number;
+  count: number;
+};
+
+export type MetricScoreDistribution = {
+  metricKey: string;
+  metricName?: string;
+  maxScore?: number;
+  deploymentCount: number;
+  sampleCount: number;
+  min: number;
+  max: number;
+  mean: number;
+  median: number;
+  buckets: ScoreBucket[];
+};
+
+export type ConditionSuccessTime = {
+  conditionName: string;
+  observedCount: number;
+  successCount: number;
+  averageSecondsToSuccess?: number;
+};
+
+export type EventTriggerRate = {
+  eventName: string;
+  concludedCount: number;
+  triggeredCount: number;
+  successRate?: number;
+};
+
+export type TloFailureRate = {
+  tloKey: string;
+  tloName?: string;
+  evaluatedCount: number;
+  failedCount: number;
+  failureRate: number;
+};
+
+export type ExerciseAnalytics = {
+  exerciseId: string;
+  deploymentCount: number;
+  metricScoreDistributions: MetricScoreDistribution[];
+  conditionSuccessTimes: ConditionSuccessTime[];
+  eventTriggerRates: EventTriggerRate[];
+  mostFailedTlos: TloFailureRate[];
+};
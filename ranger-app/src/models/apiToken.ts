@@ -0,0 +1,32 @@
+export type ApiTokenRole = 'Admin' | 'Participant' | 'Client';
+
+export type NewApiToken = {
+  name: string;
+  roles: ApiTokenRole[];
+  exerciseIds?: string[];
+  expiresAt?: string;
+};
+
+export type ApiToken = {
+  id: string;
+  name: string;
+  roles: ApiTokenRole[];
+  exerciseIds: string[];
+  createdBy: string;
+  expiresAt?: string;
+  lastUsedAt?: string;
+  createdAt: string;
+};
+
+export type CreatedApiToken = {
+  token: string;
+} & ApiToken;
+
+export type ApiTokenAuditLog = {
+  id: string;
+  apiTokenId: string;
+  method: string;
+  path: string;
+  statusCode: number;
+  createdAt: string;
+};
@@ -0,0 +1,45 @@
+export type NewCertificateTemplate = {
+  name: string;
+  content: string;
+};
+
+export type CertificateTemplate = {
+  id: string;
+  exerciseId: string;
+  createdAt: string;
+  updatedAt: string;
+} & NewCertificateTemplate;
+
+export type IssueCertificates = {
+  certificateTemplateId: string;
+  entitySelectors?: string[];
+  perParticipant?: boolean;
+  sendEmail?: boolean;
+};
+
+export type Certificate = {
+  id: string;
+  certificateTemplateId: string;
+  exerciseId: string;
+  deploymentId: string;
+  entitySelector: string;
+  userId?: string;
+  recipientName: string;
+  recipientEmail?: string;
+  exerciseName: string;
+  trainingObjectives: string;
+  signature: string;
+  issuedAt: string;
+  createdAt: string;
+};
+
+export type CertificateVerification = {
+  id: string;
+  valid: boolean;
+  revoked: boolean;
+  recipientName: string;
+  entitySelector: string;
+  exerciseName: string;
+  trainingObjectives: string;
+  issuedAt: string;
+};
@@ -72,6 +72,33 @@ type DeploymentElement = {
   errorMessage?: string;
 };
 
+type NewSecretDefinition = {
+  name: string;
+  perEntity?: boolean;
+  length?: number;
+};
+
+type SecretDefinition = {
+  id: string;
+  exerciseId: string;
+  name: string;
+  perEntity: boolean;
+  length: number;
+  createdAt: string;
+  updatedAt: string;
+};
+
+type DeploymentSecret = {
+  id: string;
+  deploymentId: string;
+  secretDefinitionId: string;
+  name: string;
+  entitySelector?: string;
+  value: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
 export type {
   ParticipantDeployment,
   NewDeployment,
@@ -80,4 +107,13 @@ export type {
   Deployers,
   DeploymentForm,
   DefaultDeployer,
+  NewSecretDefinition,
+  SecretDefinition,
+  DeploymentSecret,
 };
+
+export enum ReportFormat {
+  Html = 'html',
+  Markdown = 'markdown',
+  Pdf = 'pdf',
+}
@@ -10,6 +10,8 @@ type NewExercise = {
   deploymentGroup: string;
   sdlSchema?: string;
   groupName?: string;
+  hideLeaderboardNames?: boolean;
+  allowLateResubmission?: boolean;
 };
 
 type Exercise = {
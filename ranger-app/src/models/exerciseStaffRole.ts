@@ -0,0 +1,18 @@
+export enum ExerciseStaffRole {
+  Observer = 'observer',
+  WhiteTeam = 'whiteTeam',
+  Grader = 'grader',
+}
+
+export type NewExerciseRoleAssignment = {
+  userId: string;
+  role: ExerciseStaffRole;
+};
+
+export type ExerciseRoleAssignment = {
+  id: string;
+  exerciseId: string;
+  assignedBy?: string;
+  createdAt: string;
+  updatedAt: string;
+} & NewExerciseRoleAssignment;
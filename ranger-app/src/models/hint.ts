@@ -0,0 +1,46 @@
+export enum HintReleaseType {
+  Request = 'request',
+  Timer = 'timer',
+  Event = 'event',
+}
+
+export type NewHint = {
+  title: string;
+  content: string;
+  metricKey?: string;
+  trainingObjectiveKey?: string;
+  cost?: number;
+  releaseType: HintReleaseType;
+  releaseAfterMinutes?: number;
+  eventName?: string;
+};
+
+export type Hint = {
+  id: string;
+  exerciseId: string;
+  createdAt: string;
+  updatedAt: string;
+} & NewHint;
+
+export type HintRelease = {
+  id: string;
+  hintId: string;
+  deploymentId: string;
+  entitySelector: string;
+  penaltyId?: string;
+  requestedBy?: string;
+  createdAt: string;
+};
+
+export type ParticipantHint = {
+  id: string;
+  title: string;
+  metricKey?: string;
+  trainingObjectiveKey?: string;
+  cost?: number;
+  releaseType: HintReleaseType;
+  unlocksAt?: string;
+  isUnlocked: boolean;
+  content?: string;
+  takenAt?: string;
+};
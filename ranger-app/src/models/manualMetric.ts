@@ -31,9 +31,266 @@ export type ManualMetric = {
 export type UpdateManualMetric = {
   textSubmission?: string;
   score?: number;
+  comment?: string;
+};
+
+export type ManualMetricGrading = {
+  id: string;
+  metricId: string;
+  graderId: string;
+  previousScore?: number;
+  score: number;
+  comment?: string;
+  createdAt: string;
+  updatedAt: string;
 };
 
 export type FetchArtifact = {
   filename?: string;
   url?: string;
 };
+
+export type RubricLevel = {
+  id: string;
+  name: string;
+  description?: string;
+  value: number;
+};
+
+export type RubricCriterion = {
+  id: string;
+  name: string;
+  description?: string;
+  weight: number;
+  levels: RubricLevel[];
+};
+
+export type Rubric = {
+  id: string;
+  metricKey: string;
+  name?: string;
+  criteria: RubricCriterion[];
+};
+
+export type RubricGradeFeedback = {
+  criterionId: string;
+  levelId: string;
+  comment?: string;
+};
+
+export type RubricFeedback = {
+  metricId: string;
+  rubric: Rubric;
+  grades: RubricGradeFeedback[];
+  score?: number;
+  maxScore: number;
+};
+
+export type GradingAssignment = {
+  id: string;
+  exerciseId: string;
+  deploymentId: string;
+  metricId: string;
+  graderId: string;
+  disagreementThreshold?: number;
+  score?: number;
+  comment?: string;
+  gradedAt?: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export enum GradingReconciliationStatus {
+  Pending = 'pending',
+  Reconciled = 'reconciled',
+  Disagreement = 'disagreement',
+}
+
+export type GradingReconciliation = {
+  metricId: string;
+  status: GradingReconciliationStatus;
+  score?: number;
+  assignments: GradingAssignment[];
+};
+
+export type AnonymisedSubmission = {
+  assignmentId: string;
+  metricKey: string;
+  name?: string;
+  description?: string;
+  textSubmission?: string;
+  hasArtifact: boolean;
+  maxScore: number;
+  score?: number;
+  comment?: string;
+  gradedAt?: string;
+  submittedAt: string;
+};
+
+export type MetricSubmission = {
+  id: string;
+  metricId: string;
+  version: number;
+  textSubmission?: string;
+  artifactId?: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export enum DiffLineKind {
+  Unchanged = 'unchanged',
+  Added = 'added',
+  Removed = 'removed',
+}
+
+export type DiffLine = {
+  kind: DiffLineKind;
+  content: string;
+};
+
+export type MetricSubmissionDiff = {
+  metricId: string;
+  fromVersion: number;
+  toVersion: number;
+  lines: DiffLine[];
+  artifactChanged: boolean;
+};
+
+export type NewSubmissionWindow = {
+  metricKey: string;
+  opensAt?: string;
+  closesAt?: string;
+  eventName?: string;
+};
+
+export type SubmissionWindow = {
+  id: string;
+  exerciseId: string;
+  createdAt: string;
+  updatedAt: string;
+} & NewSubmissionWindow;
+
+export type SubmissionPeriod = {
+  metricKey: string;
+  opensAt?: string;
+  closesAt: string;
+  eventName?: string;
+};
+
+export type NewFlag = {
+  metricKey: string;
+  entitySelector?: string;
+  secretDefinitionId?: string;
+  answer?: string;
+};
+
+export type Flag = {
+  id: string;
+  exerciseId: string;
+  metricKey: string;
+  entitySelector?: string;
+  secretDefinitionId?: string;
+  createdAt: string;
+  updatedAt: string;
+};
+
+export type FlagSubmission = {
+  metricKey: string;
+  role: string;
+  answer: string;
+};
+
+export type FlagSubmissionResult = {
+  isCorrect: boolean;
+  metric?: ManualMetric;
+};
+
+export type FlagAttempt = {
+  id: string;
+  exerciseId: string;
+  deploymentId: string;
+  metricKey: string;
+  entitySelector: string;
+  submitterId?: string;
+  answer?: string;
+  isCorrect: boolean;
+  createdAt: string;
+};
+
+export enum QuestionType {
+  SingleChoice = 'singleChoice',
+  MultipleChoice = 'multipleChoice',
+  ShortAnswer = 'shortAnswer',
+}
+
+export enum AnswerMatching {
+  Exact = 'exact',
+  Regex = 'regex',
+}
+
+export type QuestionnaireChoice = {
+  id?: string;
+  text: string;
+  isCorrect: boolean;
+};
+
+export type QuestionnaireQuestion = {
+  id?: string;
+  prompt: string;
+  questionType: QuestionType;
+  choices: QuestionnaireChoice[];
+  expectedAnswer?: string;
+  answerMatching: AnswerMatching;
+  caseSensitive: boolean;
+  points: number;
+};
+
+export type Questionnaire = {
+  id?: string;
+  metricKey: string;
+  name?: string;
+  questions: QuestionnaireQuestion[];
+};
+
+export type ParticipantQuestionnaireChoice = {
+  id: string;
+  text: string;
+};
+
+export type ParticipantQuestionnaireQuestion = {
+  id: string;
+  prompt: string;
+  questionType: QuestionType;
+  choices: ParticipantQuestionnaireChoice[];
+  points: number;
+};
+
+export type ParticipantQuestionnaire = {
+  id: string;
+  metricKey: string;
+  name?: string;
+  questions: ParticipantQuestionnaireQuestion[];
+};
+
+export type QuestionAnswer = {
+  questionId: string;
+  choiceIds: string[];
+  text?: string;
+};
+
+export type QuestionnaireSubmission = {
+  metricKey: string;
+  role: string;
+  answers: QuestionAnswer[];
+};
+
+export type QuestionResult = {
+  questionId: string;
+  isCorrect: boolean;
+  points: number;
+};
+
+export type QuestionnaireSubmissionResult = {
+  metric: ManualMetric;
+  results: QuestionResult[];
+};
@@ -5,6 +5,7 @@ export type Score = {
   metricName?: string;
   metricKey: string;
   value: number;
+  reason?: string;
   timestamp: string;
   vmName: string;
 };
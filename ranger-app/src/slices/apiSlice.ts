@@ -556,6 +556,15 @@ export const apiSlice = createApi({
         return `/admin/exercise/${exerciseId}/deployment/${deploymentId}/event/${eventInfoDataChecksum}`;
       },
     }),
+    adminTriggerEvent: builder.mutation<DeploymentEvent,
+    {exerciseId: string; deploymentId: string; eventId: string}>({
+      query: ({exerciseId, deploymentId, eventId}) => ({
+        url: `/admin/exercise/${exerciseId}/deployment/${deploymentId}/event/${eventId}/trigger`,
+        method: 'POST',
+      }),
+      invalidatesTags: (result, error, {eventId}) =>
+        [{type: 'DeploymentEvent', id: eventId}],
+    }),
     adminGetDeputyPackages: builder.query<Package[], string>({
       query: packageType => `/admin/query/package?type=${packageType}`,
     }),
@@ -871,6 +880,7 @@ export const {
   useAdminDeleteEmailTemplateMutation,
   useAdminGetEventsQuery,
   useAdminGetEventInfoQuery,
+  useAdminTriggerEventMutation,
   useAdminGetDeputyPackagesQuery,
   useAdminGetExerciseSdlFromPackageQuery,
   useAdminGetBannerContentFromPackageQuery,
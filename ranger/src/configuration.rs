@@ -1,4 +1,4 @@
-use crate::constants::default_deployment_group_name;
+use crate::constants::{default_deployment_group_name, default_role_claim_path};
 use anyhow::{Error, Result};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, fs::read_to_string};
@@ -10,13 +10,54 @@ fn deployment_group_name() -> String {
     default_deployment_group_name().to_string()
 }
 
+fn role_claim_path() -> String {
+    default_role_claim_path().to_string()
+}
+
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 pub struct KeycloakConfiguration {
-    pub authentication_pem_content: String,
     pub base_url: String,
     pub realm: String,
     pub client_id: String,
     pub client_secret: String,
+    pub issuer_url: Option<String>,
+    pub audience: String,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct KeycloakAdminConfiguration {
+    pub base_url: String,
+    pub realm: String,
+    pub client_id: String,
+    pub client_secret: String,
+}
+
+impl From<&KeycloakConfiguration> for KeycloakAdminConfiguration {
+    fn from(keycloak_configuration: &KeycloakConfiguration) -> Self {
+        Self {
+            base_url: keycloak_configuration.base_url.clone(),
+            realm: keycloak_configuration.realm.clone(),
+            client_id: keycloak_configuration.client_id.clone(),
+            client_secret: keycloak_configuration.client_secret.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GroupAdapterConfiguration {
+    Keycloak(KeycloakAdminConfiguration),
+}
+
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct OidcConfiguration {
+    pub issuer_url: String,
+    pub client_id: String,
+    pub audience: Option<String>,
+    #[serde(default = "role_claim_path")]
+    pub role_claim_path: String,
+    pub groups_claim_path: Option<String>,
+    pub group_adapter: Option<GroupAdapterConfiguration>,
 }
 
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
@@ -29,9 +70,12 @@ pub struct Configuration {
     pub deployment_groups: DeploymentGroupMap,
     pub database_url: String,
     pub file_storage_path: String,
-    pub keycloak: KeycloakConfiguration,
+    pub keycloak: Option<KeycloakConfiguration>,
+    pub oidc: Option<OidcConfiguration>,
     pub mailer_configuration: Option<MailerConfiguration>,
     pub logger: Option<String>,
+    pub pdf_renderer: Option<PdfRendererConfiguration>,
+    pub certificate_signing_key: Option<String>,
 }
 
 pub fn read_configuration(arguments: Vec<String>) -> Result<Configuration> {
@@ -51,6 +95,13 @@ pub struct MailerConfiguration {
     pub from_address: String,
 }
 
+#[derive(Debug, Default, Serialize, Deserialize, Clone)]
+pub struct PdfRendererConfiguration {
+    pub command: String,
+    #[serde(default)]
+    pub arguments: Vec<String>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::configuration::Configuration;
@@ -89,7 +140,7 @@ mod tests {
                     realm: OCR
                     client_id: exercise-client
                     client_secret: oSeKTkRNcabbj6cc4PlwpEcRoshWYC8y
-                    authentication_pem_content: MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEAuZ93I6qDRnVOENLIsunn4vfExdSEPOy0nEE9gWl/bx6pvug+izqsWfx9XDMRQN/A5AfiSemQ2TvJQch+IZxo46me3Ft3fcfutgiCZTVTT1P5UxP/EBHMl2ifHLmXxBvjq8cJs6E3cXiQgM1tJIwi1SBoL0p8XiJZbho+sNlm0grroqZxpvZ0T4puWs6toFZFU2J6gTtw8NdQhWgxGAx34CWxLJgL5nz0DFPJFF0IAwnYN1QBJhObJMQzBsS4lx05PEGbDN5b+TMAwuf4OMkkauo0OdylOzRId1TIVvrX+cY30U/NOMFxbNMrBz3H9qZJxHdTtNfwp4lEsVfDmodfLwIDAQAB
+                    audience: exercise-client
                 logger: ranger.log
                 "#
         )?;
@@ -129,7 +180,7 @@ mod tests {
             realm: OCR
             client_id: exercise-client
             client_secret: oSeKTkRNcabbj6cc4PlwpEcRoshWYC8y
-            authentication_pem_content: MIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEAuZ93I6qDRnVOENLIsunn4vfExdSEPOy0nEE9gWl/bx6pvug+izqsWfx9XDMRQN/A5AfiSemQ2TvJQch+IZxo46me3Ft3fcfutgiCZTVTT1P5UxP/EBHMl2ifHLmXxBvjq8cJs6E3cXiQgM1tJIwi1SBoL0p8XiJZbho+sNlm0grroqZxpvZ0T4puWs6toFZFU2J6gTtw8NdQhWgxGAx34CWxLJgL5nz0DFPJFF0IAwnYN1QBJhObJMQzBsS4lx05PEGbDN5b+TMAwuf4OMkkauo0OdylOzRId1TIVvrX+cY30U/NOMFxbNMrBz3H9qZJxHdTtNfwp4lEsVfDmodfLwIDAQAB
+            audience: exercise-client
         logger: ranger.log
         "#;
         serde_yaml::from_str::<Configuration>(sdl).unwrap();
@@ -7,11 +7,16 @@ pub const PROJECT_NAME: &str = env!("CARGO_PKG_NAME");
 const DEFAULT_DEPLOYER_GROUP_NAME: &str = "default";
 pub const DEFAULT_LOGGER_ENV_KEY: &str = "RUST_LOG";
 pub const DEFAULT_LOGGER_LEVEL: &str = "INFO";
+const DEFAULT_ROLE_CLAIM_PATH: &str = "realm_access.roles";
 
 pub const fn default_deployment_group_name() -> &'static str {
     DEFAULT_DEPLOYER_GROUP_NAME
 }
 
+pub const fn default_role_claim_path() -> &'static str {
+    DEFAULT_ROLE_CLAIM_PATH
+}
+
 pub const MAX_DEPLOYMENT_NAME_LENGTH: usize = 20;
 pub const MAX_EXERCISE_NAME_LENGTH: usize = 20;
 pub const MAX_ORDER_NAME_LENGTH: usize = 20;
@@ -26,6 +31,28 @@ pub const NAIVEDATETIME_DEFAULT_STRING: &str = "1970-01-01 00:00:01";
 pub const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
 
 pub const EVENT_POLLER_RETRY_DURATION: Duration = Duration::from_secs(3);
+pub const CONDITION_STREAM_SILENCE_THRESHOLD: Duration = Duration::from_secs(60);
+pub const CONDITION_STREAM_SILENCE_INTERVAL_MULTIPLIER: u32 = 3;
+pub const CONDITION_STREAM_RECONNECT_RETRY_DURATION: Duration = Duration::from_secs(10);
+pub const CONDITION_STREAM_CLOSED_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+pub const JSON_WEB_KEY_SET_MINIMUM_REFRESH_INTERVAL: Duration = Duration::from_secs(30);
+pub const JSON_WEB_KEY_SET_MAXIMUM_AGE: Duration = Duration::from_secs(3600);
+pub const JSON_WEB_KEY_SET_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+pub const PDF_RENDERER_TIMEOUT: Duration = Duration::from_secs(60);
+pub const CONDITION_DIAGNOSTICS_DEFAULT_RESOLUTION_SECONDS: i64 = 60;
+pub const SCORE_HISTORY_DEFAULT_RESOLUTION_SECONDS: i64 = 60;
+pub const SCORE_HISTORY_MAX_POINTS: i64 = 10_000;
+pub const PENALTY_METRIC_KEY: &str = "penalty";
+pub const FLAG_GRADER_ID: &str = "flag";
+pub const FLAG_ATTEMPT_LIMIT: i64 = 10;
+pub const FLAG_ATTEMPT_WINDOW_SECONDS: i64 = 60;
+pub const QUESTIONNAIRE_GRADER_ID: &str = "questionnaire";
+pub const DEFAULT_SECRET_LENGTH: u32 = 24;
+pub const API_TOKEN_PREFIX: &str = "ranger_";
+pub const API_TOKEN_SECRET_LENGTH: usize = 40;
+pub const MAX_SECRET_LENGTH: u32 = 256;
+pub const ANALYTICS_SCORE_BUCKET_COUNT: usize = 10;
+pub const MAX_DIFF_TABLE_CELLS: usize = 4_000_000;
 
 pub const OCTET_STREAM_MIME_TYPE: &str = "application/octet-stream";
 
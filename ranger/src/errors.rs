@@ -51,6 +51,12 @@ pub enum RangerError {
     EmailSendingFailed,
     #[error("Keycloak query failed")]
     KeycloakQueryFailed,
+    #[error("Identity provider is not configured")]
+    IdentityProviderNotConfigured,
+    #[error("Identity provider query failed")]
+    IdentityProviderQueryFailed,
+    #[error("Identity provider does not support group listing")]
+    GroupListingNotSupported,
     #[error("User info missing")]
     UserInfoMissing,
     #[error("Application cannot access necessary configuration")]
@@ -75,18 +81,40 @@ pub enum RangerError {
     FileDeletionFailed,
     #[error("Metric has already been scored")]
     MetricAlreadyScored,
+    #[error("Resubmission is not allowed after the deadline")]
+    ResubmissionNotAllowed,
+    #[error("Submission window has not opened yet")]
+    SubmissionWindowNotOpen,
+    #[error("Submission window has closed")]
+    SubmissionWindowClosed,
+    #[error("Too many flag attempts, try again later")]
+    FlagAttemptLimitReached,
+    #[error("Hint has not been released yet")]
+    HintLocked,
+    #[error("Event is not awaiting a trigger")]
+    EventNotActive,
+    #[error("PDF renderer is not configured")]
+    PdfRendererNotConfigured,
+    #[error("Failed to generate the report")]
+    ReportGenerationFailed,
+    #[error("Certificate signing key is not configured")]
+    CertificateSigningKeyNotConfigured,
     #[error("Payload too large")]
     PayloadTooLarge,
     #[error("Unsupported media type")]
     UnsupportedMediaType,
     #[error("Missing query parameter \"{0}\"")]
     MissingParameter(String),
+    #[error("Invalid query parameter \"{0}\"")]
+    InvalidParameter(String),
     #[error("Failed to create Websocket connection")]
     WebsocketFailed,
     #[error("Failed to find package: {0}")]
     PackageCheckFailed(String),
     #[error("Deputy server error")]
     DeputyQueryFailed,
+    #[error("Failed to export data")]
+    ExportFailed,
 }
 
 impl ResponseError for RangerError {
@@ -113,9 +141,19 @@ impl ResponseError for RangerError {
             RangerError::TokenMissing => StatusCode::UNAUTHORIZED,
             RangerError::NotAuthorized => StatusCode::UNAUTHORIZED,
             RangerError::MetricAlreadyScored => StatusCode::FORBIDDEN,
+            RangerError::ResubmissionNotAllowed => StatusCode::FORBIDDEN,
+            RangerError::SubmissionWindowNotOpen => StatusCode::FORBIDDEN,
+            RangerError::SubmissionWindowClosed => StatusCode::FORBIDDEN,
+            RangerError::FlagAttemptLimitReached => StatusCode::TOO_MANY_REQUESTS,
+            RangerError::HintLocked => StatusCode::FORBIDDEN,
+            RangerError::EventNotActive => StatusCode::CONFLICT,
+            RangerError::PdfRendererNotConfigured => StatusCode::NOT_IMPLEMENTED,
+            RangerError::CertificateSigningKeyNotConfigured => StatusCode::NOT_IMPLEMENTED,
+            RangerError::GroupListingNotSupported => StatusCode::NOT_IMPLEMENTED,
             RangerError::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
             RangerError::UnsupportedMediaType => StatusCode::UNSUPPORTED_MEDIA_TYPE,
             RangerError::MissingParameter(_) => StatusCode::BAD_REQUEST,
+            RangerError::InvalidParameter(_) => StatusCode::BAD_REQUEST,
             RangerError::PackageCheckFailed(_) => StatusCode::NOT_FOUND,
             RangerError::FileNotFound => StatusCode::NOT_FOUND,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
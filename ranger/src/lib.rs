@@ -16,8 +16,10 @@ use configuration::{read_configuration, Configuration};
 use services::{
     deployer::DeployerFactory,
     deployment::{condition::ConditionAggregator, event::EventPoller, DeploymentManager},
+    identity::key_store::JsonWebKeyStore,
     websocket::WebSocketManager,
 };
+use utilities::score::backfill_score_projection;
 
 mod logger;
 
@@ -57,6 +59,7 @@ pub struct AppState {
     pub deployer_distributor_address: Addr<DeployerDistribution>,
     pub deployment_manager_address: Addr<DeploymentManager>,
     pub websocket_manager_address: Addr<WebSocketManager>,
+    pub json_web_key_store_address: Addr<JsonWebKeyStore>,
 }
 
 impl AppState {
@@ -76,6 +79,7 @@ impl AppState {
             .start(),
             configuration: configuration.to_owned(),
             websocket_manager_address: websocket_manager.clone(),
+            json_web_key_store_address: JsonWebKeyStore::new().start(),
         }
     }
 }
@@ -106,6 +110,7 @@ pub async fn app_setup(environment_arguments: Vec<String>) -> Result<(String, u1
     let logger_config = configuration.logger.as_deref().unwrap_or("ranger.log");
     let _ = logger::init(logger_config, websocket_manager.clone());
     let addressor = Addressor::try_new(deployer_distributor, database).await?;
+    backfill_score_projection(&addressor.database).await?;
     let app_state = AppState::new(&configuration, &addressor, &websocket_manager);
     Ok((configuration.host, configuration.port, app_state))
 }
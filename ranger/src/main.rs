@@ -4,23 +4,81 @@ use ranger::app_setup;
 use ranger::middleware::authentication::AuthenticationMiddlewareFactory;
 use ranger::middleware::deployment::DeploymentMiddlewareFactory;
 use ranger::middleware::exercise::ExerciseMiddlewareFactory;
-use ranger::middleware::keycloak::KeycloakAccessMiddlewareFactory;
+use ranger::middleware::identity::IdentityProviderMiddlewareFactory;
 use ranger::middleware::metric::MetricMiddlewareFactory;
 use ranger::middleware::order::OrderMiddlewareFactory;
 use ranger::middleware::participant_authentication::ParticipantAccessMiddlewareFactory;
 use ranger::roles::RangerRole;
+use ranger::routes::admin::analytics::get_admin_exercise_analytics;
+use ranger::routes::admin::api_token::{
+    add_admin_api_token, get_admin_api_token_audit_logs, get_admin_api_tokens,
+    revoke_admin_api_token,
+};
+use ranger::routes::admin::certificate::{
+    add_admin_certificate_template, delete_admin_certificate_template,
+    download_admin_certificate, get_admin_certificate_templates, get_admin_certificates,
+    issue_admin_certificates, revoke_admin_certificate,
+};
+use ranger::routes::admin::condition::get_admin_condition_diagnostics;
+use ranger::routes::admin::deployment_secret::{
+    add_admin_secret_definition, delete_admin_secret_definition, get_admin_deployment_secrets,
+    get_admin_secret_definitions,
+};
 use ranger::routes::admin::email::{
     add_email_template, delete_email, delete_email_template, get_email, get_email_form,
     get_email_template, get_email_templates, get_emails, send_email,
 };
-use ranger::routes::admin::event::{get_admin_event_info_data, get_exercise_deployment_events};
+use ranger::routes::admin::evaluation::get_admin_deployment_evaluation;
+use ranger::routes::admin::flag::{
+    add_admin_flag, delete_admin_flag, get_admin_deployment_flag_attempts, get_admin_flags,
+};
+use ranger::routes::admin::grading::{
+    assign_admin_metric_graders, download_admin_grading_assignment_artifact,
+    get_admin_deployment_grading_reconciliations, get_admin_grading_queue,
+    get_admin_metric_grading_assignments, submit_admin_grading_assignment,
+};
+use ranger::routes::admin::hint::{
+    add_admin_hint, delete_admin_hint, get_admin_deployment_hint_releases, get_admin_hints,
+};
+use ranger::routes::admin::event::{
+    get_admin_event_info_data, get_exercise_deployment_events, trigger_admin_event,
+};
+use ranger::routes::admin::exercise_role::{
+    add_admin_exercise_role, delete_admin_exercise_role, get_admin_exercise_roles,
+};
 use ranger::routes::admin::groups::get_participant_groups_users;
+use ranger::routes::admin::leaderboard::{
+    get_admin_deployment_leaderboard, get_admin_exercise_leaderboard,
+};
 use ranger::routes::admin::metric::{
-    delete_metric, download_metric_artifact, get_admin_metric, get_admin_metrics,
+    delete_metric, download_admin_metric_submission_artifact, download_metric_artifact,
+    get_admin_metric, get_admin_metric_gradings, get_admin_metric_submission_diff,
+    get_admin_metric_submissions, get_admin_metrics, revert_admin_metric_grading,
     update_admin_metric,
 };
 use ranger::routes::admin::order::{get_order_admin, get_orders_admin};
+use ranger::routes::admin::penalty::{
+    add_admin_penalty, delete_admin_penalty, get_admin_penalties,
+};
 use ranger::routes::admin::scenario::get_admin_exercise_deployment_scenario;
+use ranger::routes::admin::score::{
+    export_admin_deployment_scores, get_admin_deployment_score_history,
+};
+use ranger::routes::admin::questionnaire::{
+    add_admin_questionnaire, delete_admin_questionnaire, get_admin_questionnaires,
+};
+use ranger::routes::admin::report::get_admin_deployment_report;
+use ranger::routes::admin::rubric::{
+    add_admin_rubric, delete_admin_rubric, get_admin_metric_rubric_feedback, get_admin_rubrics,
+    grade_admin_metric_with_rubric,
+};
+use ranger::routes::admin::scoring_strategy::{
+    add_admin_scoring_strategy, delete_admin_scoring_strategy, get_admin_scoring_strategies,
+};
+use ranger::routes::admin::submission_window::{
+    add_admin_submission_window, delete_admin_submission_window, get_admin_submission_windows,
+};
+use ranger::routes::certificate::verify_certificate;
 use ranger::routes::deployers::{default_deployer, get_deployers};
 use ranger::routes::deputy_query::{
     check_package_exists, get_deputy_banner_file, get_deputy_packages_by_type,
@@ -32,7 +90,8 @@ use ranger::routes::exercise::{
     delete_exercise, delete_exercise_deployment, delete_participant, get_admin_participants,
     get_banner, get_exercise, get_exercise_deployment, get_exercise_deployment_elements,
     get_exercise_deployment_scores, get_exercise_deployment_users, get_exercise_deployments,
-    get_exercises, subscribe_to_exercise, update_banner, update_exercise,
+    get_exercises, rebuild_exercise_deployment_scores, subscribe_to_exercise, update_banner,
+    update_exercise,
 };
 use ranger::routes::logger::subscribe_to_logs_with_level;
 use ranger::routes::order::{create_order, get_order, update_order};
@@ -40,10 +99,18 @@ use ranger::routes::participant::deployment::{
     get_participant_deployment, get_participant_deployments,
     get_participant_node_deployment_elements, subscribe_participant_to_deployment,
 };
+use ranger::routes::participant::evaluation::get_participant_deployment_evaluation;
 use ranger::routes::participant::event_info::get_participant_event_info_data;
 use ranger::routes::participant::events::get_participant_events;
+use ranger::routes::participant::hint::{get_participant_hints, take_participant_hint};
+use ranger::routes::participant::leaderboard::{
+    get_participant_deployment_leaderboard, get_participant_exercise_leaderboard,
+};
 use ranger::routes::participant::metric::{
-    add_metric, get_participant_metric, get_participant_metrics, update_participant_metric,
+    add_metric, get_participant_metric, get_participant_metric_rubric_feedback,
+    get_participant_metric_submissions, get_participant_metrics,
+    get_participant_questionnaires, get_participant_submission_periods, submit_participant_flag,
+    submit_participant_questionnaire, update_participant_metric,
 };
 use ranger::routes::participant::participants::get_own_participants;
 use ranger::routes::participant::scenario::get_participant_exercise_deployment_scenario;
@@ -71,7 +138,8 @@ async fn main() -> Result<(), Error> {
             .service(version)
             .service(
                 scope("/api/v1")
-                    .wrap(KeycloakAccessMiddlewareFactory)
+                    .wrap(IdentityProviderMiddlewareFactory)
+                    .service(scope("/certificate").service(verify_certificate))
                     .service(
                         scope("/admin")
                         .service(
@@ -114,6 +182,62 @@ async fn main() -> Result<(), Error> {
                                             .service(update_exercise)
                                             .service(delete_exercise)
                                             .service(subscribe_to_exercise)
+                                            .service(get_admin_exercise_leaderboard)
+                                            .service(get_admin_exercise_analytics)
+                                            .service(
+                                                scope("/scoring-strategy")
+                                                    .service(get_admin_scoring_strategies)
+                                                    .service(add_admin_scoring_strategy)
+                                                    .service(delete_admin_scoring_strategy)
+                                            )
+                                            .service(
+                                                scope("/secret")
+                                                    .service(get_admin_secret_definitions)
+                                                    .service(add_admin_secret_definition)
+                                                    .service(delete_admin_secret_definition)
+                                            )
+                                            .service(
+                                                scope("/flag")
+                                                    .service(get_admin_flags)
+                                                    .service(add_admin_flag)
+                                                    .service(delete_admin_flag)
+                                            )
+                                            .service(
+                                                scope("/hint")
+                                                    .service(get_admin_hints)
+                                                    .service(add_admin_hint)
+                                                    .service(delete_admin_hint)
+                                            )
+                                            .service(
+                                                scope("/submission-window")
+                                                    .service(get_admin_submission_windows)
+                                                    .service(add_admin_submission_window)
+                                                    .service(delete_admin_submission_window)
+                                            )
+                                            .service(
+                                                scope("/questionnaire")
+                                                    .service(get_admin_questionnaires)
+                                                    .service(add_admin_questionnaire)
+                                                    .service(delete_admin_questionnaire)
+                                            )
+                                            .service(
+                                                scope("/certificate-template")
+                                                    .service(get_admin_certificate_templates)
+                                                    .service(add_admin_certificate_template)
+                                                    .service(delete_admin_certificate_template)
+                                            )
+                                            .service(
+                                                scope("/role")
+                                                    .service(get_admin_exercise_roles)
+                                                    .service(add_admin_exercise_role)
+                                                    .service(delete_admin_exercise_role)
+                                            )
+                                            .service(
+                                                scope("/rubric")
+                                                    .service(get_admin_rubrics)
+                                                    .service(add_admin_rubric)
+                                                    .service(delete_admin_rubric)
+                                            )
                                             .service(
                                                 scope("/deployment")
                                                     .service(get_exercise_deployments)
@@ -130,13 +254,49 @@ async fn main() -> Result<(), Error> {
                                                             .service(add_participant)
                                                             .service(delete_participant)
                                                             .service(get_exercise_deployment_scores)
+                                                            .service(
+                                                                rebuild_exercise_deployment_scores,
+                                                            )
+                                                            .service(get_admin_deployment_score_history)
+                                                            .service(export_admin_deployment_scores)
                                                             .service(
                                                                 get_admin_exercise_deployment_scenario,
                                                             )
                                                             .service(get_exercise_deployment_users)
+                                                            .service(get_admin_deployment_leaderboard)
+                                                            .service(get_admin_deployment_evaluation)
+                                                            .service(get_admin_deployment_grading_reconciliations)
+                                                            .service(get_admin_deployment_flag_attempts)
+                                                            .service(get_admin_deployment_secrets)
+                                                            .service(get_admin_deployment_hint_releases)
+                                                            .service(get_admin_deployment_report)
+                                                            .service(
+                                                                scope("/condition")
+                                                                    .service(get_admin_condition_diagnostics)
+                                                            )
+                                                            .service(
+                                                                scope("/penalty")
+                                                                    .service(get_admin_penalties)
+                                                                    .service(add_admin_penalty)
+                                                                    .service(delete_admin_penalty)
+                                                            )
+                                                            .service(
+                                                                scope("/certificate")
+                                                                    .service(get_admin_certificates)
+                                                                    .service(issue_admin_certificates)
+                                                                    .service(download_admin_certificate)
+                                                                    .service(revoke_admin_certificate)
+                                                            )
+                                                            .service(
+                                                                scope("/grading-queue")
+                                                                    .service(get_admin_grading_queue)
+                                                                    .service(submit_admin_grading_assignment)
+                                                                    .service(download_admin_grading_assignment_artifact)
+                                                            )
                                                             .service(
                                                                 scope("/event")
                                                                     .service(get_exercise_deployment_events)
+                                                                    .service(trigger_admin_event)
                                                                     .service(
                                                                         scope("/{event_info_id}")
                                                                             .service(get_admin_event_info_data)
@@ -152,6 +312,15 @@ async fn main() -> Result<(), Error> {
                                                                         .service(update_admin_metric)
                                                                         .service(delete_metric)
                                                                         .service(download_metric_artifact)
+                                                                        .service(get_admin_metric_gradings)
+                                                                        .service(revert_admin_metric_grading)
+                                                                        .service(get_admin_metric_rubric_feedback)
+                                                                        .service(grade_admin_metric_with_rubric)
+                                                                        .service(get_admin_metric_grading_assignments)
+                                                                        .service(assign_admin_metric_graders)
+                                                                        .service(get_admin_metric_submissions)
+                                                                        .service(get_admin_metric_submission_diff)
+                                                                        .service(download_admin_metric_submission_artifact)
                                                                     ),
                                                             ),
                                                     ),
@@ -181,6 +350,13 @@ async fn main() -> Result<(), Error> {
                                     .service(get_deployers)
                                     .service(default_deployer),
                             )
+                            .service(
+                                scope("/api-token")
+                                    .service(get_admin_api_tokens)
+                                    .service(add_admin_api_token)
+                                    .service(revoke_admin_api_token)
+                                    .service(get_admin_api_token_audit_logs),
+                            )
                             .service(
                                 scope("/group")
                                     .service(get_participant_groups)
@@ -241,6 +417,7 @@ async fn main() -> Result<(), Error> {
                                     .service(
                                         scope("/{exercise_uuid}")
                                             .service(get_participant_exercise)
+                                            .service(get_participant_exercise_leaderboard)
                                             .service(
                                                 scope("/deployment")
                                                     .service(get_participant_deployments)
@@ -262,6 +439,14 @@ async fn main() -> Result<(), Error> {
                                                                                 scope("/score")
                                                                                 .service(get_participant_exercise_deployment_scores)
                                                                             )
+                                                                            .service(
+                                                                                scope("/leaderboard")
+                                                                                .service(get_participant_deployment_leaderboard)
+                                                                            )
+                                                                            .service(
+                                                                                scope("/evaluation")
+                                                                                .service(get_participant_deployment_evaluation)
+                                                                            )
                                                                             .service(
                                                                                 scope("/event")
                                                                                 .service(get_participant_events)
@@ -279,14 +464,25 @@ async fn main() -> Result<(), Error> {
                                                                                 scope("/metric")
                                                                                 .service(get_participant_metrics)
                                                                                 .service(add_metric)
+                                                                                .service(get_participant_submission_periods)
+                                                                                .service(submit_participant_flag)
+                                                                                .service(get_participant_questionnaires)
+                                                                                .service(submit_participant_questionnaire)
                                                                                 .service(
                                                                                     scope("/{metric_uuid}")
                                                                                     .wrap(MetricMiddlewareFactory)
                                                                                         .service(get_participant_metric)
                                                                                         .service(update_participant_metric)
                                                                                         .service(upload_participant_artifact)
+                                                                                        .service(get_participant_metric_rubric_feedback)
+                                                                                        .service(get_participant_metric_submissions)
                                                                                     ),
                                                                             )
+                                                                            .service(
+                                                                                scope("/hint")
+                                                                                .service(get_participant_hints)
+                                                                                .service(take_participant_hint)
+                                                                            )
                                                                             .service(
                                                                                 scope("/websocket")
                                                                                 .service(subscribe_participant_to_deployment)
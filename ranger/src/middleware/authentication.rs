@@ -1,50 +1,121 @@
-use crate::{errors::RangerError, roles::RangerRole, AppState};
+use crate::{
+    constants::API_TOKEN_PREFIX,
+    errors::RangerError,
+    models::{hash_api_token, helpers::uuid::Uuid, ApiTokenRest, NewApiTokenAuditLog},
+    roles::{ExerciseStaffRole, RangerRole},
+    services::{
+        database::{
+            api_token::{CreateApiTokenAuditLog, GetApiTokenByHash},
+            exercise_role_assignment::GetExerciseRoleAssignmentByUser,
+        },
+        identity::IdentityProvider,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
 use actix_http::HttpMessage;
 use actix_web::{
     dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
-    error::ErrorUnauthorized,
     http::header::HeaderValue,
     web::Data,
     Error, FromRequest,
 };
+use chrono::Utc;
 use futures_util::future::LocalBoxFuture;
-use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use jsonwebtoken::{
+    errors::ErrorKind,
+    jwk::{AlgorithmParameters, EllipticCurve, Jwk},
+    Algorithm, DecodingKey, Validation,
+};
 use log::error;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::{
     future::{ready, Ready},
     rc::Rc,
 };
 
-#[derive(Debug, Serialize, Deserialize)]
-pub struct RealmAccess {
-    roles: Vec<String>,
-}
-
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Token {
     pub sub: String,
-    pub realm_access: RealmAccess,
     pub exp: u64,
     pub name: Option<String>,
     pub email: Option<String>,
+    #[serde(flatten)]
+    pub claims: Map<String, Value>,
+}
+
+fn get_key_algorithm(json_web_key: &Jwk) -> Option<Algorithm> {
+    let algorithm = match json_web_key.common.algorithm {
+        Some(algorithm) => algorithm,
+        None => match &json_web_key.algorithm {
+            AlgorithmParameters::RSA(_) => Algorithm::RS256,
+            AlgorithmParameters::EllipticCurve(parameters) => match parameters.curve {
+                EllipticCurve::P256 => Algorithm::ES256,
+                EllipticCurve::P384 => Algorithm::ES384,
+                _ => return None,
+            },
+            AlgorithmParameters::OctetKeyPair(_) => Algorithm::EdDSA,
+            AlgorithmParameters::OctetKey(_) => return None,
+        },
+    };
+
+    match algorithm {
+        Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512 => None,
+        algorithm => Some(algorithm),
+    }
 }
 
 impl Token {
-    pub async fn try_new(token: &str, pem: &str) -> Result<Self, Error> {
-        let pem_file = format!(
-            "-----BEGIN PUBLIC KEY-----
-    {pem}
-    -----END PUBLIC KEY-----"
-        );
-        let decoding_key = DecodingKey::from_rsa_pem(pem_file.as_bytes()).unwrap();
-        jsonwebtoken::decode::<Token>(token, &decoding_key, &Validation::new(Algorithm::RS256))
+    pub fn try_new(
+        token: &str,
+        json_web_key: &Jwk,
+        issuer: &str,
+        audience: Option<&str>,
+    ) -> Result<Self, RangerError> {
+        let decoding_key = DecodingKey::from_jwk(json_web_key).map_err(|error| {
+            error!("Failed to create decoding key from json web key: {error}");
+            RangerError::NotAuthorized
+        })?;
+        let algorithm = get_key_algorithm(json_web_key).ok_or_else(|| {
+            error!("Unsupported json web key algorithm");
+            RangerError::NotAuthorized
+        })?;
+        let mut validation = Validation::new(algorithm);
+        validation.set_issuer(&[issuer]);
+        if let Some(audience) = audience {
+            validation.set_audience(&[audience]);
+        }
+
+        jsonwebtoken::decode::<Token>(token, &decoding_key, &validation)
             .map(|data| data.claims)
-            .map_err(|e| {
-                log::error!("Failed to decode JWT: {}", e);
-                ErrorUnauthorized(e.to_string())
+            .map_err(|error| {
+                error!("Failed to decode JWT: {error}");
+                match error.kind() {
+                    ErrorKind::ExpiredSignature => RangerError::TokenExpired,
+                    _ => RangerError::NotAuthorized,
+                }
             })
     }
+
+    pub fn get_claim_values(&self, claim_path: &str) -> Vec<String> {
+        let mut path_segments = claim_path.split('.');
+        let claim = path_segments
+            .next()
+            .and_then(|first_segment| self.claims.get(first_segment));
+        let claim = path_segments.fold(claim, |claim, segment| {
+            claim.and_then(|claim| claim.get(segment))
+        });
+
+        match claim {
+            Some(Value::Array(values)) => values
+                .iter()
+                .filter_map(|value| value.as_str().map(str::to_string))
+                .collect(),
+            Some(Value::String(value)) => vec![value.clone()],
+            _ => vec![],
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -53,17 +124,139 @@ pub struct User {
     pub name: Option<String>,
     pub email: Option<String>,
     pub role: RangerRole,
+    pub groups: Vec<String>,
+    pub api_token_id: Option<Uuid>,
+    pub exercise_role: Option<ExerciseStaffRole>,
 }
 
-impl From<(Token, RangerRole)> for User {
-    fn from((token, ranger_role): (Token, RangerRole)) -> Self {
+impl User {
+    pub fn new(
+        token: Token,
+        ranger_role: RangerRole,
+        groups: Vec<String>,
+        exercise_role: Option<ExerciseStaffRole>,
+    ) -> Self {
         Self {
             id: token.sub,
             name: token.name,
             email: token.email,
             role: ranger_role,
+            groups,
+            api_token_id: None,
+            exercise_role,
         }
     }
+
+    pub fn from_api_token(api_token: ApiTokenRest, ranger_role: RangerRole) -> Self {
+        Self {
+            id: api_token.id.to_string(),
+            name: Some(api_token.name),
+            email: None,
+            role: ranger_role,
+            groups: vec![],
+            api_token_id: Some(api_token.id),
+            exercise_role: None,
+        }
+    }
+}
+
+fn get_path_exercise_id(path: &str) -> Option<Uuid> {
+    path.split('/')
+        .skip_while(|segment| *segment != "exercise")
+        .nth(1)
+        .and_then(|segment| Uuid::try_from(segment).ok())
+}
+
+async fn authenticate_api_token(
+    app_state: &AppState,
+    token: &str,
+    expected_role: RangerRole,
+    path: &str,
+) -> Result<User, RangerError> {
+    let api_token = app_state
+        .database_address
+        .send(GetApiTokenByHash(hash_api_token(token)))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get api token"))?
+        .ok_or_else(|| {
+            error!("Api token not found");
+            RangerError::NotAuthorized
+        })?;
+
+    if api_token.is_expired(Utc::now().naive_utc()) {
+        error!("Api token {} has expired", api_token.id);
+        return Err(RangerError::TokenExpired);
+    }
+    if !api_token.roles.contains(&expected_role) {
+        error!("Api token {} does not have required role", api_token.id);
+        return Err(RangerError::AccessForbidden);
+    }
+    if !api_token.allows_exercise(get_path_exercise_id(path)) {
+        error!("Api token {} is not scoped to {path}", api_token.id);
+        return Err(RangerError::AccessForbidden);
+    }
+
+    Ok(User::from_api_token(api_token, expected_role))
+}
+
+async fn get_exercise_role(
+    app_state: &AppState,
+    user_id: &str,
+    expected_role: RangerRole,
+    path: &str,
+) -> Result<ExerciseStaffRole, RangerError> {
+    let exercise_id = match expected_role {
+        RangerRole::Admin => get_path_exercise_id(path),
+        _ => None,
+    }
+    .ok_or_else(|| {
+        error!("User does not have required role");
+        RangerError::AccessForbidden
+    })?;
+    let exercise_role_assignment = app_state
+        .database_address
+        .send(GetExerciseRoleAssignmentByUser(
+            exercise_id,
+            user_id.to_string(),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get exercise role"))?
+        .ok_or_else(|| {
+            error!("User does not have required role or an exercise role");
+            RangerError::AccessForbidden
+        })?;
+
+    Ok(exercise_role_assignment.role)
+}
+
+async fn authenticate_json_web_token(
+    app_state: &AppState,
+    identity_provider: Option<Rc<dyn IdentityProvider>>,
+    token: &str,
+    expected_role: RangerRole,
+    path: &str,
+) -> Result<User, RangerError> {
+    let identity_provider = identity_provider.ok_or_else(|| {
+        error!("Identity provider not found");
+        RangerError::IdentityProviderNotConfigured
+    })?;
+    let token = identity_provider.validate_token(token).await?;
+
+    let exercise_role = match token
+        .get_claim_values(identity_provider.role_claim_path())
+        .contains(&expected_role.to_string())
+    {
+        true => None,
+        false => Some(get_exercise_role(app_state, &token.sub, expected_role, path).await?),
+    };
+    let groups = identity_provider
+        .groups_claim_path()
+        .map(|groups_claim_path| token.get_claim_values(groups_claim_path))
+        .unwrap_or_default();
+
+    Ok(User::new(token, expected_role, groups, exercise_role))
 }
 
 #[derive(Clone)]
@@ -136,19 +329,18 @@ where
     fn call(&self, req: ServiceRequest) -> Self::Future {
         let service = self.service.clone();
         let expected_role = self.expected_role;
+        let identity_provider = req.extensions().get::<Rc<dyn IdentityProvider>>().cloned();
         let app_state = req.app_data::<Data<AppState>>().cloned();
         let auth_header = req.headers().get("Authorization").cloned();
         let auth_header_ws = req.headers().get("Sec-WebSocket-Protocol").cloned();
+        let method = req.method().to_string();
+        let path = req.path().to_string();
 
         Box::pin(async move {
-            let keycloak_configuration = app_state
-                .ok_or_else(|| {
-                    error!("Keycloak configuration not found");
-                    RangerError::ConfigurationMissing
-                })?
-                .configuration
-                .keycloak
-                .clone();
+            let app_state = app_state.ok_or_else(|| {
+                error!("App state not found");
+                RangerError::AppStateMissing
+            })?;
             let token_string: HeaderValue = match auth_header {
                 Some(value) => Ok(value),
                 _ => match auth_header_ws.clone() {
@@ -163,26 +355,137 @@ where
                     RangerError::TokenMissing
                 })?
                 .replace("Bearer ", "");
-            let token = Token::try_new(
-                token_string.as_str(),
-                &keycloak_configuration.authentication_pem_content,
-            )
-            .await?;
-
-            if !token
-                .realm_access
-                .roles
-                .contains(&expected_role.to_string())
-            {
-                error!("User does not have required role");
-                return Err(RangerError::AccessForbidden.into());
-            }
+            let user = match token_string.starts_with(API_TOKEN_PREFIX) {
+                true => {
+                    authenticate_api_token(&app_state, &token_string, expected_role, &path).await?
+                }
+                false => {
+                    authenticate_json_web_token(
+                        &app_state,
+                        identity_provider,
+                        &token_string,
+                        expected_role,
+                        &path,
+                    )
+                    .await?
+                }
+            };
+            let api_token_id = user.api_token_id;
+
+            req.extensions_mut().insert::<Rc<User>>(Rc::new(user));
 
-            req.extensions_mut()
-                .insert::<Rc<User>>(Rc::new(User::from((token, expected_role))));
+            let result = service.call(req).await;
+            if let Some(api_token_id) = api_token_id {
+                let status = match &result {
+                    Ok(response) => response.status(),
+                    Err(error) => error.as_response_error().status_code(),
+                };
+                let new_audit_log =
+                    NewApiTokenAuditLog::new(api_token_id, method, path, status.as_u16());
+                match app_state
+                    .database_address
+                    .send(CreateApiTokenAuditLog(new_audit_log))
+                    .await
+                {
+                    Ok(Ok(())) => (),
+                    Ok(Err(error)) => error!("Failed to record api token audit log: {error}"),
+                    Err(error) => error!("Database actor mailbox error: {error}"),
+                }
+            }
 
-            let res = service.call(req).await?;
-            Ok(res)
+            result
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{get_key_algorithm, Token};
+    use jsonwebtoken::{jwk::Jwk, Algorithm};
+    use serde_json::json;
+
+    fn create_token(claims: serde_json::Value) -> Token {
+        serde_json::from_value(json!({
+            "sub": "user",
+            "exp": 0,
+            "realm_access": claims,
+        }))
+        .unwrap()
+    }
+
+    fn create_json_web_key(parameters: serde_json::Value) -> Jwk {
+        serde_json::from_value(parameters).unwrap()
+    }
+
+    #[test]
+    fn reads_nested_claim_arrays() {
+        let token = create_token(json!({ "roles": ["ranger-manager", "offline_access", 1] }));
+
+        assert_eq!(
+            token.get_claim_values("realm_access.roles"),
+            vec!["ranger-manager".to_string(), "offline_access".to_string()]
+        );
+    }
+
+    #[test]
+    fn reads_single_claim_strings() {
+        let token = create_token(json!({ "role": "ranger-participant" }));
+
+        assert_eq!(
+            token.get_claim_values("realm_access.role"),
+            vec!["ranger-participant".to_string()]
+        );
+    }
+
+    #[test]
+    fn missing_or_non_string_claims_are_empty() {
+        let token = create_token(json!({ "roles": { "nested": true } }));
+
+        assert!(token.get_claim_values("realm_access.roles").is_empty());
+        assert!(token.get_claim_values("realm_access.missing").is_empty());
+        assert!(token.get_claim_values("missing.roles").is_empty());
+    }
+
+    #[test]
+    fn key_algorithm_is_taken_from_the_json_web_key() {
+        let json_web_key = create_json_web_key(json!({
+            "kty": "RSA",
+            "alg": "PS256",
+            "n": "AQAB",
+            "e": "AQAB",
+        }));
+
+        assert_eq!(get_key_algorithm(&json_web_key), Some(Algorithm::PS256));
+    }
+
+    #[test]
+    fn key_algorithm_is_derived_from_the_key_type() {
+        let rsa_key = create_json_web_key(json!({ "kty": "RSA", "n": "AQAB", "e": "AQAB" }));
+        let elliptic_curve_key = create_json_web_key(json!({
+            "kty": "EC",
+            "crv": "P-384",
+            "x": "AQAB",
+            "y": "AQAB",
+        }));
+
+        assert_eq!(get_key_algorithm(&rsa_key), Some(Algorithm::RS256));
+        assert_eq!(
+            get_key_algorithm(&elliptic_curve_key),
+            Some(Algorithm::ES384)
+        );
+    }
+
+    #[test]
+    fn symmetric_keys_are_rejected() {
+        let octet_key = create_json_web_key(json!({ "kty": "oct", "k": "c2VjcmV0" }));
+        let hmac_key = create_json_web_key(json!({
+            "kty": "RSA",
+            "alg": "HS256",
+            "n": "AQAB",
+            "e": "AQAB",
+        }));
+
+        assert_eq!(get_key_algorithm(&octet_key), None);
+        assert_eq!(get_key_algorithm(&hmac_key), None);
+    }
+}
@@ -1,9 +1,9 @@
 use crate::{
     errors::RangerError,
-    middleware::{authentication::User, keycloak::KeycloakInfo},
+    middleware::authentication::User,
     models::{helpers::uuid::Uuid, Deployment},
     roles::RangerRole,
-    services::database::deployment::GetDeployment,
+    services::{database::deployment::GetDeployment, identity::IdentityProvider},
     utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
@@ -20,8 +20,6 @@ use std::{
     rc::Rc,
 };
 
-use super::keycloak::KeycloakAccess;
-
 pub struct DeploymentInfo(pub Deployment);
 
 impl DeploymentInfo {
@@ -96,7 +94,7 @@ where
         let service = self.service.clone();
         let user = req.extensions().get::<Rc<User>>().cloned();
         let app_state = req.app_data::<Data<AppState>>().cloned();
-        let keycloak_access = req.extensions().get::<Rc<KeycloakAccess>>().cloned();
+        let identity_provider = req.extensions().get::<Rc<dyn IdentityProvider>>().cloned();
 
         Box::pin(async move {
             let user = user.ok_or_else(|| {
@@ -107,10 +105,10 @@ where
                 error!("App state not found");
                 RangerError::AppStateMissing
             })?;
-            let keycloak_info = KeycloakInfo(keycloak_access.ok_or_else(|| {
-                error!("Keycloak access not found");
-                RangerError::KeycloakQueryFailed
-            })?);
+            let identity_provider = identity_provider.ok_or_else(|| {
+                error!("Identity provider not found");
+                RangerError::IdentityProviderNotConfigured
+            })?;
             let deployment_uuid =
                 Uuid::try_from(req.match_info().get("deployment_uuid").ok_or_else(|| {
                     error!("Deployment uuid not found");
@@ -133,10 +131,9 @@ where
                 RangerRole::Participant => {
                     let is_connected = deployment
                         .is_connected(
-                            user.id.clone(),
+                            &user,
                             &app_state.database_address,
-                            keycloak_info,
-                            app_state.configuration.keycloak.realm.clone(),
+                            identity_provider.as_ref(),
                         )
                         .await
                         .map_err(|err| {
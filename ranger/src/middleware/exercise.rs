@@ -3,7 +3,7 @@ use crate::{
     middleware::authentication::User,
     models::{helpers::uuid::Uuid, Exercise},
     roles::RangerRole,
-    services::database::exercise::GetExercise,
+    services::{database::exercise::GetExercise, identity::IdentityProvider},
     utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
@@ -20,8 +20,6 @@ use std::{
     rc::Rc,
 };
 
-use super::keycloak::{KeycloakAccess, KeycloakInfo};
-
 pub struct ExerciseInfo(pub Exercise);
 
 impl ExerciseInfo {
@@ -95,7 +93,7 @@ where
     fn call(&self, req: ServiceRequest) -> Self::Future {
         let service = self.service.clone();
         let user = req.extensions().get::<Rc<User>>().cloned();
-        let keycloak_access = req.extensions().get::<Rc<KeycloakAccess>>().cloned();
+        let identity_provider = req.extensions().get::<Rc<dyn IdentityProvider>>().cloned();
         let app_state = req.app_data::<Data<AppState>>().cloned();
 
         Box::pin(async move {
@@ -103,10 +101,10 @@ where
                 error!("User not found");
                 RangerError::UserInfoMissing
             })?;
-            let keycloak_info = KeycloakInfo(keycloak_access.ok_or_else(|| {
-                error!("Keycloak access not found");
-                RangerError::KeycloakQueryFailed
-            })?);
+            let identity_provider = identity_provider.ok_or_else(|| {
+                error!("Identity provider not found");
+                RangerError::IdentityProviderNotConfigured
+            })?;
             let app_state = app_state.ok_or_else(|| {
                 error!("App state not found");
                 RangerError::AppStateMissing
@@ -129,14 +127,25 @@ where
                 .map_err(create_database_error_handler("Get exercises"))?;
 
             let exercise = match user.role {
-                RangerRole::Admin => std::result::Result::Ok(exercise),
+                RangerRole::Admin => match user.exercise_role {
+                    Some(exercise_role) => {
+                        let route_pattern = req.match_pattern().unwrap_or_default();
+                        match exercise_role.permits(req.method(), &route_pattern) {
+                            true => std::result::Result::Ok(exercise),
+                            false => {
+                                debug!(
+                                    "Exercise role {exercise_role} does not permit {} {route_pattern}",
+                                    req.method()
+                                );
+                                Err(RangerError::AccessForbidden)
+                            }
+                        }
+                    }
+                    None => std::result::Result::Ok(exercise),
+                },
                 RangerRole::Participant => {
                     let is_member = exercise
-                        .is_member(
-                            user.id.clone(),
-                            keycloak_info,
-                            app_state.configuration.keycloak.realm.clone(),
-                        )
+                        .is_member(&user, identity_provider.as_ref())
                         .await
                         .map_err(|err| {
                             error!(
@@ -0,0 +1,101 @@
+use std::{
+    future::{ready, Ready},
+    rc::Rc,
+};
+
+use crate::{
+    errors::RangerError,
+    services::identity::{create_identity_provider, IdentityProvider},
+    AppState,
+};
+use actix_http::HttpMessage;
+use actix_web::{
+    dev::{forward_ready, Service, ServiceRequest, ServiceResponse, Transform},
+    web::Data,
+    Error, FromRequest,
+};
+use futures_util::future::LocalBoxFuture;
+use log::error;
+
+pub struct IdentityProviderInfo(pub Rc<dyn IdentityProvider>);
+
+impl FromRequest for IdentityProviderInfo {
+    type Error = Error;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(
+        req: &actix_web::HttpRequest,
+        _payload: &mut actix_web::dev::Payload,
+    ) -> Self::Future {
+        let value = req.extensions().get::<Rc<dyn IdentityProvider>>().cloned();
+        let result = match value {
+            Some(v) => Ok(IdentityProviderInfo(v)),
+            None => Err(RangerError::IdentityProviderNotConfigured.into()),
+        };
+        ready(result)
+    }
+}
+
+impl std::ops::Deref for IdentityProviderInfo {
+    type Target = Rc<dyn IdentityProvider>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+pub struct IdentityProviderMiddlewareFactory;
+
+impl<S, B> Transform<S, ServiceRequest> for IdentityProviderMiddlewareFactory
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = IdentityProviderMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(IdentityProviderMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+pub struct IdentityProviderMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for IdentityProviderMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let service = self.service.clone();
+        let app_state = req.app_data::<Data<AppState>>().cloned();
+
+        Box::pin(async move {
+            let app_state = app_state.ok_or_else(|| {
+                error!("App state not found");
+                RangerError::AppStateMissing
+            })?;
+            let identity_provider = create_identity_provider(&app_state).await?;
+            req.extensions_mut()
+                .insert::<Rc<dyn IdentityProvider>>(identity_provider);
+
+            let res = service.call(req).await?;
+            Ok(res)
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{
     errors::RangerError,
+    middleware::authentication::User,
     models::{helpers::uuid::Uuid, metric::Metric},
-    services::database::metric::GetMetric,
+    roles::RangerRole,
+    services::database::{grading_assignment::GetPendingGradingMetricIds, metric::GetMetric},
     utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
@@ -92,12 +94,17 @@ where
     fn call(&self, req: ServiceRequest) -> Self::Future {
         let service = self.service.clone();
         let app_state = req.app_data::<Data<AppState>>().cloned();
+        let user = req.extensions().get::<Rc<User>>().cloned();
 
         Box::pin(async move {
             let app_state = app_state.ok_or_else(|| {
                 error!("App state not found");
                 RangerError::AppStateMissing
             })?;
+            let user = user.ok_or_else(|| {
+                error!("User not found");
+                RangerError::UserInfoMissing
+            })?;
 
             let metric_uuid =
                 Uuid::try_from(req.match_info().get("metric_uuid").ok_or_else(|| {
@@ -115,6 +122,23 @@ where
                 .await
                 .map_err(create_mailbox_error_handler("Database"))?
                 .map_err(create_database_error_handler("Get Metric"))?;
+            if user.role == RangerRole::Admin {
+                let pending_metric_ids = app_state
+                    .database_address
+                    .send(GetPendingGradingMetricIds(
+                        manual_metric.deployment_id,
+                        user.id.clone(),
+                    ))
+                    .await
+                    .map_err(create_mailbox_error_handler("Database"))?
+                    .map_err(create_database_error_handler(
+                        "Get pending grading assignments",
+                    ))?;
+                if pending_metric_ids.contains(&manual_metric.id) {
+                    error!("Assigned grader must use the grading queue for this metric");
+                    return Err(RangerError::AccessForbidden.into());
+                }
+            }
 
             req.extensions_mut().insert(MetricInfo(manual_metric));
 
@@ -1,7 +1,7 @@
 pub mod authentication;
 pub mod deployment;
 pub mod exercise;
-pub mod keycloak;
+pub mod identity;
 pub mod metric;
 pub mod order;
 pub mod participant_authentication;
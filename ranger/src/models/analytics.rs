@@ -0,0 +1,350 @@
+use super::{helpers::uuid::Uuid, EntityEvaluation, Event, Score};
+use crate::constants::ANALYTICS_SCORE_BUCKET_COUNT;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalyticsMetric {
+    pub key: String,
+    pub name: Option<String>,
+    pub max_score: BigDecimal,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct DeploymentAnalyticsData {
+    pub deployment_id: Uuid,
+    pub deployment_start: NaiveDateTime,
+    pub metrics: Vec<AnalyticsMetric>,
+    pub latest_scores: Vec<Score>,
+    pub events: Vec<Event>,
+    pub evaluations: Vec<EntityEvaluation>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ConditionFirstSuccess {
+    pub condition_name: String,
+    pub deployment_id: Uuid,
+    pub virtual_machine_id: Uuid,
+    pub first_success_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreBucket {
+    pub lower_bound: BigDecimal,
+    pub upper_bound: BigDecimal,
+    pub count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricScoreDistribution {
+    pub metric_key: String,
+    pub metric_name: Option<String>,
+    pub max_score: Option<BigDecimal>,
+    pub deployment_count: usize,
+    pub sample_count: usize,
+    pub min: BigDecimal,
+    pub max: BigDecimal,
+    pub mean: BigDecimal,
+    pub median: BigDecimal,
+    pub buckets: Vec<ScoreBucket>,
+}
+
+impl MetricScoreDistribution {
+    fn new(
+        metric_key: String,
+        metric: Option<&AnalyticsMetric>,
+        deployment_count: usize,
+        mut values: Vec<BigDecimal>,
+    ) -> Option<Self> {
+        values.sort();
+        let sample_count = values.len();
+        let min = values.first()?.clone();
+        let max = values.last()?.clone();
+        let sum = values
+            .iter()
+            .fold(BigDecimal::from(0), |sum, value| sum + value);
+        let mean = (sum / BigDecimal::from(sample_count as u64)).round(2);
+        let median = match sample_count % 2 {
+            0 => ((&values[sample_count / 2 - 1] + &values[sample_count / 2])
+                / BigDecimal::from(2))
+            .round(2),
+            _ => values[sample_count / 2].clone(),
+        };
+        let max_score = metric.map(|metric| metric.max_score.clone());
+        let buckets = match &max_score {
+            Some(max_score) if *max_score > BigDecimal::from(0) => {
+                let bucket_count = BigDecimal::from(ANALYTICS_SCORE_BUCKET_COUNT as u64);
+                let mut buckets = (0..ANALYTICS_SCORE_BUCKET_COUNT)
+                    .map(|index| ScoreBucket {
+                        lower_bound: (max_score * BigDecimal::from(index as u64) / &bucket_count)
+                            .round(2),
+                        upper_bound: (max_score * BigDecimal::from(index as u64 + 1)
+                            / &bucket_count)
+                            .round(2),
+                        count: 0,
+                    })
+                    .collect::<Vec<ScoreBucket>>();
+                for value in &values {
+                    let index = (value * &bucket_count / max_score)
+                        .with_scale(0)
+                        .to_usize()
+                        .unwrap_or_default()
+                        .min(ANALYTICS_SCORE_BUCKET_COUNT - 1);
+                    buckets[index].count += 1;
+                }
+                buckets
+            }
+            _ => vec![],
+        };
+
+        Some(Self {
+            metric_key,
+            metric_name: metric.and_then(|metric| metric.name.clone()),
+            max_score,
+            deployment_count,
+            sample_count,
+            min,
+            max,
+            mean,
+            median,
+            buckets,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionSuccessTime {
+    pub condition_name: String,
+    pub observed_count: usize,
+    pub success_count: usize,
+    pub average_seconds_to_success: Option<i64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventTriggerRate {
+    pub event_name: String,
+    pub concluded_count: usize,
+    pub triggered_count: usize,
+    pub success_rate: Option<f64>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TloFailureRate {
+    pub tlo_key: String,
+    pub tlo_name: Option<String>,
+    pub evaluated_count: usize,
+    pub failed_count: usize,
+    pub failure_rate: f64,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseAnalytics {
+    pub exercise_id: Uuid,
+    pub deployment_count: usize,
+    pub metric_score_distributions: Vec<MetricScoreDistribution>,
+    pub condition_success_times: Vec<ConditionSuccessTime>,
+    pub event_trigger_rates: Vec<EventTriggerRate>,
+    pub most_failed_tlos: Vec<TloFailureRate>,
+}
+
+impl ExerciseAnalytics {
+    pub fn new(
+        exercise_id: Uuid,
+        deployments: &[DeploymentAnalyticsData],
+        condition_first_successes: &[ConditionFirstSuccess],
+        now: NaiveDateTime,
+    ) -> Self {
+        Self {
+            exercise_id,
+            deployment_count: deployments.len(),
+            metric_score_distributions: Self::get_metric_score_distributions(deployments),
+            condition_success_times: Self::get_condition_success_times(
+                deployments,
+                condition_first_successes,
+            ),
+            event_trigger_rates: Self::get_event_trigger_rates(deployments, now),
+            most_failed_tlos: Self::get_most_failed_tlos(deployments),
+        }
+    }
+
+    fn get_metric_score_distributions(
+        deployments: &[DeploymentAnalyticsData],
+    ) -> Vec<MetricScoreDistribution> {
+        let mut metrics: HashMap<&str, &AnalyticsMetric> = HashMap::new();
+        let mut values: HashMap<&str, Vec<BigDecimal>> = HashMap::new();
+        let mut deployment_ids: HashMap<&str, Vec<Uuid>> = HashMap::new();
+        for deployment in deployments {
+            for metric in &deployment.metrics {
+                metrics.entry(metric.key.as_str()).or_insert(metric);
+            }
+            for score in &deployment.latest_scores {
+                values
+                    .entry(score.metric_key.as_str())
+                    .or_default()
+                    .push(score.value.clone());
+                let metric_deployment_ids =
+                    deployment_ids.entry(score.metric_key.as_str()).or_default();
+                if !metric_deployment_ids.contains(&deployment.deployment_id) {
+                    metric_deployment_ids.push(deployment.deployment_id);
+                }
+            }
+        }
+
+        let mut distributions = values
+            .into_iter()
+            .filter_map(|(metric_key, metric_values)| {
+                MetricScoreDistribution::new(
+                    metric_key.to_owned(),
+                    metrics.get(metric_key).copied(),
+                    deployment_ids.get(metric_key).map_or(0, Vec::len),
+                    metric_values,
+                )
+            })
+            .collect::<Vec<MetricScoreDistribution>>();
+        distributions.sort_by(|distribution, other_distribution| {
+            distribution.metric_key.cmp(&other_distribution.metric_key)
+        });
+
+        distributions
+    }
+
+    fn get_condition_success_times(
+        deployments: &[DeploymentAnalyticsData],
+        condition_first_successes: &[ConditionFirstSuccess],
+    ) -> Vec<ConditionSuccessTime> {
+        let deployment_starts: HashMap<Uuid, NaiveDateTime> = deployments
+            .iter()
+            .map(|deployment| (deployment.deployment_id, deployment.deployment_start))
+            .collect();
+
+        let mut condition_success_seconds: HashMap<&str, (usize, Vec<i64>)> = HashMap::new();
+        for condition_first_success in condition_first_successes {
+            let deployment_start =
+                match deployment_starts.get(&condition_first_success.deployment_id) {
+                    Some(deployment_start) => deployment_start,
+                    None => continue,
+                };
+            let (observed_count, success_seconds) = condition_success_seconds
+                .entry(condition_first_success.condition_name.as_str())
+                .or_default();
+            *observed_count += 1;
+            if let Some(succeeded_at) = condition_first_success.first_success_at {
+                success_seconds.push((succeeded_at - *deployment_start).num_seconds().max(0));
+            }
+        }
+
+        let mut condition_success_times = condition_success_seconds
+            .into_iter()
+            .map(
+                |(condition_name, (observed_count, success_seconds))| ConditionSuccessTime {
+                    condition_name: condition_name.to_owned(),
+                    observed_count,
+                    success_count: success_seconds.len(),
+                    average_seconds_to_success: match success_seconds.is_empty() {
+                        true => None,
+                        false => {
+                            Some(success_seconds.iter().sum::<i64>() / success_seconds.len() as i64)
+                        }
+                    },
+                },
+            )
+            .collect::<Vec<ConditionSuccessTime>>();
+        condition_success_times.sort_by(|success_time, other_success_time| {
+            success_time
+                .condition_name
+                .cmp(&other_success_time.condition_name)
+        });
+
+        condition_success_times
+    }
+
+    fn get_event_trigger_rates(
+        deployments: &[DeploymentAnalyticsData],
+        now: NaiveDateTime,
+    ) -> Vec<EventTriggerRate> {
+        let mut event_counts: HashMap<&str, (usize, usize)> = HashMap::new();
+        for event in deployments
+            .iter()
+            .flat_map(|deployment| deployment.events.iter())
+        {
+            let (concluded_count, triggered_count) =
+                event_counts.entry(event.name.as_str()).or_default();
+            if event.has_triggered {
+                *concluded_count += 1;
+                *triggered_count += 1;
+            } else if event.end < now {
+                *concluded_count += 1;
+            }
+        }
+
+        let mut event_trigger_rates = event_counts
+            .into_iter()
+            .map(
+                |(event_name, (concluded_count, triggered_count))| EventTriggerRate {
+                    event_name: event_name.to_owned(),
+                    concluded_count,
+                    triggered_count,
+                    success_rate: (concluded_count > 0)
+                        .then(|| triggered_count as f64 / concluded_count as f64),
+                },
+            )
+            .collect::<Vec<EventTriggerRate>>();
+        event_trigger_rates.sort_by(|trigger_rate, other_trigger_rate| {
+            trigger_rate.event_name.cmp(&other_trigger_rate.event_name)
+        });
+
+        event_trigger_rates
+    }
+
+    fn get_most_failed_tlos(deployments: &[DeploymentAnalyticsData]) -> Vec<TloFailureRate> {
+        let mut tlo_failures: HashMap<&str, (Option<String>, usize, usize)> = HashMap::new();
+        for tlo in deployments
+            .iter()
+            .flat_map(|deployment| deployment.evaluations.iter())
+            .flat_map(|entity_evaluation| entity_evaluation.tlos.iter())
+        {
+            let (_, evaluated_count, failed_count) = tlo_failures
+                .entry(tlo.tlo_key.as_str())
+                .or_insert_with(|| (tlo.tlo_name.clone(), 0, 0));
+            *evaluated_count += 1;
+            if !tlo.passed {
+                *failed_count += 1;
+            }
+        }
+
+        let mut most_failed_tlos = tlo_failures
+            .into_iter()
+            .map(
+                |(tlo_key, (tlo_name, evaluated_count, failed_count))| TloFailureRate {
+                    tlo_key: tlo_key.to_owned(),
+                    tlo_name,
+                    evaluated_count,
+                    failed_count,
+                    failure_rate: failed_count as f64 / evaluated_count as f64,
+                },
+            )
+            .collect::<Vec<TloFailureRate>>();
+        most_failed_tlos.sort_by(|failure_rate, other_failure_rate| {
+            other_failure_rate
+                .failed_count
+                .cmp(&failure_rate.failed_count)
+                .then_with(|| {
+                    other_failure_rate
+                        .failure_rate
+                        .total_cmp(&failure_rate.failure_rate)
+                })
+                .then_with(|| failure_rate.tlo_key.cmp(&other_failure_rate.tlo_key))
+        });
+
+        most_failed_tlos
+    }
+}
@@ -0,0 +1,297 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::{API_TOKEN_PREFIX, API_TOKEN_SECRET_LENGTH, NAIVEDATETIME_DEFAULT_VALUE},
+    errors::RangerError,
+    roles::RangerRole,
+    schema::{api_token_audit_logs, api_token_exercises, api_tokens},
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+    utilities::Validation,
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+use std::result::Result as StdResult;
+
+pub fn generate_api_token() -> String {
+    let secret: String = thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(API_TOKEN_SECRET_LENGTH)
+        .map(char::from)
+        .collect();
+
+    format!("{API_TOKEN_PREFIX}{secret}")
+}
+
+pub fn hash_api_token(token: &str) -> String {
+    let mut hasher = Sha3_256::new();
+    hasher.update(token.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewApiTokenResource {
+    pub name: String,
+    pub roles: Vec<RangerRole>,
+    #[serde(default)]
+    pub exercise_ids: Vec<Uuid>,
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+impl Validation for NewApiTokenResource {
+    fn validate(&self) -> StdResult<(), RangerError> {
+        if self.name.trim().is_empty() {
+            return Err(RangerError::InvalidParameter("name".to_string()));
+        }
+        if self.roles.is_empty() {
+            return Err(RangerError::InvalidParameter("roles".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_tokens)]
+pub struct NewApiToken {
+    pub id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub roles: String,
+    pub created_by: String,
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+impl NewApiToken {
+    pub fn new(resource: &NewApiTokenResource, token: &str, created_by: String) -> Self {
+        Self {
+            id: Uuid::random(),
+            name: resource.name.clone(),
+            token_hash: hash_api_token(token),
+            roles: resource
+                .roles
+                .iter()
+                .map(RangerRole::to_string)
+                .collect::<Vec<String>>()
+                .join(","),
+            created_by,
+            expires_at: resource.expires_at,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, api_tokens::table> {
+        insert_into(api_tokens::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_tokens)]
+pub struct ApiToken {
+    pub id: Uuid,
+    pub name: String,
+    pub token_hash: String,
+    pub roles: String,
+    pub created_by: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByTokenHash<T> =
+    Filter<FilterExisting<T, api_tokens::deleted_at>, Eq<api_tokens::token_hash, String>>;
+
+impl ApiToken {
+    fn all_with_deleted() -> All<api_tokens::table, Self> {
+        api_tokens::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<api_tokens::table, Self>, api_tokens::deleted_at> {
+        Self::all_with_deleted().filter(api_tokens::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<api_tokens::table, api_tokens::id, api_tokens::deleted_at, Self> {
+        Self::all().filter(api_tokens::id.eq(id))
+    }
+
+    pub fn by_token_hash(token_hash: String) -> ByTokenHash<All<api_tokens::table, Self>> {
+        Self::all().filter(api_tokens::token_hash.eq(token_hash))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<api_tokens::id, api_tokens::deleted_at, api_tokens::table> {
+        diesel::update(api_tokens::table.filter(api_tokens::id.eq(self.id)))
+            .set(api_tokens::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_token_exercises)]
+pub struct NewApiTokenExercise {
+    pub id: Uuid,
+    pub api_token_id: Uuid,
+    pub exercise_id: Uuid,
+}
+
+impl NewApiTokenExercise {
+    pub fn new(api_token_id: Uuid, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            api_token_id,
+            exercise_id,
+        }
+    }
+
+    pub fn batch_insert(
+        api_token_exercises: Vec<Self>,
+    ) -> Create<Vec<Self>, api_token_exercises::table> {
+        insert_into(api_token_exercises::table).values(api_token_exercises)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_token_exercises)]
+pub struct ApiTokenExercise {
+    pub id: Uuid,
+    pub api_token_id: Uuid,
+    pub exercise_id: Uuid,
+    pub created_at: NaiveDateTime,
+}
+
+impl ApiTokenExercise {
+    pub fn by_api_token_id(
+        api_token_id: Uuid,
+    ) -> Filter<All<api_token_exercises::table, Self>, Eq<api_token_exercises::api_token_id, Uuid>>
+    {
+        api_token_exercises::table
+            .select(Self::as_select())
+            .filter(api_token_exercises::api_token_id.eq(api_token_id))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiTokenRest {
+    pub id: Uuid,
+    pub name: String,
+    pub roles: Vec<RangerRole>,
+    pub exercise_ids: Vec<Uuid>,
+    pub created_by: String,
+    pub expires_at: Option<NaiveDateTime>,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+impl From<(ApiToken, Vec<ApiTokenExercise>)> for ApiTokenRest {
+    fn from((api_token, api_token_exercises): (ApiToken, Vec<ApiTokenExercise>)) -> Self {
+        let token_roles = api_token.roles.split(',').collect::<Vec<&str>>();
+        let roles = [
+            RangerRole::Admin,
+            RangerRole::Participant,
+            RangerRole::Client,
+        ]
+        .into_iter()
+        .filter(|role| token_roles.contains(&role.to_string().as_str()))
+        .collect();
+
+        Self {
+            id: api_token.id,
+            name: api_token.name,
+            roles,
+            exercise_ids: api_token_exercises
+                .into_iter()
+                .map(|api_token_exercise| api_token_exercise.exercise_id)
+                .collect(),
+            created_by: api_token.created_by,
+            expires_at: api_token.expires_at,
+            last_used_at: api_token.last_used_at,
+            created_at: api_token.created_at,
+        }
+    }
+}
+
+impl ApiTokenRest {
+    pub fn is_expired(&self, now: NaiveDateTime) -> bool {
+        self.expires_at
+            .map_or(false, |expires_at| expires_at <= now)
+    }
+
+    pub fn allows_exercise(&self, exercise_id: Option<Uuid>) -> bool {
+        match self.exercise_ids.is_empty() {
+            true => true,
+            false => exercise_id.map_or(false, |exercise_id| {
+                self.exercise_ids.contains(&exercise_id)
+            }),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedApiToken {
+    #[serde(flatten)]
+    pub api_token: ApiTokenRest,
+    pub token: String,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_token_audit_logs)]
+pub struct NewApiTokenAuditLog {
+    pub id: Uuid,
+    pub api_token_id: Uuid,
+    pub method: String,
+    pub path: String,
+    pub status_code: u16,
+}
+
+impl NewApiTokenAuditLog {
+    pub fn new(api_token_id: Uuid, method: String, path: String, status_code: u16) -> Self {
+        Self {
+            id: Uuid::random(),
+            api_token_id,
+            method,
+            path,
+            status_code,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, api_token_audit_logs::table> {
+        insert_into(api_token_audit_logs::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = api_token_audit_logs)]
+pub struct ApiTokenAuditLog {
+    pub id: Uuid,
+    pub api_token_id: Uuid,
+    pub method: String,
+    pub path: String,
+    pub status_code: u16,
+    pub created_at: NaiveDateTime,
+}
+
+impl ApiTokenAuditLog {
+    pub fn by_api_token_id(
+        api_token_id: Uuid,
+    ) -> Filter<All<api_token_audit_logs::table, Self>, Eq<api_token_audit_logs::api_token_id, Uuid>>
+    {
+        api_token_audit_logs::table
+            .select(Self::as_select())
+            .filter(api_token_audit_logs::api_token_id.eq(api_token_id))
+    }
+}
@@ -0,0 +1,504 @@
+use super::{helpers::uuid::Uuid, report::escape_html, Deployment, Exercise};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{certificate_templates, certificates},
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+};
+use chrono::{NaiveDateTime, Timelike, Utc};
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha3::Sha3_256;
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCertificateTemplateResource {
+    pub name: String,
+    pub content: String,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = certificate_templates)]
+pub struct NewCertificateTemplate {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub name: String,
+    pub content: String,
+}
+
+impl NewCertificateTemplate {
+    pub fn new(resource: NewCertificateTemplateResource, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            name: resource.name,
+            content: resource.content,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, certificate_templates::table> {
+        insert_into(certificate_templates::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = certificate_templates)]
+pub struct CertificateTemplate {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub name: String,
+    pub content: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<
+    FilterExisting<T, certificate_templates::deleted_at>,
+    Eq<certificate_templates::exercise_id, Uuid>,
+>;
+
+impl CertificateTemplate {
+    fn all_with_deleted() -> All<certificate_templates::table, Self> {
+        certificate_templates::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<certificate_templates::table, Self>, certificate_templates::deleted_at>
+    {
+        Self::all_with_deleted()
+            .filter(certificate_templates::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        certificate_templates::table,
+        certificate_templates::id,
+        certificate_templates::deleted_at,
+        Self,
+    > {
+        Self::all().filter(certificate_templates::id.eq(id))
+    }
+
+    pub fn by_exercise_id(
+        exercise_id: Uuid,
+    ) -> ByExerciseId<All<certificate_templates::table, Self>> {
+        Self::all().filter(certificate_templates::exercise_id.eq(exercise_id))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        certificate_templates::id,
+        certificate_templates::deleted_at,
+        certificate_templates::table,
+    > {
+        diesel::update(certificate_templates::table.filter(certificate_templates::id.eq(self.id)))
+            .set(certificate_templates::deleted_at.eq(diesel::dsl::now))
+    }
+
+    /// Substitutes `{{name}}` placeholders in a single pass, so substituted
+    /// values are never scanned for further placeholders.
+    pub fn render(&self, placeholders: &[(&str, &str)]) -> String {
+        let mut rendered = String::with_capacity(self.content.len());
+        let mut remaining = self.content.as_str();
+        while let Some(start) = remaining.find("{{") {
+            rendered.push_str(&remaining[..start]);
+            let placeholder_start = &remaining[start + 2..];
+            let placeholder = placeholder_start.find("}}").and_then(|end| {
+                placeholders
+                    .iter()
+                    .find(|(name, _)| *name == &placeholder_start[..end])
+                    .map(|(_, value)| (end, value))
+            });
+            match placeholder {
+                Some((end, value)) => {
+                    rendered.push_str(&escape_html(value));
+                    remaining = &placeholder_start[end + 2..];
+                }
+                None => {
+                    rendered.push('{');
+                    remaining = &remaining[start + 1..];
+                }
+            }
+        }
+        rendered.push_str(remaining);
+
+        rendered
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssueCertificatesResource {
+    pub certificate_template_id: Uuid,
+    pub entity_selectors: Option<Vec<String>>,
+    #[serde(default)]
+    pub per_participant: bool,
+    #[serde(default)]
+    pub send_email: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateRecipient {
+    pub entity_selector: String,
+    pub entity_name: Option<String>,
+    pub user_id: Option<String>,
+    pub name: String,
+    pub email: Option<String>,
+    pub training_objectives: Vec<String>,
+}
+
+fn create_signature_mac(signing_key: &str, fields: &[&str]) -> Hmac<Sha3_256> {
+    let mut mac = Hmac::<Sha3_256>::new_from_slice(signing_key.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    for field in fields {
+        mac.update(&(field.len() as u64).to_be_bytes());
+        mac.update(field.as_bytes());
+    }
+    mac
+}
+
+fn create_signature(signing_key: &str, fields: &[&str]) -> String {
+    create_signature_mac(signing_key, fields)
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn decode_signature(signature: &str) -> Option<Vec<u8>> {
+    if !signature.is_ascii() || signature.len() % 2 != 0 {
+        return None;
+    }
+    (0..signature.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&signature[index..index + 2], 16).ok())
+        .collect()
+}
+
+fn verify_signature(signing_key: &str, fields: &[&str], signature: &str) -> bool {
+    match decode_signature(signature) {
+        Some(signature) => create_signature_mac(signing_key, fields)
+            .verify_slice(&signature)
+            .is_ok(),
+        None => false,
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = certificates)]
+pub struct NewCertificate {
+    pub id: Uuid,
+    pub certificate_template_id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub user_id: Option<String>,
+    pub recipient_name: String,
+    pub recipient_email: Option<String>,
+    pub exercise_name: String,
+    pub training_objectives: String,
+    pub content: String,
+    pub signature: String,
+    pub issued_at: NaiveDateTime,
+}
+
+impl NewCertificate {
+    pub fn new(
+        certificate_template: &CertificateTemplate,
+        exercise: &Exercise,
+        deployment: &Deployment,
+        recipient: CertificateRecipient,
+        signing_key: &str,
+    ) -> Self {
+        let id = Uuid::random();
+        let now = Utc::now().naive_utc();
+        let issued_at = now.with_nanosecond(0).unwrap_or(now);
+        let training_objectives = recipient.training_objectives.join(", ");
+        let id_string = id.to_string();
+        let issued_at_string = issued_at.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+        let content = certificate_template.render(&[
+            ("certificateId", &id_string),
+            ("recipientName", &recipient.name),
+            (
+                "entityName",
+                recipient
+                    .entity_name
+                    .as_deref()
+                    .unwrap_or(&recipient.entity_selector),
+            ),
+            ("entitySelector", &recipient.entity_selector),
+            ("exerciseName", &exercise.name),
+            ("deploymentName", &deployment.name),
+            ("trainingObjectives", &training_objectives),
+            ("issuedAt", &issued_at_string),
+        ]);
+
+        let mut new_certificate = Self {
+            id,
+            certificate_template_id: certificate_template.id,
+            exercise_id: exercise.id,
+            deployment_id: deployment.id,
+            entity_selector: recipient.entity_selector,
+            user_id: recipient.user_id,
+            recipient_name: recipient.name,
+            recipient_email: recipient.email,
+            exercise_name: exercise.name.clone(),
+            training_objectives,
+            content,
+            signature: String::new(),
+            issued_at,
+        };
+        new_certificate.signature = create_signature(
+            signing_key,
+            &Certificate::signature_fields(
+                &id_string,
+                &new_certificate.entity_selector,
+                new_certificate.user_id.as_deref(),
+                &new_certificate.recipient_name,
+                &new_certificate.exercise_name,
+                &new_certificate.training_objectives,
+                &new_certificate.content,
+                &issued_at_string,
+            ),
+        );
+
+        new_certificate
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, certificates::table> {
+        insert_into(certificates::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = certificates)]
+pub struct Certificate {
+    pub id: Uuid,
+    pub certificate_template_id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub user_id: Option<String>,
+    pub recipient_name: String,
+    pub recipient_email: Option<String>,
+    pub exercise_name: String,
+    pub training_objectives: String,
+    #[serde(skip_serializing)]
+    pub content: String,
+    pub signature: String,
+    pub issued_at: NaiveDateTime,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByDeploymentId<T> =
+    Filter<FilterExisting<T, certificates::deleted_at>, Eq<certificates::deployment_id, Uuid>>;
+
+impl Certificate {
+    fn all_with_deleted() -> All<certificates::table, Self> {
+        certificates::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<certificates::table, Self>, certificates::deleted_at> {
+        Self::all_with_deleted().filter(certificates::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<certificates::table, certificates::id, certificates::deleted_at, Self> {
+        Self::all().filter(certificates::id.eq(id))
+    }
+
+    pub fn by_id_with_deleted(
+        id: Uuid,
+    ) -> Filter<All<certificates::table, Self>, Eq<certificates::id, Uuid>> {
+        Self::all_with_deleted().filter(certificates::id.eq(id))
+    }
+
+    pub fn by_deployment_id(deployment_id: Uuid) -> ByDeploymentId<All<certificates::table, Self>> {
+        Self::all().filter(certificates::deployment_id.eq(deployment_id))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<certificates::id, certificates::deleted_at, certificates::table> {
+        diesel::update(certificates::table.filter(certificates::id.eq(self.id)))
+            .set(certificates::deleted_at.eq(diesel::dsl::now))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn signature_fields<'a>(
+        id: &'a str,
+        entity_selector: &'a str,
+        user_id: Option<&'a str>,
+        recipient_name: &'a str,
+        exercise_name: &'a str,
+        training_objectives: &'a str,
+        content: &'a str,
+        issued_at: &'a str,
+    ) -> [&'a str; 8] {
+        [
+            id,
+            entity_selector,
+            user_id.unwrap_or_default(),
+            recipient_name,
+            exercise_name,
+            training_objectives,
+            content,
+            issued_at,
+        ]
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.deleted_at != *NAIVEDATETIME_DEFAULT_VALUE
+    }
+
+    pub fn has_valid_signature(&self, signing_key: &str) -> bool {
+        let id = self.id.to_string();
+        let issued_at = self.issued_at.format("%Y-%m-%d %H:%M:%S UTC").to_string();
+        verify_signature(
+            signing_key,
+            &Self::signature_fields(
+                &id,
+                &self.entity_selector,
+                self.user_id.as_deref(),
+                &self.recipient_name,
+                &self.exercise_name,
+                &self.training_objectives,
+                &self.content,
+                &issued_at,
+            ),
+            &self.signature,
+        )
+    }
+
+    pub fn is_issued_to(
+        &self,
+        certificate_template_id: Uuid,
+        recipient: &CertificateRecipient,
+    ) -> bool {
+        self.certificate_template_id == certificate_template_id
+            && self.entity_selector == recipient.entity_selector
+            && self.user_id == recipient.user_id
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CertificateVerification {
+    pub id: Uuid,
+    pub valid: bool,
+    pub revoked: bool,
+    pub recipient_name: String,
+    pub entity_selector: String,
+    pub exercise_name: String,
+    pub training_objectives: String,
+    pub issued_at: NaiveDateTime,
+}
+
+impl CertificateVerification {
+    pub fn new(certificate: Certificate, signing_key: &str) -> Self {
+        let revoked = certificate.is_revoked();
+        let valid = !revoked && certificate.has_valid_signature(signing_key);
+
+        Self {
+            id: certificate.id,
+            valid,
+            revoked,
+            recipient_name: certificate.recipient_name,
+            entity_selector: certificate.entity_selector,
+            exercise_name: certificate.exercise_name,
+            training_objectives: certificate.training_objectives,
+            issued_at: certificate.issued_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{create_signature, verify_signature, CertificateTemplate};
+    use crate::models::helpers::uuid::Uuid;
+    use chrono::NaiveDateTime;
+
+    fn create_template(content: &str) -> CertificateTemplate {
+        CertificateTemplate {
+            id: Uuid::random(),
+            exercise_id: Uuid::random(),
+            name: "Template".to_string(),
+            content: content.to_string(),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            deleted_at: NaiveDateTime::default(),
+        }
+    }
+
+    #[test]
+    fn renders_escaped_placeholders() {
+        let template = create_template("<p>{{recipientName}} completed {{exerciseName}}</p>");
+
+        assert_eq!(
+            template.render(&[("recipientName", "<b>Bob</b>"), ("exerciseName", "Drill")]),
+            "<p>&lt;b&gt;Bob&lt;/b&gt; completed Drill</p>"
+        );
+    }
+
+    #[test]
+    fn does_not_render_placeholders_inside_values() {
+        let template = create_template("{{recipientName}} / {{exerciseName}}");
+
+        assert_eq!(
+            template.render(&[
+                ("recipientName", "{{exerciseName}}"),
+                ("exerciseName", "Drill")
+            ]),
+            "{{exerciseName}} / Drill"
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_placeholders() {
+        let template = create_template("{{unknown}} {{{recipientName}}} {{");
+
+        assert_eq!(
+            template.render(&[("recipientName", "Bob")]),
+            "{{unknown}} {Bob} {{"
+        );
+    }
+
+    #[test]
+    fn verifies_signatures() {
+        let fields = ["certificate", "team.bob", "Bob"];
+        let signature = create_signature("key", &fields);
+
+        assert!(verify_signature("key", &fields, &signature));
+        assert!(!verify_signature("other-key", &fields, &signature));
+        assert!(!verify_signature(
+            "key",
+            &["certificate", "team.bob", "Alice"],
+            &signature
+        ));
+        assert!(!verify_signature("key", &fields, "not a signature"));
+        assert!(!verify_signature("key", &fields, &signature[2..]));
+    }
+
+    #[test]
+    fn signature_fields_are_length_prefixed() {
+        assert_ne!(
+            create_signature("key", &["ab", "c"]),
+            create_signature("key", &["a", "bc"])
+        );
+    }
+}
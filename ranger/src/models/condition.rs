@@ -1,4 +1,4 @@
-use super::helpers::uuid::Uuid;
+use super::{helpers::uuid::Uuid, DeploymentElement, ElementStatus};
 use crate::{
     constants::NAIVEDATETIME_DEFAULT_VALUE,
     schema::condition_messages,
@@ -10,7 +10,9 @@ use diesel::{
     helper_types::{Eq, Filter},
     insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
 };
+use sdl_parser::metric::Metric;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -122,3 +124,112 @@ impl ConditionMessage {
             .set(condition_messages::deleted_at.eq(diesel::dsl::now))
     }
 }
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionDiagnosticsPoint {
+    pub timestamp: NaiveDateTime,
+    pub value: BigDecimal,
+    pub message_count: usize,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionDiagnostics {
+    pub vm_name: String,
+    pub virtual_machine_id: Option<Uuid>,
+    pub condition_name: String,
+    pub condition_id: Option<String>,
+    pub status: ElementStatus,
+    pub error_message: Option<String>,
+    pub latest_value: Option<BigDecimal>,
+    pub last_received_at: Option<NaiveDateTime>,
+    pub message_count: usize,
+    pub messages_per_minute: f64,
+    pub metric_key: Option<String>,
+    pub metric_name: Option<String>,
+    pub metric_max_score: Option<u32>,
+    pub time_series: Vec<ConditionDiagnosticsPoint>,
+}
+
+impl ConditionDiagnostics {
+    pub fn new(
+        vm_name: String,
+        condition_element: &DeploymentElement,
+        condition_messages: &[&ConditionMessage],
+        condition_metric: Option<(String, Metric)>,
+        resolution_seconds: i64,
+    ) -> Self {
+        let latest_message = condition_messages
+            .iter()
+            .max_by_key(|condition_message| condition_message.created_at);
+        let first_message = condition_messages
+            .iter()
+            .min_by_key(|condition_message| condition_message.created_at);
+
+        let messages_per_minute = match (first_message, latest_message) {
+            (Some(first_message), Some(latest_message)) => {
+                let elapsed_seconds =
+                    (latest_message.created_at - first_message.created_at).num_seconds();
+                match elapsed_seconds > 0 {
+                    true => (condition_messages.len() - 1) as f64 * 60.0 / elapsed_seconds as f64,
+                    false => 0.0,
+                }
+            }
+            _ => 0.0,
+        };
+
+        let (metric_key, metric_name, metric_max_score) = match condition_metric {
+            Some((metric_key, metric)) => (Some(metric_key), metric.name, Some(metric.max_score)),
+            None => (None, None, None),
+        };
+
+        Self {
+            vm_name,
+            virtual_machine_id: condition_element.parent_node_id,
+            condition_name: condition_element.scenario_reference.clone(),
+            condition_id: condition_element.handler_reference.clone(),
+            status: condition_element.status,
+            error_message: condition_element.error_message.clone(),
+            latest_value: latest_message.map(|condition_message| condition_message.value.clone()),
+            last_received_at: latest_message.map(|condition_message| condition_message.created_at),
+            message_count: condition_messages.len(),
+            messages_per_minute,
+            metric_key,
+            metric_name,
+            metric_max_score,
+            time_series: Self::downsample(condition_messages, resolution_seconds),
+        }
+    }
+
+    fn downsample(
+        condition_messages: &[&ConditionMessage],
+        resolution_seconds: i64,
+    ) -> Vec<ConditionDiagnosticsPoint> {
+        let resolution_seconds = resolution_seconds.max(1);
+        let mut buckets: BTreeMap<i64, (BigDecimal, usize)> = BTreeMap::new();
+
+        for condition_message in condition_messages {
+            let timestamp = condition_message.created_at.timestamp();
+            let bucket_start = timestamp - timestamp.rem_euclid(resolution_seconds);
+            let bucket = buckets
+                .entry(bucket_start)
+                .or_insert_with(|| (BigDecimal::from(0), 0));
+            bucket.0 += condition_message.value.clone();
+            bucket.1 += 1;
+        }
+
+        buckets
+            .into_iter()
+            .filter_map(|(bucket_start, (value_sum, message_count))| {
+                NaiveDateTime::from_timestamp_opt(bucket_start, 0).map(|timestamp| {
+                    ConditionDiagnosticsPoint {
+                        timestamp,
+                        value: value_sum / BigDecimal::from(message_count as u64),
+                        message_count,
+                    }
+                })
+            })
+            .collect()
+    }
+}
@@ -1,15 +1,18 @@
 use crate::{
     constants::{MAX_DEPLOYMENT_NAME_LENGTH, NAIVEDATETIME_DEFAULT_VALUE},
     errors::RangerError,
-    middleware::keycloak::KeycloakInfo,
+    middleware::authentication::User,
     schema::{
         deployment_elements::{self},
         deployments,
     },
-    services::database::{
-        deployment::UpdateDeploymentElement, participant::GetParticipants, All, Create,
-        CreateOrIgnore, Database, FilterExisting, SelectById, SoftDelete, SoftDeleteById,
-        UpdateById,
+    services::{
+        database::{
+            deployment::UpdateDeploymentElement, participant::GetParticipants, All, Create,
+            CreateOrIgnore, Database, FilterExisting, SelectById, SoftDelete, SoftDeleteById,
+            UpdateById,
+        },
+        identity::IdentityProvider,
     },
     utilities::{create_database_error_handler, create_mailbox_error_handler, Validation},
 };
@@ -405,32 +408,15 @@ impl Deployment {
 
     async fn is_member(
         &self,
-        user_id: String,
-        keycloak_info: KeycloakInfo,
-        realm_name: String,
+        user: &User,
+        identity_provider: &dyn IdentityProvider,
     ) -> Result<bool> {
-        let role_name = self
+        let group_name = self
             .group_name
-            .clone()
+            .as_ref()
             .ok_or_else(|| anyhow!("Exercise group name is not set"))?;
-        let users = keycloak_info
-            .service_user
-            .realm_clients_with_id_roles_with_role_name_users_get(
-                &realm_name,
-                &keycloak_info.client_id,
-                &role_name,
-                None,
-                None,
-            )
-            .await?;
-        for user in users {
-            if let Some(loop_user_id) = user.id {
-                if loop_user_id == user_id {
-                    return Ok(true);
-                }
-            }
-        }
-        Ok(false)
+
+        Ok(identity_provider.is_group_member(group_name, user).await?)
     }
 
     async fn is_assigned_entity(
@@ -455,16 +441,13 @@ impl Deployment {
 
     pub async fn is_connected(
         &self,
-        user_id: String,
+        user: &User,
         database_address: &Addr<Database>,
-        keycloak_info: KeycloakInfo,
-        realm_name: String,
+        identity_provider: &dyn IdentityProvider,
     ) -> Result<bool> {
-        let is_member = self
-            .is_member(user_id.clone(), keycloak_info, realm_name.clone())
-            .await?;
+        let is_member = self.is_member(user, identity_provider).await?;
         let is_connected = self
-            .is_assigned_entity(user_id.clone(), database_address)
+            .is_assigned_entity(user.id.clone(), database_address)
             .await?;
 
         Ok(is_member && is_connected)
@@ -0,0 +1,218 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{deployment_secrets, secret_definitions},
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSecretDefinitionResource {
+    pub name: String,
+    #[serde(default)]
+    pub per_entity: bool,
+    pub length: Option<u32>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = secret_definitions)]
+pub struct NewSecretDefinition {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub name: String,
+    pub per_entity: bool,
+    pub length: u32,
+}
+
+impl NewSecretDefinition {
+    pub fn new(resource: NewSecretDefinitionResource, exercise_id: Uuid, length: u32) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            name: resource.name,
+            per_entity: resource.per_entity,
+            length,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, secret_definitions::table> {
+        insert_into(secret_definitions::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = secret_definitions)]
+pub struct SecretDefinition {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub name: String,
+    pub per_entity: bool,
+    pub length: u32,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<
+    FilterExisting<T, secret_definitions::deleted_at>,
+    Eq<secret_definitions::exercise_id, Uuid>,
+>;
+
+impl SecretDefinition {
+    fn all_with_deleted() -> All<secret_definitions::table, Self> {
+        secret_definitions::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<secret_definitions::table, Self>, secret_definitions::deleted_at> {
+        Self::all_with_deleted()
+            .filter(secret_definitions::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        secret_definitions::table,
+        secret_definitions::id,
+        secret_definitions::deleted_at,
+        Self,
+    > {
+        Self::all().filter(secret_definitions::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<secret_definitions::table, Self>> {
+        Self::all().filter(secret_definitions::exercise_id.eq(exercise_id))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        secret_definitions::id,
+        secret_definitions::deleted_at,
+        secret_definitions::table,
+    > {
+        diesel::update(secret_definitions::table.filter(secret_definitions::id.eq(self.id)))
+            .set(secret_definitions::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn is_valid_name(name: &str) -> bool {
+        let mut characters = name.chars();
+        characters
+            .next()
+            .is_some_and(|character| character.is_ascii_alphabetic() || character == '_')
+            && characters.all(|character| character.is_ascii_alphanumeric() || character == '_')
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = deployment_secrets)]
+pub struct NewDeploymentSecret {
+    pub id: Uuid,
+    pub deployment_id: Uuid,
+    pub secret_definition_id: Uuid,
+    pub name: String,
+    pub entity_selector: Option<String>,
+    pub value: String,
+}
+
+impl NewDeploymentSecret {
+    pub fn new(
+        deployment_id: Uuid,
+        secret_definition: &SecretDefinition,
+        entity_selector: Option<String>,
+    ) -> Self {
+        let value = thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(secret_definition.length as usize)
+            .map(char::from)
+            .collect();
+
+        Self {
+            id: Uuid::random(),
+            deployment_id,
+            secret_definition_id: secret_definition.id,
+            name: secret_definition.name.clone(),
+            entity_selector,
+            value,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, deployment_secrets::table> {
+        insert_into(deployment_secrets::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = deployment_secrets)]
+pub struct DeploymentSecret {
+    pub id: Uuid,
+    pub deployment_id: Uuid,
+    pub secret_definition_id: Uuid,
+    pub name: String,
+    pub entity_selector: Option<String>,
+    pub value: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByDeploymentId<T> = Filter<
+    FilterExisting<T, deployment_secrets::deleted_at>,
+    Eq<deployment_secrets::deployment_id, Uuid>,
+>;
+
+impl DeploymentSecret {
+    fn all_with_deleted() -> All<deployment_secrets::table, Self> {
+        deployment_secrets::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<deployment_secrets::table, Self>, deployment_secrets::deleted_at> {
+        Self::all_with_deleted()
+            .filter(deployment_secrets::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_deployment_id(
+        deployment_id: Uuid,
+    ) -> ByDeploymentId<All<deployment_secrets::table, Self>> {
+        Self::all().filter(deployment_secrets::deployment_id.eq(deployment_id))
+    }
+
+    pub fn get_environment(secrets: &[Self], entity_selectors: &[String]) -> Vec<String> {
+        secrets
+            .iter()
+            .flat_map(|secret| match &secret.entity_selector {
+                None => vec![format!("{}={}", secret.name, secret.value)],
+                Some(entity_selector) if entity_selectors.contains(entity_selector) => {
+                    let entity_suffix = entity_selector
+                        .chars()
+                        .map(|character| match character.is_ascii_alphanumeric() {
+                            true => character.to_ascii_uppercase(),
+                            false => '_',
+                        })
+                        .collect::<String>();
+                    let mut environment = vec![format!(
+                        "{}_{}={}",
+                        secret.name, entity_suffix, secret.value
+                    )];
+                    if entity_selectors.len() == 1 {
+                        environment.push(format!("{}={}", secret.name, secret.value));
+                    }
+                    environment
+                }
+                Some(_) => vec![],
+            })
+            .collect()
+    }
+}
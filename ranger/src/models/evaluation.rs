@@ -0,0 +1,85 @@
+use super::helpers::uuid::Uuid;
+use bigdecimal::BigDecimal;
+use sdl_parser::entity::ExerciseRole;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TloEvaluation {
+    pub tlo_key: String,
+    pub tlo_name: Option<String>,
+    pub evaluation_key: String,
+    pub evaluation_name: Option<String>,
+    pub metric_keys: Vec<String>,
+    pub score: BigDecimal,
+    pub max_score: BigDecimal,
+    pub min_score: BigDecimal,
+    pub passed: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalEvaluation {
+    pub goal_key: String,
+    pub goal_name: Option<String>,
+    pub tlo_keys: Vec<String>,
+    pub passed: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityEvaluation {
+    pub entity_selector: String,
+    pub entity_name: Option<String>,
+    pub role: Option<ExerciseRole>,
+    pub tlos: Vec<TloEvaluation>,
+    pub goals: Vec<GoalEvaluation>,
+    pub passed: bool,
+}
+
+impl EntityEvaluation {
+    pub fn new(
+        entity_selector: String,
+        entity_name: Option<String>,
+        role: Option<ExerciseRole>,
+        tlos: Vec<TloEvaluation>,
+        goals: Vec<GoalEvaluation>,
+    ) -> Self {
+        let passed = tlos.iter().all(|tlo| tlo.passed) && goals.iter().all(|goal| goal.passed);
+
+        Self {
+            entity_selector,
+            entity_name,
+            role,
+            tlos,
+            goals,
+            passed,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentEvaluation {
+    pub deployment_id: Uuid,
+    pub entities: Vec<EntityEvaluation>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EvaluationUpdate {
+    pub deployment_id: Uuid,
+}
+
+impl DeploymentEvaluation {
+    pub fn filter_by_entity(mut self, entity_selector: &str) -> Self {
+        self.entities.retain(|entity_evaluation| {
+            entity_evaluation.entity_selector == entity_selector
+                || entity_evaluation
+                    .entity_selector
+                    .starts_with(&format!("{entity_selector}."))
+        });
+
+        self
+    }
+}
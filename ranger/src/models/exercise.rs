@@ -2,9 +2,12 @@ use super::helpers::uuid::Uuid;
 use crate::{
     constants::{MAX_EXERCISE_NAME_LENGTH, NAIVEDATETIME_DEFAULT_VALUE},
     errors::RangerError,
-    middleware::keycloak::KeycloakInfo,
+    middleware::authentication::User,
     schema::exercises,
-    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById, UpdateById},
+    services::{
+        database::{All, Create, FilterExisting, SelectById, SoftDeleteById, UpdateById},
+        identity::IdentityProvider,
+    },
     utilities::Validation,
 };
 use anyhow::{anyhow, Result};
@@ -26,6 +29,10 @@ pub struct NewExercise {
     pub deployment_group: String,
     pub sdl_schema: Option<String>,
     pub group_name: Option<String>,
+    #[serde(default)]
+    pub hide_leaderboard_names: bool,
+    #[serde(default)]
+    pub allow_late_resubmission: bool,
 }
 
 impl NewExercise {
@@ -53,6 +60,8 @@ pub struct Exercise {
     pub deployment_group: String,
     pub sdl_schema: Option<String>,
     pub group_name: Option<String>,
+    pub hide_leaderboard_names: bool,
+    pub allow_late_resubmission: bool,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
 }
@@ -81,32 +90,15 @@ impl Exercise {
 
     pub async fn is_member(
         &self,
-        user_id: String,
-        keycloak_info: KeycloakInfo,
-        realm_name: String,
+        user: &User,
+        identity_provider: &dyn IdentityProvider,
     ) -> Result<bool> {
-        let role_name = self
+        let group_name = self
             .group_name
-            .clone()
+            .as_ref()
             .ok_or_else(|| anyhow!("Exercise group name is not set"))?;
-        let users = keycloak_info
-            .service_user
-            .realm_clients_with_id_roles_with_role_name_users_get(
-                &realm_name,
-                &keycloak_info.client_id,
-                &role_name,
-                None,
-                None,
-            )
-            .await?;
-        for user in users {
-            if let Some(loop_user_id) = user.id {
-                if loop_user_id == user_id {
-                    return Ok(true);
-                }
-            }
-        }
-        Ok(false)
+
+        Ok(identity_provider.is_group_member(group_name, user).await?)
     }
 }
 
@@ -136,6 +128,10 @@ pub struct UpdateExercise {
     pub deployment_group: String,
     pub sdl_schema: Option<String>,
     pub group_name: Option<String>,
+    #[serde(default)]
+    pub hide_leaderboard_names: Option<bool>,
+    #[serde(default)]
+    pub allow_late_resubmission: Option<bool>,
 }
 
 impl UpdateExercise {
@@ -0,0 +1,135 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    errors::RangerError,
+    roles::ExerciseStaffRole,
+    schema::exercise_role_assignments,
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+    utilities::Validation,
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+use std::result::Result as StdResult;
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewExerciseRoleAssignmentResource {
+    pub user_id: String,
+    pub role: ExerciseStaffRole,
+}
+
+impl Validation for NewExerciseRoleAssignmentResource {
+    fn validate(&self) -> StdResult<(), RangerError> {
+        if self.user_id.trim().is_empty() {
+            return Err(RangerError::InvalidParameter("userId".to_string()));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = exercise_role_assignments)]
+pub struct NewExerciseRoleAssignment {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub user_id: String,
+    pub role: ExerciseStaffRole,
+    pub assigned_by: Option<String>,
+}
+
+impl NewExerciseRoleAssignment {
+    pub fn new(
+        resource: NewExerciseRoleAssignmentResource,
+        exercise_id: Uuid,
+        assigned_by: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            user_id: resource.user_id.trim().to_string(),
+            role: resource.role,
+            assigned_by,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, exercise_role_assignments::table> {
+        insert_into(exercise_role_assignments::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = exercise_role_assignments)]
+pub struct ExerciseRoleAssignment {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub user_id: String,
+    pub role: ExerciseStaffRole,
+    pub assigned_by: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<
+    FilterExisting<T, exercise_role_assignments::deleted_at>,
+    Eq<exercise_role_assignments::exercise_id, Uuid>,
+>;
+type ByExerciseIdAndUserId<T> =
+    Filter<ByExerciseId<T>, Eq<exercise_role_assignments::user_id, String>>;
+
+impl ExerciseRoleAssignment {
+    fn all_with_deleted() -> All<exercise_role_assignments::table, Self> {
+        exercise_role_assignments::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<
+        All<exercise_role_assignments::table, Self>,
+        exercise_role_assignments::deleted_at,
+    > {
+        Self::all_with_deleted()
+            .filter(exercise_role_assignments::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        exercise_role_assignments::table,
+        exercise_role_assignments::id,
+        exercise_role_assignments::deleted_at,
+        Self,
+    > {
+        Self::all().filter(exercise_role_assignments::id.eq(id))
+    }
+
+    pub fn by_exercise_id(
+        exercise_id: Uuid,
+    ) -> ByExerciseId<All<exercise_role_assignments::table, Self>> {
+        Self::all().filter(exercise_role_assignments::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_user_id(
+        exercise_id: Uuid,
+        user_id: String,
+    ) -> ByExerciseIdAndUserId<All<exercise_role_assignments::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(exercise_role_assignments::user_id.eq(user_id))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        exercise_role_assignments::id,
+        exercise_role_assignments::deleted_at,
+        exercise_role_assignments::table,
+    > {
+        diesel::update(
+            exercise_role_assignments::table.filter(exercise_role_assignments::id.eq(self.id)),
+        )
+        .set(exercise_role_assignments::deleted_at.eq(diesel::dsl::now))
+    }
+}
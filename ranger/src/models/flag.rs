@@ -0,0 +1,364 @@
+use super::{helpers::uuid::Uuid, DeploymentSecret, Metric};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{flag_attempts, flags},
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+    utilities::score::is_entity_or_descendant,
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter, Gt},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Sha3_256};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewFlagResource {
+    pub metric_key: String,
+    pub entity_selector: Option<String>,
+    pub secret_definition_id: Option<Uuid>,
+    #[serde(default)]
+    pub answer: String,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone)]
+#[diesel(table_name = flags)]
+pub struct NewFlag {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub entity_selector: Option<String>,
+    pub secret_definition_id: Option<Uuid>,
+    pub answer_salt: Option<String>,
+    pub answer_hash: Option<String>,
+}
+
+impl NewFlag {
+    pub fn new(resource: NewFlagResource, exercise_id: Uuid) -> Self {
+        let (answer_salt, answer_hash) = match resource.secret_definition_id {
+            Some(_) => (None, None),
+            None => {
+                let answer_salt: String = thread_rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(32)
+                    .map(char::from)
+                    .collect();
+                let answer_hash = Flag::hash_answer(&answer_salt, &resource.answer);
+                (Some(answer_salt), Some(answer_hash))
+            }
+        };
+
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: resource.metric_key,
+            entity_selector: resource.entity_selector,
+            secret_definition_id: resource.secret_definition_id,
+            answer_salt,
+            answer_hash,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, flags::table> {
+        insert_into(flags::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = flags)]
+pub struct Flag {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub entity_selector: Option<String>,
+    pub secret_definition_id: Option<Uuid>,
+    #[serde(skip_serializing)]
+    pub answer_salt: Option<String>,
+    #[serde(skip_serializing)]
+    pub answer_hash: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<FilterExisting<T, flags::deleted_at>, Eq<flags::exercise_id, Uuid>>;
+type ByExerciseIdAndMetricKey<T> = Filter<ByExerciseId<T>, Eq<flags::metric_key, String>>;
+
+impl Flag {
+    fn all_with_deleted() -> All<flags::table, Self> {
+        flags::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<flags::table, Self>, flags::deleted_at> {
+        Self::all_with_deleted().filter(flags::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(id: Uuid) -> SelectById<flags::table, flags::id, flags::deleted_at, Self> {
+        Self::all().filter(flags::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<flags::table, Self>> {
+        Self::all().filter(flags::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> ByExerciseIdAndMetricKey<All<flags::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(flags::metric_key.eq(metric_key))
+    }
+
+    pub fn soft_delete(&self) -> SoftDeleteById<flags::id, flags::deleted_at, flags::table> {
+        diesel::update(flags::table.filter(flags::id.eq(self.id)))
+            .set(flags::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn hash_answer(salt: &str, answer: &str) -> String {
+        let mut hasher = Sha3_256::new();
+        hasher.update(salt.as_bytes());
+        hasher.update(answer.trim().as_bytes());
+        format!("{:x}", hasher.finalize())
+    }
+
+    pub fn applies_to(&self, entity_selector: &str) -> bool {
+        self.entity_selector
+            .as_ref()
+            .map_or(true, |flag_entity_selector| {
+                flag_entity_selector.eq(entity_selector)
+            })
+    }
+
+    pub fn matches(
+        &self,
+        answer: &str,
+        entity_selector: &str,
+        deployment_secrets: &[DeploymentSecret],
+    ) -> bool {
+        match (
+            self.secret_definition_id,
+            &self.answer_salt,
+            &self.answer_hash,
+        ) {
+            (Some(secret_definition_id), _, _) => {
+                deployment_secrets.iter().any(|deployment_secret| {
+                    deployment_secret.secret_definition_id == secret_definition_id
+                        && deployment_secret.entity_selector.as_ref().map_or(
+                            true,
+                            |secret_entity_selector| {
+                                is_entity_or_descendant(entity_selector, secret_entity_selector)
+                            },
+                        )
+                        && deployment_secret.value == answer.trim()
+                })
+            }
+            (None, Some(answer_salt), Some(answer_hash)) => {
+                Self::hash_answer(answer_salt, answer) == *answer_hash
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlagSubmissionResource {
+    pub metric_key: String,
+    pub role: String,
+    pub answer: String,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = flag_attempts)]
+pub struct NewFlagAttempt {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub metric_key: String,
+    pub entity_selector: String,
+    pub submitter_id: Option<String>,
+    pub answer: Option<String>,
+    pub is_correct: bool,
+}
+
+impl NewFlagAttempt {
+    pub fn new(
+        exercise_id: Uuid,
+        deployment_id: Uuid,
+        entity_selector: String,
+        submitter_id: Option<String>,
+        resource: &FlagSubmissionResource,
+        is_correct: bool,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            deployment_id,
+            metric_key: resource.metric_key.clone(),
+            entity_selector,
+            submitter_id,
+            answer: match is_correct {
+                true => None,
+                false => Some(resource.answer.clone()),
+            },
+            is_correct,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, flag_attempts::table> {
+        insert_into(flag_attempts::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = flag_attempts)]
+pub struct FlagAttempt {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub metric_key: String,
+    pub entity_selector: String,
+    pub submitter_id: Option<String>,
+    pub answer: Option<String>,
+    pub is_correct: bool,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByDeploymentId<T> =
+    Filter<FilterExisting<T, flag_attempts::deleted_at>, Eq<flag_attempts::deployment_id, Uuid>>;
+type ByDeploymentIdAndEntitySelector<T> =
+    Filter<ByDeploymentId<T>, Eq<flag_attempts::entity_selector, String>>;
+type RecentByEntitySelectorAndMetricKey<T> = Filter<
+    Filter<ByDeploymentIdAndEntitySelector<T>, Eq<flag_attempts::metric_key, String>>,
+    Gt<flag_attempts::created_at, NaiveDateTime>,
+>;
+
+impl FlagAttempt {
+    fn all_with_deleted() -> All<flag_attempts::table, Self> {
+        flag_attempts::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<flag_attempts::table, Self>, flag_attempts::deleted_at> {
+        Self::all_with_deleted().filter(flag_attempts::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_deployment_id(
+        deployment_id: Uuid,
+    ) -> ByDeploymentId<All<flag_attempts::table, Self>> {
+        Self::all().filter(flag_attempts::deployment_id.eq(deployment_id))
+    }
+
+    pub fn recent_by_entity_selector_and_metric_key(
+        deployment_id: Uuid,
+        entity_selector: String,
+        metric_key: String,
+        since: NaiveDateTime,
+    ) -> RecentByEntitySelectorAndMetricKey<All<flag_attempts::table, Self>> {
+        Self::by_deployment_id(deployment_id)
+            .filter(flag_attempts::entity_selector.eq(entity_selector))
+            .filter(flag_attempts::metric_key.eq(metric_key))
+            .filter(flag_attempts::created_at.gt(since))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlagSubmissionResult {
+    pub is_correct: bool,
+    pub metric: Option<Metric>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Flag, NewFlag, NewFlagResource};
+    use crate::{
+        constants::NAIVEDATETIME_DEFAULT_VALUE,
+        models::{helpers::uuid::Uuid, DeploymentSecret},
+    };
+
+    fn create_flag(secret_definition_id: Option<Uuid>, answer: &str) -> Flag {
+        let new_flag = NewFlag::new(
+            NewFlagResource {
+                metric_key: "flag-metric".to_string(),
+                entity_selector: None,
+                secret_definition_id,
+                answer: answer.to_string(),
+            },
+            Uuid::random(),
+        );
+
+        Flag {
+            id: new_flag.id,
+            exercise_id: new_flag.exercise_id,
+            metric_key: new_flag.metric_key,
+            entity_selector: new_flag.entity_selector,
+            secret_definition_id: new_flag.secret_definition_id,
+            answer_salt: new_flag.answer_salt,
+            answer_hash: new_flag.answer_hash,
+            created_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            updated_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            deleted_at: *NAIVEDATETIME_DEFAULT_VALUE,
+        }
+    }
+
+    fn create_deployment_secret(
+        secret_definition_id: Uuid,
+        entity_selector: Option<&str>,
+        value: &str,
+    ) -> DeploymentSecret {
+        DeploymentSecret {
+            id: Uuid::random(),
+            deployment_id: Uuid::random(),
+            secret_definition_id,
+            name: "FLAG".to_string(),
+            entity_selector: entity_selector.map(str::to_string),
+            value: value.to_string(),
+            created_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            updated_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            deleted_at: *NAIVEDATETIME_DEFAULT_VALUE,
+        }
+    }
+
+    #[test]
+    fn static_flags_match_the_hashed_answer() {
+        let flag = create_flag(None, "flag{static}");
+
+        assert!(flag.matches(" flag{static} ", "blue-team", &[]));
+        assert!(!flag.matches("flag{other}", "blue-team", &[]));
+    }
+
+    #[test]
+    fn secret_flags_match_the_entity_secret() {
+        let secret_definition_id = Uuid::random();
+        let flag = create_flag(Some(secret_definition_id), "");
+        let deployment_secrets = vec![
+            create_deployment_secret(secret_definition_id, Some("blue-team.alice"), "alice"),
+            create_deployment_secret(secret_definition_id, Some("blue-team.bob"), "bob"),
+        ];
+
+        assert!(flag.matches("alice", "blue-team.alice", &deployment_secrets));
+        assert!(!flag.matches("bob", "blue-team.alice", &deployment_secrets));
+        assert!(!flag.matches("", "blue-team.alice", &deployment_secrets));
+    }
+
+    #[test]
+    fn shared_secret_flags_match_every_entity() {
+        let secret_definition_id = Uuid::random();
+        let flag = create_flag(Some(secret_definition_id), "");
+        let deployment_secrets = vec![create_deployment_secret(
+            secret_definition_id,
+            None,
+            "shared",
+        )];
+
+        assert!(flag.matches("shared", "blue-team.alice", &deployment_secrets));
+        assert!(!flag.matches("shared", "blue-team.alice", &[]));
+    }
+}
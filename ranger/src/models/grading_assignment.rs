@@ -0,0 +1,394 @@
+use super::{helpers::uuid::Uuid, Metric};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::grading_assignments,
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById, UpdateById},
+};
+use chrono::{NaiveDateTime, Utc};
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, AsChangeset, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable,
+    SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewGradingAssignmentsResource {
+    pub grader_ids: Vec<String>,
+    pub disagreement_threshold: Option<u32>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = grading_assignments)]
+pub struct NewGradingAssignment {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub metric_id: Uuid,
+    pub grader_id: String,
+    pub disagreement_threshold: Option<u32>,
+}
+
+impl NewGradingAssignment {
+    pub fn new(metric: &Metric, grader_id: String, disagreement_threshold: Option<u32>) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id: metric.exercise_id,
+            deployment_id: metric.deployment_id,
+            metric_id: metric.id,
+            grader_id,
+            disagreement_threshold,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, grading_assignments::table> {
+        insert_into(grading_assignments::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = grading_assignments)]
+pub struct GradingAssignment {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub metric_id: Uuid,
+    pub grader_id: String,
+    pub disagreement_threshold: Option<u32>,
+    pub score: Option<u32>,
+    pub comment: Option<String>,
+    pub graded_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByMetricId<T> = Filter<
+    FilterExisting<T, grading_assignments::deleted_at>,
+    Eq<grading_assignments::metric_id, Uuid>,
+>;
+type ByDeploymentId<T> = Filter<
+    FilterExisting<T, grading_assignments::deleted_at>,
+    Eq<grading_assignments::deployment_id, Uuid>,
+>;
+type ByDeploymentIdAndGraderId<T> =
+    Filter<ByDeploymentId<T>, Eq<grading_assignments::grader_id, String>>;
+
+impl GradingAssignment {
+    fn all_with_deleted() -> All<grading_assignments::table, Self> {
+        grading_assignments::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<grading_assignments::table, Self>, grading_assignments::deleted_at>
+    {
+        Self::all_with_deleted()
+            .filter(grading_assignments::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        grading_assignments::table,
+        grading_assignments::id,
+        grading_assignments::deleted_at,
+        Self,
+    > {
+        Self::all().filter(grading_assignments::id.eq(id))
+    }
+
+    pub fn by_metric_id(metric_id: Uuid) -> ByMetricId<All<grading_assignments::table, Self>> {
+        Self::all().filter(grading_assignments::metric_id.eq(metric_id))
+    }
+
+    pub fn by_deployment_id(
+        deployment_id: Uuid,
+    ) -> ByDeploymentId<All<grading_assignments::table, Self>> {
+        Self::all().filter(grading_assignments::deployment_id.eq(deployment_id))
+    }
+
+    pub fn by_deployment_id_and_grader_id(
+        deployment_id: Uuid,
+        grader_id: String,
+    ) -> ByDeploymentIdAndGraderId<All<grading_assignments::table, Self>> {
+        Self::by_deployment_id(deployment_id).filter(grading_assignments::grader_id.eq(grader_id))
+    }
+
+    pub fn is_graded(&self) -> bool {
+        self.score.is_some()
+    }
+
+    fn without_grade(self) -> Self {
+        Self {
+            score: None,
+            comment: None,
+            ..self
+        }
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        grading_assignments::id,
+        grading_assignments::deleted_at,
+        grading_assignments::table,
+    > {
+        diesel::update(grading_assignments::table.filter(grading_assignments::id.eq(self.id)))
+            .set(grading_assignments::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(AsChangeset, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = grading_assignments, treat_none_as_null = true)]
+pub struct UpdateGradingAssignment {
+    pub disagreement_threshold: Option<u32>,
+}
+
+impl UpdateGradingAssignment {
+    pub fn create_update_by_metric_id(
+        &self,
+        metric_id: Uuid,
+    ) -> UpdateById<
+        grading_assignments::metric_id,
+        grading_assignments::deleted_at,
+        grading_assignments::table,
+        &Self,
+    > {
+        diesel::update(grading_assignments::table)
+            .filter(grading_assignments::metric_id.eq(metric_id))
+            .filter(grading_assignments::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+            .set(self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitGradingAssignmentResource {
+    pub score: u32,
+    pub comment: Option<String>,
+}
+
+#[derive(AsChangeset, Debug, PartialEq, Eq, Clone)]
+#[diesel(table_name = grading_assignments, treat_none_as_null = true)]
+pub struct SubmitGradingAssignment {
+    pub score: u32,
+    pub comment: Option<String>,
+    pub graded_at: NaiveDateTime,
+}
+
+impl From<SubmitGradingAssignmentResource> for SubmitGradingAssignment {
+    fn from(resource: SubmitGradingAssignmentResource) -> Self {
+        Self {
+            score: resource.score,
+            comment: resource.comment,
+            graded_at: Utc::now().naive_utc(),
+        }
+    }
+}
+
+impl SubmitGradingAssignment {
+    pub fn create_update(
+        &self,
+        id: Uuid,
+    ) -> UpdateById<
+        grading_assignments::id,
+        grading_assignments::deleted_at,
+        grading_assignments::table,
+        &Self,
+    > {
+        diesel::update(grading_assignments::table)
+            .filter(grading_assignments::id.eq(id))
+            .filter(grading_assignments::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+            .set(self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnonymisedSubmission {
+    pub assignment_id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub text_submission: Option<String>,
+    pub has_artifact: bool,
+    pub max_score: u32,
+    pub score: Option<u32>,
+    pub comment: Option<String>,
+    pub graded_at: Option<NaiveDateTime>,
+    pub submitted_at: NaiveDateTime,
+}
+
+impl From<(GradingAssignment, Metric)> for AnonymisedSubmission {
+    fn from((assignment, metric): (GradingAssignment, Metric)) -> Self {
+        Self {
+            assignment_id: assignment.id,
+            metric_key: metric.sdl_key,
+            name: metric.name,
+            description: metric.description,
+            text_submission: metric.text_submission,
+            has_artifact: metric.has_artifact,
+            max_score: metric.max_score,
+            score: assignment.score,
+            comment: assignment.comment,
+            graded_at: assignment.graded_at,
+            submitted_at: metric.created_at,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GradingReconciliationStatus {
+    Pending,
+    Reconciled,
+    Disagreement,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GradingReconciliation {
+    pub metric_id: Uuid,
+    pub status: GradingReconciliationStatus,
+    pub score: Option<u32>,
+    pub assignments: Vec<GradingAssignment>,
+}
+
+impl GradingReconciliation {
+    pub fn new(metric_id: Uuid, assignments: Vec<GradingAssignment>) -> Self {
+        let scores = assignments
+            .iter()
+            .filter_map(|assignment| assignment.score)
+            .collect::<Vec<u32>>();
+        let (status, score) = match (
+            scores.iter().min(),
+            scores.iter().max(),
+            scores.len() == assignments.len(),
+        ) {
+            (Some(min_score), Some(max_score), true) => {
+                let disagreement_threshold = assignments
+                    .iter()
+                    .filter_map(|assignment| assignment.disagreement_threshold)
+                    .min();
+                match disagreement_threshold {
+                    Some(threshold) if max_score - min_score > threshold => {
+                        (GradingReconciliationStatus::Disagreement, None)
+                    }
+                    _ => {
+                        let count = scores.len() as u32;
+                        let sum = scores.iter().sum::<u32>();
+                        (
+                            GradingReconciliationStatus::Reconciled,
+                            Some((sum * 2 + count) / (count * 2)),
+                        )
+                    }
+                }
+            }
+            _ => (GradingReconciliationStatus::Pending, None),
+        };
+        let assignments = match status {
+            GradingReconciliationStatus::Pending => assignments
+                .into_iter()
+                .map(GradingAssignment::without_grade)
+                .collect(),
+            _ => assignments,
+        };
+
+        Self {
+            metric_id,
+            status,
+            score,
+            assignments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GradingAssignment, GradingReconciliation, GradingReconciliationStatus};
+    use crate::{constants::NAIVEDATETIME_DEFAULT_VALUE, models::helpers::uuid::Uuid};
+
+    fn assignment(
+        metric_id: Uuid,
+        score: Option<u32>,
+        disagreement_threshold: Option<u32>,
+    ) -> GradingAssignment {
+        GradingAssignment {
+            id: Uuid::random(),
+            exercise_id: Uuid::random(),
+            deployment_id: Uuid::random(),
+            metric_id,
+            grader_id: "grader".to_string(),
+            disagreement_threshold,
+            score,
+            comment: score.map(|score| format!("Scored {score}")),
+            graded_at: score.map(|_| *NAIVEDATETIME_DEFAULT_VALUE),
+            created_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            updated_at: *NAIVEDATETIME_DEFAULT_VALUE,
+            deleted_at: *NAIVEDATETIME_DEFAULT_VALUE,
+        }
+    }
+
+    #[test]
+    fn reconciles_to_the_rounded_average() {
+        let metric_id = Uuid::random();
+        let reconciliation = GradingReconciliation::new(
+            metric_id,
+            vec![
+                assignment(metric_id, Some(7), Some(3)),
+                assignment(metric_id, Some(8), Some(3)),
+            ],
+        );
+
+        assert_eq!(
+            reconciliation.status,
+            GradingReconciliationStatus::Reconciled
+        );
+        assert_eq!(reconciliation.score, Some(8));
+    }
+
+    #[test]
+    fn reports_disagreement_above_the_threshold() {
+        let metric_id = Uuid::random();
+        let reconciliation = GradingReconciliation::new(
+            metric_id,
+            vec![
+                assignment(metric_id, Some(2), Some(3)),
+                assignment(metric_id, Some(9), None),
+            ],
+        );
+
+        assert_eq!(
+            reconciliation.status,
+            GradingReconciliationStatus::Disagreement
+        );
+        assert_eq!(reconciliation.score, None);
+        assert!(reconciliation
+            .assignments
+            .iter()
+            .all(|assignment| assignment.score.is_some()));
+    }
+
+    #[test]
+    fn hides_submitted_grades_until_every_grader_is_done() {
+        let metric_id = Uuid::random();
+        let reconciliation = GradingReconciliation::new(
+            metric_id,
+            vec![
+                assignment(metric_id, Some(5), None),
+                assignment(metric_id, None, None),
+            ],
+        );
+
+        assert_eq!(reconciliation.status, GradingReconciliationStatus::Pending);
+        assert!(reconciliation
+            .assignments
+            .iter()
+            .all(|assignment| { assignment.score.is_none() && assignment.comment.is_none() }));
+        assert!(reconciliation.assignments[0].graded_at.is_some());
+    }
+}
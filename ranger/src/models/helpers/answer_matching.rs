@@ -0,0 +1,34 @@
+use crate::models::AnswerMatching;
+use diesel::{
+    deserialize,
+    deserialize::FromSql,
+    mysql::{Mysql, MysqlValue},
+    serialize::{self},
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use std::io::Write;
+
+impl FromSql<Text, Mysql> for AnswerMatching {
+    fn from_sql(bytes: MysqlValue) -> deserialize::Result<Self> {
+        if let Ok(value) = <String>::from_sql(bytes) {
+            return match value.as_str() {
+                "exact" => Ok(AnswerMatching::Exact),
+                "regex" => Ok(AnswerMatching::Regex),
+                _ => Err("Invalid answer matching".into()),
+            };
+        }
+        Err("Failed to parse answer matching into string".into())
+    }
+}
+
+impl ToSql<Text, Mysql> for AnswerMatching {
+    fn to_sql(&self, out: &mut Output<Mysql>) -> serialize::Result {
+        let value = String::from(match self {
+            AnswerMatching::Exact => "exact",
+            AnswerMatching::Regex => "regex",
+        });
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
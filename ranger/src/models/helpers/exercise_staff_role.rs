@@ -0,0 +1,31 @@
+use crate::roles::ExerciseStaffRole;
+use diesel::{
+    deserialize,
+    deserialize::FromSql,
+    mysql::{Mysql, MysqlValue},
+    serialize::{self},
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use std::io::Write;
+
+impl FromSql<Text, Mysql> for ExerciseStaffRole {
+    fn from_sql(bytes: MysqlValue) -> deserialize::Result<Self> {
+        if let Ok(value) = <String>::from_sql(bytes) {
+            return match value.as_str() {
+                "observer" => Ok(ExerciseStaffRole::Observer),
+                "whiteTeam" => Ok(ExerciseStaffRole::WhiteTeam),
+                "grader" => Ok(ExerciseStaffRole::Grader),
+                _ => Err("Invalid exercise staff role".into()),
+            };
+        }
+        Err("Failed to parse exercise staff role into string".into())
+    }
+}
+
+impl ToSql<Text, Mysql> for ExerciseStaffRole {
+    fn to_sql(&self, out: &mut Output<Mysql>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,36 @@
+use crate::models::HintReleaseType;
+use diesel::{
+    deserialize,
+    deserialize::FromSql,
+    mysql::{Mysql, MysqlValue},
+    serialize::{self},
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use std::io::Write;
+
+impl FromSql<Text, Mysql> for HintReleaseType {
+    fn from_sql(bytes: MysqlValue) -> deserialize::Result<Self> {
+        if let Ok(value) = <String>::from_sql(bytes) {
+            return match value.as_str() {
+                "request" => Ok(HintReleaseType::Request),
+                "timer" => Ok(HintReleaseType::Timer),
+                "event" => Ok(HintReleaseType::Event),
+                _ => Err("Invalid hint release type".into()),
+            };
+        }
+        Err("Failed to parse hint release type into string".into())
+    }
+}
+
+impl ToSql<Text, Mysql> for HintReleaseType {
+    fn to_sql(&self, out: &mut Output<Mysql>) -> serialize::Result {
+        let value = String::from(match self {
+            HintReleaseType::Request => "request",
+            HintReleaseType::Timer => "timer",
+            HintReleaseType::Event => "event",
+        });
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
@@ -1,7 +1,12 @@
+pub mod answer_matching;
 pub mod deployer_type;
 pub mod element_status;
 pub mod email_status_name;
+pub mod exercise_staff_role;
 pub mod grpc_package;
+pub mod hint_release_type;
 pub mod order_status;
+pub mod question_type;
+pub mod scoring_strategy_type;
 pub mod uuid;
 pub mod websocket_wrapper;
@@ -0,0 +1,36 @@
+use crate::models::QuestionType;
+use diesel::{
+    deserialize,
+    deserialize::FromSql,
+    mysql::{Mysql, MysqlValue},
+    serialize::{self},
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use std::io::Write;
+
+impl FromSql<Text, Mysql> for QuestionType {
+    fn from_sql(bytes: MysqlValue) -> deserialize::Result<Self> {
+        if let Ok(value) = <String>::from_sql(bytes) {
+            return match value.as_str() {
+                "singleChoice" => Ok(QuestionType::SingleChoice),
+                "multipleChoice" => Ok(QuestionType::MultipleChoice),
+                "shortAnswer" => Ok(QuestionType::ShortAnswer),
+                _ => Err("Invalid question type".into()),
+            };
+        }
+        Err("Failed to parse question type into string".into())
+    }
+}
+
+impl ToSql<Text, Mysql> for QuestionType {
+    fn to_sql(&self, out: &mut Output<Mysql>) -> serialize::Result {
+        let value = String::from(match self {
+            QuestionType::SingleChoice => "singleChoice",
+            QuestionType::MultipleChoice => "multipleChoice",
+            QuestionType::ShortAnswer => "shortAnswer",
+        });
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,40 @@
+use crate::models::ScoringStrategyType;
+use diesel::{
+    deserialize,
+    deserialize::FromSql,
+    mysql::{Mysql, MysqlValue},
+    serialize::{self},
+    serialize::{IsNull, Output, ToSql},
+    sql_types::Text,
+};
+use std::io::Write;
+
+impl FromSql<Text, Mysql> for ScoringStrategyType {
+    fn from_sql(bytes: MysqlValue) -> deserialize::Result<Self> {
+        if let Ok(value) = <String>::from_sql(bytes) {
+            return match value.as_str() {
+                "value" => Ok(ScoringStrategyType::Value),
+                "availability" => Ok(ScoringStrategyType::Availability),
+                "firstSuccessBonus" => Ok(ScoringStrategyType::FirstSuccessBonus),
+                "decay" => Ok(ScoringStrategyType::Decay),
+                "penalty" => Ok(ScoringStrategyType::Penalty),
+                _ => Err("Invalid scoring strategy".into()),
+            };
+        }
+        Err("Failed to parse scoring strategy into string".into())
+    }
+}
+
+impl ToSql<Text, Mysql> for ScoringStrategyType {
+    fn to_sql(&self, out: &mut Output<Mysql>) -> serialize::Result {
+        let value = String::from(match self {
+            ScoringStrategyType::Value => "value",
+            ScoringStrategyType::Availability => "availability",
+            ScoringStrategyType::FirstSuccessBonus => "firstSuccessBonus",
+            ScoringStrategyType::Decay => "decay",
+            ScoringStrategyType::Penalty => "penalty",
+        });
+        out.write_all(value.as_bytes())?;
+        Ok(IsNull::No)
+    }
+}
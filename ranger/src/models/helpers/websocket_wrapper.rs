@@ -1,7 +1,9 @@
 use serde::Serialize;
 
 use super::uuid::Uuid;
-use crate::models::{Deployment, DeploymentElement, Event, Score, UpdateExercise};
+use crate::models::{
+    Deployment, DeploymentElement, EvaluationUpdate, Event, Score, UpdateExercise,
+};
 
 #[derive(Debug, Serialize)]
 pub enum MessageType {
@@ -11,6 +13,7 @@ pub enum MessageType {
     DeploymentElementUpdate,
     Score,
     Event,
+    Evaluation,
 }
 
 #[derive(Serialize)]
@@ -88,3 +91,15 @@ impl From<(Uuid, Uuid, Event)> for WebsocketWrapper<Event> {
         }
     }
 }
+
+impl From<(Uuid, Uuid, EvaluationUpdate)> for WebsocketWrapper<EvaluationUpdate> {
+    fn from(evaluation: (Uuid, Uuid, EvaluationUpdate)) -> Self {
+        let (exercise_id, own_id, content) = evaluation;
+        Self {
+            message_type: MessageType::Evaluation,
+            exercise_id,
+            own_id,
+            content,
+        }
+    }
+}
@@ -0,0 +1,259 @@
+use super::{helpers::uuid::Uuid, Deployment, Event};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{hint_releases, hints},
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDateTime};
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into,
+    sql_types::Text,
+    AsExpression, ExpressionMethods, FromSqlRow, Insertable, QueryDsl, Queryable, Selectable,
+    SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, FromSqlRow, AsExpression, Eq, Deserialize, Serialize, Default,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "camelCase")]
+pub enum HintReleaseType {
+    #[default]
+    Request,
+    Timer,
+    Event,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewHintResource {
+    pub title: String,
+    pub content: String,
+    pub metric_key: Option<String>,
+    pub training_objective_key: Option<String>,
+    pub cost: Option<BigDecimal>,
+    #[serde(default)]
+    pub release_type: HintReleaseType,
+    pub release_after_minutes: Option<u32>,
+    pub event_name: Option<String>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = hints)]
+pub struct NewHint {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: Option<String>,
+    pub training_objective_key: Option<String>,
+    pub title: String,
+    pub content: String,
+    pub cost: Option<BigDecimal>,
+    pub release_type: HintReleaseType,
+    pub release_after_minutes: Option<u32>,
+    pub event_name: Option<String>,
+}
+
+impl NewHint {
+    pub fn new(resource: NewHintResource, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: resource.metric_key,
+            training_objective_key: resource.training_objective_key,
+            title: resource.title,
+            content: resource.content,
+            cost: resource.cost,
+            release_type: resource.release_type,
+            release_after_minutes: resource.release_after_minutes,
+            event_name: resource.event_name,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, hints::table> {
+        insert_into(hints::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = hints)]
+pub struct Hint {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: Option<String>,
+    pub training_objective_key: Option<String>,
+    pub title: String,
+    pub content: String,
+    pub cost: Option<BigDecimal>,
+    pub release_type: HintReleaseType,
+    pub release_after_minutes: Option<u32>,
+    pub event_name: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<FilterExisting<T, hints::deleted_at>, Eq<hints::exercise_id, Uuid>>;
+
+impl Hint {
+    fn all_with_deleted() -> All<hints::table, Self> {
+        hints::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<hints::table, Self>, hints::deleted_at> {
+        Self::all_with_deleted().filter(hints::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(id: Uuid) -> SelectById<hints::table, hints::id, hints::deleted_at, Self> {
+        Self::all().filter(hints::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<hints::table, Self>> {
+        Self::all().filter(hints::exercise_id.eq(exercise_id))
+    }
+
+    pub fn soft_delete(&self) -> SoftDeleteById<hints::id, hints::deleted_at, hints::table> {
+        diesel::update(hints::table.filter(hints::id.eq(self.id)))
+            .set(hints::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn unlocks_at(&self, deployment: &Deployment, events: &[Event]) -> Option<NaiveDateTime> {
+        match self.release_type {
+            HintReleaseType::Request => Some(deployment.start),
+            HintReleaseType::Timer => Some(
+                deployment.start
+                    + Duration::minutes(self.release_after_minutes.unwrap_or_default() as i64),
+            ),
+            HintReleaseType::Event => self.event_name.as_ref().and_then(|event_name| {
+                events
+                    .iter()
+                    .find(|event| event.name.eq(event_name) && event.has_triggered)
+                    .map(|event| event.triggered_at)
+            }),
+        }
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = hint_releases)]
+pub struct NewHintRelease {
+    pub id: Uuid,
+    pub hint_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub penalty_id: Option<Uuid>,
+    pub requested_by: Option<String>,
+}
+
+impl NewHintRelease {
+    pub fn new(
+        hint_id: Uuid,
+        deployment_id: Uuid,
+        entity_selector: String,
+        penalty_id: Option<Uuid>,
+        requested_by: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            hint_id,
+            deployment_id,
+            entity_selector,
+            penalty_id,
+            requested_by,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, hint_releases::table> {
+        insert_into(hint_releases::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = hint_releases)]
+pub struct HintRelease {
+    pub id: Uuid,
+    pub hint_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub penalty_id: Option<Uuid>,
+    pub requested_by: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByDeploymentId<T> =
+    Filter<FilterExisting<T, hint_releases::deleted_at>, Eq<hint_releases::deployment_id, Uuid>>;
+type ByDeploymentIdAndEntitySelector<T> =
+    Filter<ByDeploymentId<T>, Eq<hint_releases::entity_selector, String>>;
+
+impl HintRelease {
+    fn all_with_deleted() -> All<hint_releases::table, Self> {
+        hint_releases::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<hint_releases::table, Self>, hint_releases::deleted_at> {
+        Self::all_with_deleted().filter(hint_releases::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_deployment_id(
+        deployment_id: Uuid,
+    ) -> ByDeploymentId<All<hint_releases::table, Self>> {
+        Self::all().filter(hint_releases::deployment_id.eq(deployment_id))
+    }
+
+    pub fn by_deployment_id_and_entity_selector(
+        deployment_id: Uuid,
+        entity_selector: String,
+    ) -> ByDeploymentIdAndEntitySelector<All<hint_releases::table, Self>> {
+        Self::by_deployment_id(deployment_id)
+            .filter(hint_releases::entity_selector.eq(entity_selector))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantHint {
+    pub id: Uuid,
+    pub title: String,
+    pub metric_key: Option<String>,
+    pub training_objective_key: Option<String>,
+    pub cost: Option<BigDecimal>,
+    pub release_type: HintReleaseType,
+    pub unlocks_at: Option<NaiveDateTime>,
+    pub is_unlocked: bool,
+    pub content: Option<String>,
+    pub taken_at: Option<NaiveDateTime>,
+}
+
+impl ParticipantHint {
+    pub fn new(
+        hint: Hint,
+        deployment: &Deployment,
+        events: &[Event],
+        hint_release: Option<&HintRelease>,
+        timestamp: NaiveDateTime,
+    ) -> Self {
+        let unlocks_at = hint.unlocks_at(deployment, events);
+        let taken_at = hint_release.map(|hint_release| hint_release.created_at);
+
+        Self {
+            id: hint.id,
+            title: hint.title,
+            metric_key: hint.metric_key,
+            training_objective_key: hint.training_objective_key,
+            cost: hint.cost,
+            release_type: hint.release_type,
+            unlocks_at,
+            is_unlocked: unlocks_at.is_some_and(|unlocks_at| timestamp >= unlocks_at),
+            content: taken_at.map(|_| hint.content),
+            taken_at,
+        }
+    }
+}
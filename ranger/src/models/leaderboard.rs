@@ -0,0 +1,284 @@
+use super::helpers::uuid::Uuid;
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use sdl_parser::entity::ExerciseRole;
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityScore {
+    pub entity_selector: String,
+    pub entity_name: Option<String>,
+    pub role: Option<ExerciseRole>,
+    pub score: BigDecimal,
+    pub last_scored_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    pub rank: usize,
+    pub deployment_id: Uuid,
+    pub deployment_name: String,
+    pub entity_selector: Option<String>,
+    pub entity_name: Option<String>,
+    pub role: Option<ExerciseRole>,
+    pub score: BigDecimal,
+    pub last_scored_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentLeaderboardEntry {
+    pub rank: usize,
+    pub deployment_id: Uuid,
+    pub deployment_name: String,
+    pub score: BigDecimal,
+    pub last_scored_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Leaderboard {
+    pub entities: Vec<LeaderboardEntry>,
+    pub deployments: Vec<DeploymentLeaderboardEntry>,
+}
+
+fn compare_standings(
+    (score, last_scored_at): (&BigDecimal, &Option<NaiveDateTime>),
+    (other_score, other_last_scored_at): (&BigDecimal, &Option<NaiveDateTime>),
+) -> Ordering {
+    other_score
+        .cmp(score)
+        .then_with(|| match (last_scored_at, other_last_scored_at) {
+            (Some(last_scored_at), Some(other_last_scored_at)) => {
+                last_scored_at.cmp(other_last_scored_at)
+            }
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        })
+}
+
+impl Leaderboard {
+    pub fn new(deployment_entity_scores: Vec<(Uuid, String, Vec<EntityScore>)>) -> Self {
+        let mut deployment_totals: HashMap<Uuid, DeploymentLeaderboardEntry> = HashMap::new();
+        let mut entities: Vec<LeaderboardEntry> = vec![];
+
+        for (deployment_id, deployment_name, entity_scores) in deployment_entity_scores {
+            let deployment_total = deployment_totals.entry(deployment_id).or_insert_with(|| {
+                DeploymentLeaderboardEntry {
+                    rank: 0,
+                    deployment_id,
+                    deployment_name: deployment_name.clone(),
+                    score: BigDecimal::from(0),
+                    last_scored_at: None,
+                }
+            });
+
+            for entity_score in entity_scores {
+                if !entity_score.entity_selector.contains('.') {
+                    deployment_total.score += entity_score.score.clone();
+                    deployment_total.last_scored_at = deployment_total
+                        .last_scored_at
+                        .max(entity_score.last_scored_at);
+                }
+
+                entities.push(LeaderboardEntry {
+                    rank: 0,
+                    deployment_id,
+                    deployment_name: deployment_name.clone(),
+                    entity_selector: Some(entity_score.entity_selector),
+                    entity_name: entity_score.entity_name,
+                    role: entity_score.role,
+                    score: entity_score.score,
+                    last_scored_at: entity_score.last_scored_at,
+                });
+            }
+        }
+
+        entities.sort_by(|entry, other_entry| {
+            compare_standings(
+                (&entry.score, &entry.last_scored_at),
+                (&other_entry.score, &other_entry.last_scored_at),
+            )
+        });
+        entities
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, entry)| entry.rank = index + 1);
+
+        let mut deployments: Vec<DeploymentLeaderboardEntry> =
+            deployment_totals.into_values().collect();
+        deployments.sort_by(|entry, other_entry| {
+            compare_standings(
+                (&entry.score, &entry.last_scored_at),
+                (&other_entry.score, &other_entry.last_scored_at),
+            )
+        });
+        deployments
+            .iter_mut()
+            .enumerate()
+            .for_each(|(index, entry)| entry.rank = index + 1);
+
+        Self {
+            entities,
+            deployments,
+        }
+    }
+
+    fn is_related_entity(entry: &LeaderboardEntry, entity_selector: &str) -> bool {
+        entry.entity_selector.as_ref().is_some_and(|selector| {
+            selector == entity_selector
+                || entity_selector.starts_with(&format!("{selector}."))
+                || selector.starts_with(&format!("{entity_selector}."))
+        })
+    }
+
+    pub fn hide_other_entities(mut self, entity_selector: &str) -> Self {
+        self.entities.iter_mut().for_each(|entry| {
+            if !Self::is_related_entity(entry, entity_selector) {
+                entry.entity_selector = None;
+                entry.entity_name = None;
+            }
+        });
+
+        self
+    }
+
+    pub fn hide_unowned_entities(mut self, own_entities: &[(Uuid, String)]) -> Self {
+        self.entities.iter_mut().for_each(|entry| {
+            let is_owned_entity = own_entities.iter().any(|(deployment_id, entity_selector)| {
+                entry.deployment_id == *deployment_id
+                    && Self::is_related_entity(entry, entity_selector)
+            });
+            if !is_owned_entity {
+                entry.entity_selector = None;
+                entry.entity_name = None;
+            }
+        });
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare_standings, EntityScore, Leaderboard};
+    use crate::models::helpers::uuid::Uuid;
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, NaiveDateTime};
+    use std::cmp::Ordering;
+
+    fn scored_at(seconds: i64) -> Option<NaiveDateTime> {
+        Some(NaiveDateTime::default() + Duration::seconds(seconds))
+    }
+
+    fn entity_score(entity_selector: &str, score: u32, seconds: i64) -> EntityScore {
+        EntityScore {
+            entity_selector: entity_selector.to_string(),
+            entity_name: Some(entity_selector.to_string()),
+            role: None,
+            score: BigDecimal::from(score),
+            last_scored_at: scored_at(seconds),
+        }
+    }
+
+    #[test]
+    fn higher_score_ranks_first() {
+        assert_eq!(
+            compare_standings(
+                (&BigDecimal::from(10), &scored_at(60)),
+                (&BigDecimal::from(5), &scored_at(0))
+            ),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn earlier_score_breaks_ties() {
+        let score = BigDecimal::from(10);
+
+        assert_eq!(
+            compare_standings((&score, &scored_at(0)), (&score, &scored_at(60))),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_standings((&score, &None), (&score, &scored_at(60))),
+            Ordering::Greater
+        );
+        assert_eq!(
+            compare_standings((&score, &None), (&score, &None)),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn ranks_entities_and_deployments() {
+        let first_deployment_id = Uuid::random();
+        let second_deployment_id = Uuid::random();
+        let leaderboard = Leaderboard::new(vec![
+            (
+                first_deployment_id,
+                "first".to_string(),
+                vec![
+                    entity_score("blue", 10, 60),
+                    entity_score("blue.bob", 10, 30),
+                ],
+            ),
+            (
+                second_deployment_id,
+                "second".to_string(),
+                vec![entity_score("blue", 10, 0)],
+            ),
+        ]);
+
+        assert_eq!(
+            leaderboard
+                .entities
+                .iter()
+                .map(|entry| (entry.rank, entry.deployment_id))
+                .collect::<Vec<_>>(),
+            vec![
+                (1, second_deployment_id),
+                (2, first_deployment_id),
+                (3, first_deployment_id)
+            ]
+        );
+        assert_eq!(
+            leaderboard.entities[1].entity_selector.as_deref(),
+            Some("blue.bob")
+        );
+        assert_eq!(leaderboard.deployments[0].rank, 1);
+        assert_eq!(
+            leaderboard.deployments[0].deployment_id,
+            second_deployment_id
+        );
+        assert_eq!(leaderboard.deployments[1].score, BigDecimal::from(10));
+    }
+
+    #[test]
+    fn hides_unowned_entities() {
+        let deployment_id = Uuid::random();
+        let leaderboard = Leaderboard::new(vec![(
+            deployment_id,
+            "deployment".to_string(),
+            vec![
+                entity_score("blue", 30, 0),
+                entity_score("blue.bob", 20, 0),
+                entity_score("red", 10, 0),
+            ],
+        )])
+        .hide_unowned_entities(&[(deployment_id, "blue.bob".to_string())]);
+
+        assert_eq!(
+            leaderboard
+                .entities
+                .iter()
+                .map(|entry| entry.entity_selector.as_deref())
+                .collect::<Vec<_>>(),
+            vec![Some("blue"), Some("blue.bob"), None]
+        );
+    }
+}
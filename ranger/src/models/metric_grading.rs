@@ -0,0 +1,101 @@
+use super::{helpers::uuid::Uuid, UpdateMetric};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::metric_gradings,
+    services::database::{All, Create, FilterExisting, SelectById},
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GradeMetricResource {
+    #[serde(flatten)]
+    pub update_metric: UpdateMetric,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RevertMetricGradingResource {
+    pub comment: Option<String>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = metric_gradings)]
+pub struct NewMetricGrading {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub grader_id: String,
+    pub previous_score: Option<u32>,
+    pub score: u32,
+    pub comment: Option<String>,
+}
+
+impl NewMetricGrading {
+    pub fn new(
+        metric_id: Uuid,
+        grader_id: String,
+        previous_score: Option<u32>,
+        score: u32,
+        comment: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            metric_id,
+            grader_id,
+            previous_score,
+            score,
+            comment,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, metric_gradings::table> {
+        insert_into(metric_gradings::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = metric_gradings)]
+pub struct MetricGrading {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub grader_id: String,
+    pub previous_score: Option<u32>,
+    pub score: u32,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByMetricId<T> =
+    Filter<FilterExisting<T, metric_gradings::deleted_at>, Eq<metric_gradings::metric_id, Uuid>>;
+
+impl MetricGrading {
+    fn all_with_deleted() -> All<metric_gradings::table, Self> {
+        metric_gradings::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<metric_gradings::table, Self>, metric_gradings::deleted_at> {
+        Self::all_with_deleted()
+            .filter(metric_gradings::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<metric_gradings::table, metric_gradings::id, metric_gradings::deleted_at, Self>
+    {
+        Self::all().filter(metric_gradings::id.eq(id))
+    }
+
+    pub fn by_metric_id(metric_id: Uuid) -> ByMetricId<All<metric_gradings::table, Self>> {
+        Self::all().filter(metric_gradings::metric_id.eq(metric_id))
+    }
+}
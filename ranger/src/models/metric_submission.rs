@@ -0,0 +1,112 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::metric_submissions,
+    services::database::{All, Create, FilterExisting},
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = metric_submissions)]
+pub struct NewMetricSubmission {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub version: u32,
+    pub text_submission: Option<String>,
+    pub artifact_id: Option<Uuid>,
+}
+
+impl NewMetricSubmission {
+    pub fn new(
+        metric_id: Uuid,
+        version: u32,
+        text_submission: Option<String>,
+        artifact_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            metric_id,
+            version,
+            text_submission,
+            artifact_id,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, metric_submissions::table> {
+        insert_into(metric_submissions::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = metric_submissions)]
+pub struct MetricSubmission {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub version: u32,
+    pub text_submission: Option<String>,
+    pub artifact_id: Option<Uuid>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByMetricId<T> = Filter<
+    FilterExisting<T, metric_submissions::deleted_at>,
+    Eq<metric_submissions::metric_id, Uuid>,
+>;
+type ByMetricIdAndVersion<T> = Filter<ByMetricId<T>, Eq<metric_submissions::version, u32>>;
+
+impl MetricSubmission {
+    fn all_with_deleted() -> All<metric_submissions::table, Self> {
+        metric_submissions::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<metric_submissions::table, Self>, metric_submissions::deleted_at> {
+        Self::all_with_deleted()
+            .filter(metric_submissions::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_metric_id(metric_id: Uuid) -> ByMetricId<All<metric_submissions::table, Self>> {
+        Self::all().filter(metric_submissions::metric_id.eq(metric_id))
+    }
+
+    pub fn by_metric_id_and_version(
+        metric_id: Uuid,
+        version: u32,
+    ) -> ByMetricIdAndVersion<All<metric_submissions::table, Self>> {
+        Self::by_metric_id(metric_id).filter(metric_submissions::version.eq(version))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DiffLineKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub content: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSubmissionDiff {
+    pub metric_id: Uuid,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub lines: Vec<DiffLine>,
+    pub artifact_changed: bool,
+}
@@ -1,29 +1,65 @@
 mod account;
+mod analytics;
+mod api_token;
 mod banner;
+mod certificate;
 mod condition;
 mod deployment;
+mod deployment_secret;
 mod email;
 mod email_status;
+mod evaluation;
 mod event;
 pub mod event_info;
 mod exercise;
+mod exercise_role_assignment;
+mod flag;
+mod grading_assignment;
 pub(crate) mod helpers;
+mod hint;
+mod leaderboard;
 pub mod metric;
+mod metric_grading;
+mod metric_submission;
 mod order;
 mod participant;
+mod penalty;
+mod questionnaire;
+mod report;
+mod rubric;
 pub mod score;
+mod scoring_strategy;
+mod submission_window;
 pub mod upload;
 pub mod user;
 
 pub use account::*;
+pub use analytics::*;
+pub use api_token::*;
 pub use banner::*;
+pub use certificate::*;
 pub use condition::*;
 pub use deployment::*;
+pub use deployment_secret::*;
 pub use email::*;
 pub use email_status::*;
+pub use evaluation::*;
 pub use event::*;
 pub use exercise::*;
+pub use exercise_role_assignment::*;
+pub use flag::*;
+pub use grading_assignment::*;
+pub use hint::*;
+pub use leaderboard::*;
 pub use metric::*;
+pub use metric_grading::*;
+pub use metric_submission::*;
 pub use order::*;
 pub use participant::*;
+pub use penalty::*;
+pub use questionnaire::*;
+pub use report::*;
+pub use rubric::*;
 pub use score::*;
+pub use scoring_strategy::*;
+pub use submission_window::*;
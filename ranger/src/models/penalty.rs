@@ -0,0 +1,103 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::penalties,
+    services::database::{All, Create, FilterExisting, SelectById, SoftDeleteById},
+};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewPenaltyResource {
+    pub entity_selector: String,
+    pub value: BigDecimal,
+    pub reason: String,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = penalties)]
+pub struct NewPenalty {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub value: BigDecimal,
+    pub reason: String,
+    pub issued_by: Option<String>,
+}
+
+impl NewPenalty {
+    pub fn new(
+        resource: NewPenaltyResource,
+        exercise_id: Uuid,
+        deployment_id: Uuid,
+        issued_by: Option<String>,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            deployment_id,
+            entity_selector: resource.entity_selector,
+            value: resource.value.abs(),
+            reason: resource.reason,
+            issued_by,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, penalties::table> {
+        insert_into(penalties::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = penalties)]
+pub struct Penalty {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub entity_selector: String,
+    pub value: BigDecimal,
+    pub reason: String,
+    pub issued_by: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByDeploymentId<T> =
+    Filter<FilterExisting<T, penalties::deleted_at>, Eq<penalties::deployment_id, Uuid>>;
+
+impl Penalty {
+    fn all_with_deleted() -> All<penalties::table, Self> {
+        penalties::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<penalties::table, Self>, penalties::deleted_at> {
+        Self::all_with_deleted().filter(penalties::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<penalties::table, penalties::id, penalties::deleted_at, Self> {
+        Self::all().filter(penalties::id.eq(id))
+    }
+
+    pub fn by_deployment_id(deployment_id: Uuid) -> ByDeploymentId<All<penalties::table, Self>> {
+        Self::all().filter(penalties::deployment_id.eq(deployment_id))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<penalties::id, penalties::deleted_at, penalties::table> {
+        diesel::update(penalties::table.filter(penalties::id.eq(self.id)))
+            .set(penalties::deleted_at.eq(diesel::dsl::now))
+    }
+}
@@ -0,0 +1,669 @@
+use super::{helpers::uuid::Uuid, Metric};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{questionnaire_choices, questionnaire_questions, questionnaires},
+    services::database::{
+        All, Create, FilterExisting, SelectById, SelectByIdFromAllReference, SoftDelete,
+        SoftDeleteById,
+    },
+};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into,
+    sql_types::Text,
+    AsExpression, Associations, ExpressionMethods, FromSqlRow, Identifiable, Insertable, QueryDsl,
+    Queryable, Selectable, SelectableHelper,
+};
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug, PartialEq, FromSqlRow, AsExpression, Eq, Deserialize, Serialize)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "camelCase")]
+pub enum QuestionType {
+    SingleChoice,
+    MultipleChoice,
+    ShortAnswer,
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, FromSqlRow, AsExpression, Eq, Deserialize, Serialize, Default,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "camelCase")]
+pub enum AnswerMatching {
+    #[default]
+    Exact,
+    Regex,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionnaireChoiceRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub text: String,
+    #[serde(default)]
+    pub is_correct: bool,
+}
+
+impl From<QuestionnaireChoice> for QuestionnaireChoiceRest {
+    fn from(choice: QuestionnaireChoice) -> Self {
+        Self {
+            id: choice.id,
+            text: choice.text,
+            is_correct: choice.is_correct,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionnaireQuestionRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub prompt: String,
+    pub question_type: QuestionType,
+    #[serde(default)]
+    pub choices: Vec<QuestionnaireChoiceRest>,
+    pub expected_answer: Option<String>,
+    #[serde(default)]
+    pub answer_matching: AnswerMatching,
+    #[serde(default)]
+    pub case_sensitive: bool,
+    pub points: BigDecimal,
+}
+
+impl From<(QuestionnaireQuestion, Vec<QuestionnaireChoiceRest>)> for QuestionnaireQuestionRest {
+    fn from((question, choices): (QuestionnaireQuestion, Vec<QuestionnaireChoiceRest>)) -> Self {
+        Self {
+            id: question.id,
+            prompt: question.prompt,
+            question_type: question.question_type,
+            choices,
+            expected_answer: question.expected_answer,
+            answer_matching: question.answer_matching,
+            case_sensitive: question.case_sensitive,
+            points: question.points,
+        }
+    }
+}
+
+impl QuestionnaireQuestionRest {
+    pub fn answer_pattern(&self) -> Option<Result<Regex, regex::Error>> {
+        self.expected_answer.as_ref().map(|expected_answer| {
+            let pattern = match self.answer_matching {
+                AnswerMatching::Exact => regex::escape(expected_answer.trim()),
+                AnswerMatching::Regex => expected_answer.to_owned(),
+            };
+            RegexBuilder::new(&format!("^(?:{pattern})$"))
+                .case_insensitive(!self.case_sensitive)
+                .build()
+        })
+    }
+
+    pub fn is_correct(&self, answer: &QuestionAnswer) -> bool {
+        match self.question_type {
+            QuestionType::SingleChoice | QuestionType::MultipleChoice => {
+                let correct_choice_ids = self
+                    .choices
+                    .iter()
+                    .filter(|choice| choice.is_correct)
+                    .map(|choice| choice.id)
+                    .collect::<HashSet<Uuid>>();
+                let selected_choice_ids =
+                    answer.choice_ids.iter().cloned().collect::<HashSet<Uuid>>();
+
+                !correct_choice_ids.is_empty() && correct_choice_ids == selected_choice_ids
+            }
+            QuestionType::ShortAnswer => match (&answer.text, self.answer_pattern()) {
+                (Some(text), Some(Ok(pattern))) => pattern.is_match(text.trim()),
+                _ => false,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionnaireRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub questions: Vec<QuestionnaireQuestionRest>,
+}
+
+impl From<(Questionnaire, Vec<QuestionnaireQuestionRest>)> for QuestionnaireRest {
+    fn from((questionnaire, questions): (Questionnaire, Vec<QuestionnaireQuestionRest>)) -> Self {
+        Self {
+            id: questionnaire.id,
+            metric_key: questionnaire.metric_key,
+            name: questionnaire.name,
+            questions,
+        }
+    }
+}
+
+impl QuestionnaireRest {
+    pub fn grade(&self, answers: &[QuestionAnswer]) -> Vec<QuestionResult> {
+        self.questions
+            .iter()
+            .map(|question| {
+                let answer = answers
+                    .iter()
+                    .find(|answer| answer.question_id == question.id);
+                let is_correct = answer.is_some_and(|answer| question.is_correct(answer));
+
+                QuestionResult {
+                    question_id: question.id,
+                    is_correct,
+                    points: match is_correct {
+                        true => question.points.clone(),
+                        false => BigDecimal::from(0),
+                    },
+                }
+            })
+            .collect()
+    }
+
+    pub fn calculate_score(&self, results: &[QuestionResult], max_score: u32) -> Option<u32> {
+        let total_points = self
+            .questions
+            .iter()
+            .fold(BigDecimal::from(0), |total, question| {
+                total + &question.points
+            });
+        if total_points <= BigDecimal::from(0) {
+            return None;
+        }
+        let earned_points = results
+            .iter()
+            .fold(BigDecimal::from(0), |total, result| total + &result.points);
+
+        (earned_points / total_points * BigDecimal::from(max_score))
+            .round(0)
+            .to_u32()
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = questionnaires)]
+pub struct NewQuestionnaire {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+}
+
+impl NewQuestionnaire {
+    pub fn new(questionnaire_rest: &QuestionnaireRest, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: questionnaire_rest.metric_key.clone(),
+            name: questionnaire_rest.name.clone(),
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, questionnaires::table> {
+        insert_into(questionnaires::table).values(self)
+    }
+}
+
+#[derive(
+    Identifiable, Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = questionnaires)]
+pub struct Questionnaire {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> =
+    Filter<FilterExisting<T, questionnaires::deleted_at>, Eq<questionnaires::exercise_id, Uuid>>;
+type ByExerciseIdAndMetricKey<T> = Filter<ByExerciseId<T>, Eq<questionnaires::metric_key, String>>;
+
+impl Questionnaire {
+    fn all_with_deleted() -> All<questionnaires::table, Self> {
+        questionnaires::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<questionnaires::table, Self>, questionnaires::deleted_at> {
+        Self::all_with_deleted().filter(questionnaires::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<questionnaires::table, questionnaires::id, questionnaires::deleted_at, Self>
+    {
+        Self::all().filter(questionnaires::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<questionnaires::table, Self>> {
+        Self::all().filter(questionnaires::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> ByExerciseIdAndMetricKey<All<questionnaires::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(questionnaires::metric_key.eq(metric_key))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<questionnaires::id, questionnaires::deleted_at, questionnaires::table> {
+        diesel::update(questionnaires::table.filter(questionnaires::id.eq(self.id)))
+            .set(questionnaires::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn soft_delete_by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> SoftDelete<ByExerciseIdAndMetricKey<questionnaires::table>, questionnaires::deleted_at>
+    {
+        diesel::update(
+            questionnaires::table
+                .filter(questionnaires::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(questionnaires::exercise_id.eq(exercise_id))
+                .filter(questionnaires::metric_key.eq(metric_key)),
+        )
+        .set(questionnaires::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Associations,
+    Queryable,
+    Selectable,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(belongs_to(Questionnaire, foreign_key = questionnaire_id))]
+#[diesel(table_name = questionnaire_questions)]
+pub struct QuestionnaireQuestion {
+    pub id: Uuid,
+    pub questionnaire_id: Uuid,
+    pub prompt: String,
+    pub question_type: QuestionType,
+    pub expected_answer: Option<String>,
+    pub answer_matching: AnswerMatching,
+    pub case_sensitive: bool,
+    pub points: BigDecimal,
+    pub position: u32,
+}
+
+impl QuestionnaireQuestion {
+    pub fn new(
+        questionnaire_id: Uuid,
+        position: u32,
+        question: &QuestionnaireQuestionRest,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            questionnaire_id,
+            prompt: question.prompt.clone(),
+            question_type: question.question_type,
+            expected_answer: question.expected_answer.clone(),
+            answer_matching: question.answer_matching,
+            case_sensitive: question.case_sensitive,
+            points: question.points.clone(),
+            position,
+        }
+    }
+
+    pub fn by_questionnaire(
+        questionnaire: &Questionnaire,
+    ) -> SelectByIdFromAllReference<
+        questionnaire_questions::table,
+        questionnaire_questions::questionnaire_id,
+        Self,
+    > {
+        Self::belonging_to(questionnaire).select(Self::as_select())
+    }
+
+    pub fn batch_insert(questions: Vec<Self>) -> Create<Vec<Self>, questionnaire_questions::table> {
+        insert_into(questionnaire_questions::table).values(questions)
+    }
+}
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Associations,
+    Queryable,
+    Selectable,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(belongs_to(QuestionnaireQuestion, foreign_key = questionnaire_question_id))]
+#[diesel(table_name = questionnaire_choices)]
+pub struct QuestionnaireChoice {
+    pub id: Uuid,
+    pub questionnaire_question_id: Uuid,
+    pub text: String,
+    pub is_correct: bool,
+    pub position: u32,
+}
+
+impl QuestionnaireChoice {
+    pub fn new(
+        questionnaire_question_id: Uuid,
+        position: u32,
+        choice: &QuestionnaireChoiceRest,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            questionnaire_question_id,
+            text: choice.text.clone(),
+            is_correct: choice.is_correct,
+            position,
+        }
+    }
+
+    pub fn by_question(
+        question: &QuestionnaireQuestion,
+    ) -> SelectByIdFromAllReference<
+        questionnaire_choices::table,
+        questionnaire_choices::questionnaire_question_id,
+        Self,
+    > {
+        Self::belonging_to(question).select(Self::as_select())
+    }
+
+    pub fn batch_insert(choices: Vec<Self>) -> Create<Vec<Self>, questionnaire_choices::table> {
+        insert_into(questionnaire_choices::table).values(choices)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantQuestionnaireChoice {
+    pub id: Uuid,
+    pub text: String,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantQuestionnaireQuestion {
+    pub id: Uuid,
+    pub prompt: String,
+    pub question_type: QuestionType,
+    pub choices: Vec<ParticipantQuestionnaireChoice>,
+    pub points: BigDecimal,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantQuestionnaire {
+    pub id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub questions: Vec<ParticipantQuestionnaireQuestion>,
+}
+
+impl From<QuestionnaireRest> for ParticipantQuestionnaire {
+    fn from(questionnaire: QuestionnaireRest) -> Self {
+        Self {
+            id: questionnaire.id,
+            metric_key: questionnaire.metric_key,
+            name: questionnaire.name,
+            questions: questionnaire
+                .questions
+                .into_iter()
+                .map(|question| ParticipantQuestionnaireQuestion {
+                    id: question.id,
+                    prompt: question.prompt,
+                    question_type: question.question_type,
+                    choices: question
+                        .choices
+                        .into_iter()
+                        .map(|choice| ParticipantQuestionnaireChoice {
+                            id: choice.id,
+                            text: choice.text,
+                        })
+                        .collect(),
+                    points: question.points,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionAnswer {
+    pub question_id: Uuid,
+    #[serde(default)]
+    pub choice_ids: Vec<Uuid>,
+    pub text: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionnaireSubmissionResource {
+    pub metric_key: String,
+    pub role: String,
+    pub answers: Vec<QuestionAnswer>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionResult {
+    pub question_id: Uuid,
+    pub is_correct: bool,
+    pub points: BigDecimal,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionnaireSubmissionResult {
+    pub metric: Metric,
+    pub results: Vec<QuestionResult>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AnswerMatching, QuestionAnswer, QuestionType, QuestionnaireChoiceRest,
+        QuestionnaireQuestionRest, QuestionnaireRest,
+    };
+    use crate::models::helpers::uuid::Uuid;
+    use bigdecimal::BigDecimal;
+
+    fn choice(is_correct: bool) -> QuestionnaireChoiceRest {
+        QuestionnaireChoiceRest {
+            id: Uuid::random(),
+            text: "Choice".to_string(),
+            is_correct,
+        }
+    }
+
+    fn question(
+        question_type: QuestionType,
+        choices: Vec<QuestionnaireChoiceRest>,
+        expected_answer: Option<&str>,
+        answer_matching: AnswerMatching,
+        points: u32,
+    ) -> QuestionnaireQuestionRest {
+        QuestionnaireQuestionRest {
+            id: Uuid::random(),
+            prompt: "Question".to_string(),
+            question_type,
+            choices,
+            expected_answer: expected_answer.map(str::to_string),
+            answer_matching,
+            case_sensitive: false,
+            points: BigDecimal::from(points),
+        }
+    }
+
+    fn text_answer(question: &QuestionnaireQuestionRest, text: &str) -> QuestionAnswer {
+        QuestionAnswer {
+            question_id: question.id,
+            choice_ids: vec![],
+            text: Some(text.to_string()),
+        }
+    }
+
+    fn choice_answer(
+        question: &QuestionnaireQuestionRest,
+        choice_ids: Vec<Uuid>,
+    ) -> QuestionAnswer {
+        QuestionAnswer {
+            question_id: question.id,
+            choice_ids,
+            text: None,
+        }
+    }
+
+    #[test]
+    fn multiple_choice_requires_exactly_the_correct_choices() {
+        let choices = vec![choice(true), choice(true), choice(false)];
+        let question = question(
+            QuestionType::MultipleChoice,
+            choices.clone(),
+            None,
+            AnswerMatching::Exact,
+            1,
+        );
+
+        assert!(question.is_correct(&choice_answer(
+            &question,
+            vec![choices[1].id, choices[0].id]
+        )));
+        assert!(!question.is_correct(&choice_answer(&question, vec![choices[0].id])));
+        assert!(!question.is_correct(&choice_answer(
+            &question,
+            choices.iter().map(|choice| choice.id).collect()
+        )));
+    }
+
+    #[test]
+    fn choice_question_without_correct_choices_is_never_correct() {
+        let question = question(
+            QuestionType::SingleChoice,
+            vec![choice(false)],
+            None,
+            AnswerMatching::Exact,
+            1,
+        );
+
+        assert!(!question.is_correct(&choice_answer(&question, vec![])));
+    }
+
+    #[test]
+    fn exact_short_answer_is_trimmed_case_insensitive_and_literal() {
+        let question = question(
+            QuestionType::ShortAnswer,
+            vec![],
+            Some(" 10.0.0.1 "),
+            AnswerMatching::Exact,
+            1,
+        );
+
+        assert!(question.is_correct(&text_answer(&question, "10.0.0.1\n")));
+        assert!(!question.is_correct(&text_answer(&question, "10a0b0c1")));
+
+        let question = QuestionnaireQuestionRest {
+            expected_answer: Some("Nmap".to_string()),
+            ..question
+        };
+        assert!(question.is_correct(&text_answer(&question, "nmap")));
+    }
+
+    #[test]
+    fn regex_short_answer_must_match_whole_answer() {
+        let question = question(
+            QuestionType::ShortAnswer,
+            vec![],
+            Some("port (22|443)"),
+            AnswerMatching::Regex,
+            1,
+        );
+
+        assert!(question.is_correct(&text_answer(&question, "Port 443")));
+        assert!(!question.is_correct(&text_answer(&question, "port 4433")));
+    }
+
+    #[test]
+    fn invalid_regex_is_never_correct() {
+        let question = question(
+            QuestionType::ShortAnswer,
+            vec![],
+            Some("("),
+            AnswerMatching::Regex,
+            1,
+        );
+
+        assert!(!question.is_correct(&text_answer(&question, "(")));
+    }
+
+    #[test]
+    fn grades_and_scores_questionnaire() {
+        let first_question = question(
+            QuestionType::ShortAnswer,
+            vec![],
+            Some("yes"),
+            AnswerMatching::Exact,
+            1,
+        );
+        let second_question = question(
+            QuestionType::ShortAnswer,
+            vec![],
+            Some("no"),
+            AnswerMatching::Exact,
+            2,
+        );
+        let questionnaire = QuestionnaireRest {
+            id: Uuid::random(),
+            metric_key: "metric".to_string(),
+            name: None,
+            questions: vec![first_question.clone(), second_question],
+        };
+
+        let results = questionnaire.grade(&[text_answer(&first_question, "yes")]);
+
+        assert_eq!(results.len(), 2);
+        assert!(results[0].is_correct);
+        assert_eq!(results[0].points, BigDecimal::from(1));
+        assert!(!results[1].is_correct);
+        assert_eq!(results[1].points, BigDecimal::from(0));
+        assert_eq!(questionnaire.calculate_score(&results, 30), Some(10));
+    }
+
+    #[test]
+    fn questionnaire_without_points_has_no_score() {
+        let questionnaire = QuestionnaireRest {
+            id: Uuid::random(),
+            metric_key: "metric".to_string(),
+            name: None,
+            questions: vec![],
+        };
+
+        assert_eq!(questionnaire.calculate_score(&[], 10), None);
+    }
+}
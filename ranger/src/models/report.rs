@@ -0,0 +1,553 @@
+use super::{
+    helpers::deployer_type::DeployerType, Deployment, DeploymentElement, ElementStatus,
+    EntityEvaluation, EntityScore, Event, Exercise, Metric, MetricGrading,
+};
+use chrono::NaiveDateTime;
+use ranger_grpc::capabilities::DeployerType as GrpcDeployerType;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReportFormat {
+    Html,
+    Markdown,
+    Pdf,
+}
+
+impl ReportFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "html" => Some(Self::Html),
+            "markdown" | "md" => Some(Self::Markdown),
+            "pdf" => Some(Self::Pdf),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Html => "text/html; charset=utf-8",
+            Self::Markdown => "text/markdown; charset=utf-8",
+            Self::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn file_extension(&self) -> &'static str {
+        match self {
+            Self::Html => "html",
+            Self::Markdown => "md",
+            Self::Pdf => "pdf",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportEvent {
+    pub name: String,
+    pub description: Option<String>,
+    pub start: NaiveDateTime,
+    pub end: NaiveDateTime,
+    pub triggered_at: Option<NaiveDateTime>,
+}
+
+impl From<&Event> for ReportEvent {
+    fn from(event: &Event) -> Self {
+        Self {
+            name: event.name.clone(),
+            description: event.description.clone(),
+            start: event.start,
+            end: event.end,
+            triggered_at: event.has_triggered.then_some(event.triggered_at),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportInject {
+    pub name: String,
+    pub event_name: Option<String>,
+    pub delivered_at: Option<NaiveDateTime>,
+    pub status: ElementStatus,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportGradingComment {
+    pub metric_name: String,
+    pub entity_selector: String,
+    pub grader_id: String,
+    pub previous_score: Option<u32>,
+    pub score: u32,
+    pub comment: String,
+    pub graded_at: NaiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportFailure {
+    pub name: String,
+    pub deployer_type: DeployerType,
+    pub status: ElementStatus,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AfterActionReport {
+    pub exercise_name: String,
+    pub deployment_name: String,
+    pub deployment_start: NaiveDateTime,
+    pub deployment_end: NaiveDateTime,
+    pub generated_at: NaiveDateTime,
+    pub events: Vec<ReportEvent>,
+    pub injects: Vec<ReportInject>,
+    pub entity_scores: Vec<EntityScore>,
+    pub evaluations: Vec<EntityEvaluation>,
+    pub grading_comments: Vec<ReportGradingComment>,
+    pub failures: Vec<ReportFailure>,
+}
+
+fn format_timestamp(timestamp: Option<NaiveDateTime>) -> String {
+    timestamp
+        .map(|timestamp| timestamp.format("%Y-%m-%d %H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+fn format_outcome(passed: bool) -> &'static str {
+    match passed {
+        true => "Passed",
+        false => "Failed",
+    }
+}
+
+fn escape_markdown(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+pub(super) fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+impl AfterActionReport {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        exercise: &Exercise,
+        deployment: &Deployment,
+        mut events: Vec<Event>,
+        deployment_elements: &[DeploymentElement],
+        entity_scores: Vec<EntityScore>,
+        evaluations: Vec<EntityEvaluation>,
+        metrics: &[Metric],
+        metric_gradings: &[MetricGrading],
+        generated_at: NaiveDateTime,
+    ) -> Self {
+        events.sort_by_key(|event| match event.has_triggered {
+            true => (0, event.triggered_at),
+            false => (1, event.start),
+        });
+
+        let injects = deployment_elements
+            .iter()
+            .filter(|element| matches!(element.deployer_type.0, GrpcDeployerType::Inject))
+            .map(|element| {
+                let event = element
+                    .event_id
+                    .and_then(|event_id| events.iter().find(|event| event.id == event_id));
+                ReportInject {
+                    name: element.scenario_reference.clone(),
+                    event_name: event.map(|event| event.name.clone()),
+                    delivered_at: event
+                        .filter(|event| {
+                            event.has_triggered && matches!(element.status, ElementStatus::Success)
+                        })
+                        .map(|event| event.triggered_at),
+                    status: element.status,
+                }
+            })
+            .collect();
+
+        let failures = deployment_elements
+            .iter()
+            .filter(|element| {
+                matches!(
+                    element.status,
+                    ElementStatus::Failed | ElementStatus::RemoveFailed
+                )
+            })
+            .map(|element| ReportFailure {
+                name: element.scenario_reference.clone(),
+                deployer_type: element.deployer_type,
+                status: element.status,
+                error_message: element.error_message.clone(),
+            })
+            .collect();
+
+        let mut grading_comments = metric_gradings
+            .iter()
+            .filter_map(|metric_grading| {
+                let comment = metric_grading.comment.clone()?;
+                let metric = metrics
+                    .iter()
+                    .find(|metric| metric.id == metric_grading.metric_id)?;
+                Some(ReportGradingComment {
+                    metric_name: metric.name.clone().unwrap_or(metric.sdl_key.clone()),
+                    entity_selector: metric.entity_selector.clone(),
+                    grader_id: metric_grading.grader_id.clone(),
+                    previous_score: metric_grading.previous_score,
+                    score: metric_grading.score,
+                    comment,
+                    graded_at: metric_grading.created_at,
+                })
+            })
+            .collect::<Vec<_>>();
+        grading_comments.sort_by_key(|grading_comment| grading_comment.graded_at);
+
+        Self {
+            exercise_name: exercise.name.clone(),
+            deployment_name: deployment.name.clone(),
+            deployment_start: deployment.start,
+            deployment_end: deployment.end,
+            generated_at,
+            events: events.iter().map(ReportEvent::from).collect(),
+            injects,
+            entity_scores,
+            evaluations,
+            grading_comments,
+            failures,
+        }
+    }
+
+    pub fn to_markdown(&self) -> Result<String, std::fmt::Error> {
+        let mut markdown = String::new();
+        writeln!(
+            markdown,
+            "# After-action report: {}",
+            escape_markdown(&self.deployment_name)
+        )?;
+        writeln!(markdown)?;
+        writeln!(
+            markdown,
+            "- Exercise: {}",
+            escape_markdown(&self.exercise_name)
+        )?;
+        writeln!(
+            markdown,
+            "- Deployment window: {} - {}",
+            format_timestamp(Some(self.deployment_start)),
+            format_timestamp(Some(self.deployment_end))
+        )?;
+        writeln!(
+            markdown,
+            "- Generated at: {}",
+            format_timestamp(Some(self.generated_at))
+        )?;
+
+        writeln!(markdown, "\n## Event timeline\n")?;
+        writeln!(
+            markdown,
+            "| Event | Planned start | Triggered at | Description |"
+        )?;
+        writeln!(markdown, "| --- | --- | --- | --- |")?;
+        for event in &self.events {
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} |",
+                escape_markdown(&event.name),
+                format_timestamp(Some(event.start)),
+                format_timestamp(event.triggered_at),
+                escape_markdown(event.description.as_deref().unwrap_or_default())
+            )?;
+        }
+
+        writeln!(markdown, "\n## Injects\n")?;
+        writeln!(markdown, "| Inject | Event | Delivered at | Status |")?;
+        writeln!(markdown, "| --- | --- | --- | --- |")?;
+        for inject in &self.injects {
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {:?} |",
+                escape_markdown(&inject.name),
+                escape_markdown(inject.event_name.as_deref().unwrap_or("-")),
+                format_timestamp(inject.delivered_at),
+                inject.status
+            )?;
+        }
+
+        writeln!(markdown, "\n## Scores\n")?;
+        writeln!(markdown, "| Entity | Score | Last scored at |")?;
+        writeln!(markdown, "| --- | --- | --- |")?;
+        for entity_score in &self.entity_scores {
+            writeln!(
+                markdown,
+                "| {} | {} | {} |",
+                escape_markdown(
+                    entity_score
+                        .entity_name
+                        .as_deref()
+                        .unwrap_or(&entity_score.entity_selector)
+                ),
+                entity_score.score.round(2),
+                format_timestamp(entity_score.last_scored_at)
+            )?;
+        }
+
+        writeln!(markdown, "\n## Training objective outcomes\n")?;
+        writeln!(
+            markdown,
+            "| Entity | Objective | Score | Required | Outcome |"
+        )?;
+        writeln!(markdown, "| --- | --- | --- | --- | --- |")?;
+        for evaluation in &self.evaluations {
+            for tlo in &evaluation.tlos {
+                writeln!(
+                    markdown,
+                    "| {} | {} | {} / {} | {} | {} |",
+                    escape_markdown(
+                        evaluation
+                            .entity_name
+                            .as_deref()
+                            .unwrap_or(&evaluation.entity_selector)
+                    ),
+                    escape_markdown(tlo.tlo_name.as_deref().unwrap_or(&tlo.tlo_key)),
+                    tlo.score.round(2),
+                    tlo.max_score.round(2),
+                    tlo.min_score.round(2),
+                    format_outcome(tlo.passed)
+                )?;
+            }
+        }
+
+        writeln!(markdown, "\n## Grading comments\n")?;
+        writeln!(
+            markdown,
+            "| Metric | Entity | Grader | Score | Comment | Graded at |"
+        )?;
+        writeln!(markdown, "| --- | --- | --- | --- | --- | --- |")?;
+        for grading_comment in &self.grading_comments {
+            writeln!(
+                markdown,
+                "| {} | {} | {} | {} | {} | {} |",
+                escape_markdown(&grading_comment.metric_name),
+                escape_markdown(&grading_comment.entity_selector),
+                escape_markdown(&grading_comment.grader_id),
+                grading_comment.score,
+                escape_markdown(&grading_comment.comment),
+                format_timestamp(Some(grading_comment.graded_at))
+            )?;
+        }
+
+        writeln!(markdown, "\n## Deployment failures\n")?;
+        writeln!(markdown, "| Element | Type | Status | Error |")?;
+        writeln!(markdown, "| --- | --- | --- | --- |")?;
+        for failure in &self.failures {
+            writeln!(
+                markdown,
+                "| {} | {} | {:?} | {} |",
+                escape_markdown(&failure.name),
+                failure.deployer_type,
+                failure.status,
+                escape_markdown(failure.error_message.as_deref().unwrap_or_default())
+            )?;
+        }
+
+        Ok(markdown)
+    }
+
+    fn write_html_table(
+        html: &mut String,
+        title: &str,
+        headers: &[&str],
+        rows: Vec<Vec<String>>,
+    ) -> Result<(), std::fmt::Error> {
+        writeln!(html, "<h2>{}</h2>", escape_html(title))?;
+        if rows.is_empty() {
+            writeln!(html, "<p>None</p>")?;
+            return Ok(());
+        }
+        writeln!(html, "<table>")?;
+        write!(html, "<tr>")?;
+        for header in headers {
+            write!(html, "<th>{}</th>", escape_html(header))?;
+        }
+        writeln!(html, "</tr>")?;
+        for row in rows {
+            write!(html, "<tr>")?;
+            for cell in row {
+                write!(html, "<td>{}</td>", escape_html(&cell))?;
+            }
+            writeln!(html, "</tr>")?;
+        }
+        writeln!(html, "</table>")?;
+
+        Ok(())
+    }
+
+    pub fn to_html(&self) -> Result<String, std::fmt::Error> {
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html>")?;
+        writeln!(html, "<head>")?;
+        writeln!(html, "<meta charset=\"utf-8\">")?;
+        writeln!(
+            html,
+            "<title>After-action report: {}</title>",
+            escape_html(&self.deployment_name)
+        )?;
+        writeln!(
+            html,
+            "<style>body {{ font-family: sans-serif; margin: 2em; }} table {{ border-collapse: collapse; margin-bottom: 1em; }} th, td {{ border: 1px solid #999; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }}</style>"
+        )?;
+        writeln!(html, "</head>")?;
+        writeln!(html, "<body>")?;
+        writeln!(
+            html,
+            "<h1>After-action report: {}</h1>",
+            escape_html(&self.deployment_name)
+        )?;
+        writeln!(html, "<ul>")?;
+        writeln!(
+            html,
+            "<li>Exercise: {}</li>",
+            escape_html(&self.exercise_name)
+        )?;
+        writeln!(
+            html,
+            "<li>Deployment window: {} - {}</li>",
+            format_timestamp(Some(self.deployment_start)),
+            format_timestamp(Some(self.deployment_end))
+        )?;
+        writeln!(
+            html,
+            "<li>Generated at: {}</li>",
+            format_timestamp(Some(self.generated_at))
+        )?;
+        writeln!(html, "</ul>")?;
+
+        Self::write_html_table(
+            &mut html,
+            "Event timeline",
+            &["Event", "Planned start", "Triggered at", "Description"],
+            self.events
+                .iter()
+                .map(|event| {
+                    vec![
+                        event.name.clone(),
+                        format_timestamp(Some(event.start)),
+                        format_timestamp(event.triggered_at),
+                        event.description.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect(),
+        )?;
+        Self::write_html_table(
+            &mut html,
+            "Injects",
+            &["Inject", "Event", "Delivered at", "Status"],
+            self.injects
+                .iter()
+                .map(|inject| {
+                    vec![
+                        inject.name.clone(),
+                        inject.event_name.clone().unwrap_or("-".to_string()),
+                        format_timestamp(inject.delivered_at),
+                        format!("{:?}", inject.status),
+                    ]
+                })
+                .collect(),
+        )?;
+        Self::write_html_table(
+            &mut html,
+            "Scores",
+            &["Entity", "Score", "Last scored at"],
+            self.entity_scores
+                .iter()
+                .map(|entity_score| {
+                    vec![
+                        entity_score
+                            .entity_name
+                            .clone()
+                            .unwrap_or(entity_score.entity_selector.clone()),
+                        entity_score.score.round(2).to_string(),
+                        format_timestamp(entity_score.last_scored_at),
+                    ]
+                })
+                .collect(),
+        )?;
+        Self::write_html_table(
+            &mut html,
+            "Training objective outcomes",
+            &["Entity", "Objective", "Score", "Required", "Outcome"],
+            self.evaluations
+                .iter()
+                .flat_map(|evaluation| {
+                    evaluation.tlos.iter().map(|tlo| {
+                        vec![
+                            evaluation
+                                .entity_name
+                                .clone()
+                                .unwrap_or(evaluation.entity_selector.clone()),
+                            tlo.tlo_name.clone().unwrap_or(tlo.tlo_key.clone()),
+                            format!("{} / {}", tlo.score.round(2), tlo.max_score.round(2)),
+                            tlo.min_score.round(2).to_string(),
+                            format_outcome(tlo.passed).to_string(),
+                        ]
+                    })
+                })
+                .collect(),
+        )?;
+        Self::write_html_table(
+            &mut html,
+            "Grading comments",
+            &[
+                "Metric",
+                "Entity",
+                "Grader",
+                "Score",
+                "Comment",
+                "Graded at",
+            ],
+            self.grading_comments
+                .iter()
+                .map(|grading_comment| {
+                    vec![
+                        grading_comment.metric_name.clone(),
+                        grading_comment.entity_selector.clone(),
+                        grading_comment.grader_id.clone(),
+                        grading_comment.score.to_string(),
+                        grading_comment.comment.clone(),
+                        format_timestamp(Some(grading_comment.graded_at)),
+                    ]
+                })
+                .collect(),
+        )?;
+        Self::write_html_table(
+            &mut html,
+            "Deployment failures",
+            &["Element", "Type", "Status", "Error"],
+            self.failures
+                .iter()
+                .map(|failure| {
+                    vec![
+                        failure.name.clone(),
+                        failure.deployer_type.to_string(),
+                        format!("{:?}", failure.status),
+                        failure.error_message.clone().unwrap_or_default(),
+                    ]
+                })
+                .collect(),
+        )?;
+
+        writeln!(html, "</body>")?;
+        writeln!(html, "</html>")?;
+
+        Ok(html)
+    }
+}
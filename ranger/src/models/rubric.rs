@@ -0,0 +1,422 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::{rubric_criteria, rubric_grades, rubric_levels, rubrics},
+    services::database::{
+        All, Create, FilterExisting, SelectById, SelectByIdFromAllReference, SoftDelete,
+        SoftDeleteById,
+    },
+};
+use bigdecimal::{BigDecimal, ToPrimitive};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, Associations, ExpressionMethods, Identifiable, Insertable, QueryDsl, Queryable,
+    Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricLevelRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub value: BigDecimal,
+}
+
+impl From<RubricLevel> for RubricLevelRest {
+    fn from(level: RubricLevel) -> Self {
+        Self {
+            id: level.id,
+            name: level.name,
+            description: level.description,
+            value: level.value,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricCriterionRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub weight: BigDecimal,
+    pub levels: Vec<RubricLevelRest>,
+}
+
+impl From<(RubricCriterion, Vec<RubricLevelRest>)> for RubricCriterionRest {
+    fn from((criterion, levels): (RubricCriterion, Vec<RubricLevelRest>)) -> Self {
+        Self {
+            id: criterion.id,
+            name: criterion.name,
+            description: criterion.description,
+            weight: criterion.weight,
+            levels,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricRest {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub criteria: Vec<RubricCriterionRest>,
+}
+
+impl From<(Rubric, Vec<RubricCriterionRest>)> for RubricRest {
+    fn from((rubric, criteria): (Rubric, Vec<RubricCriterionRest>)) -> Self {
+        Self {
+            id: rubric.id,
+            metric_key: rubric.metric_key,
+            name: rubric.name,
+            criteria,
+        }
+    }
+}
+
+impl RubricRest {
+    pub fn calculate_score(
+        &self,
+        levels_by_criterion: &HashMap<Uuid, Uuid>,
+        max_score: u32,
+    ) -> Option<u32> {
+        let mut total_weight = BigDecimal::from(0);
+        let mut weighted_value = BigDecimal::from(0);
+        for criterion in &self.criteria {
+            let level_id = levels_by_criterion.get(&criterion.id)?;
+            let level = criterion
+                .levels
+                .iter()
+                .find(|level| level.id == *level_id)?;
+            total_weight += &criterion.weight;
+            weighted_value += &criterion.weight * &level.value;
+        }
+        if total_weight <= BigDecimal::from(0) {
+            return None;
+        }
+
+        (weighted_value / total_weight * BigDecimal::from(max_score))
+            .round(0)
+            .to_u32()
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = rubrics)]
+pub struct NewRubric {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+}
+
+impl NewRubric {
+    pub fn new(rubric_rest: &RubricRest, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: rubric_rest.metric_key.clone(),
+            name: rubric_rest.name.clone(),
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, rubrics::table> {
+        insert_into(rubrics::table).values(self)
+    }
+}
+
+#[derive(
+    Identifiable, Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = rubrics)]
+pub struct Rubric {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub name: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> =
+    Filter<FilterExisting<T, rubrics::deleted_at>, Eq<rubrics::exercise_id, Uuid>>;
+type ByExerciseIdAndMetricKey<T> = Filter<ByExerciseId<T>, Eq<rubrics::metric_key, String>>;
+
+impl Rubric {
+    fn all_with_deleted() -> All<rubrics::table, Self> {
+        rubrics::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<rubrics::table, Self>, rubrics::deleted_at> {
+        Self::all_with_deleted().filter(rubrics::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(id: Uuid) -> SelectById<rubrics::table, rubrics::id, rubrics::deleted_at, Self> {
+        Self::all().filter(rubrics::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<rubrics::table, Self>> {
+        Self::all().filter(rubrics::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> ByExerciseIdAndMetricKey<All<rubrics::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(rubrics::metric_key.eq(metric_key))
+    }
+
+    pub fn soft_delete(&self) -> SoftDeleteById<rubrics::id, rubrics::deleted_at, rubrics::table> {
+        diesel::update(rubrics::table.filter(rubrics::id.eq(self.id)))
+            .set(rubrics::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn soft_delete_by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> SoftDelete<ByExerciseIdAndMetricKey<rubrics::table>, rubrics::deleted_at> {
+        diesel::update(
+            rubrics::table
+                .filter(rubrics::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(rubrics::exercise_id.eq(exercise_id))
+                .filter(rubrics::metric_key.eq(metric_key)),
+        )
+        .set(rubrics::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Associations,
+    Queryable,
+    Selectable,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(belongs_to(Rubric, foreign_key = rubric_id))]
+#[diesel(table_name = rubric_criteria)]
+pub struct RubricCriterion {
+    pub id: Uuid,
+    pub rubric_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub weight: BigDecimal,
+    pub position: u32,
+}
+
+impl RubricCriterion {
+    pub fn new(rubric_id: Uuid, position: u32, criterion: &RubricCriterionRest) -> Self {
+        Self {
+            id: Uuid::random(),
+            rubric_id,
+            name: criterion.name.clone(),
+            description: criterion.description.clone(),
+            weight: criterion.weight.clone(),
+            position,
+        }
+    }
+
+    pub fn by_rubric(
+        rubric: &Rubric,
+    ) -> SelectByIdFromAllReference<rubric_criteria::table, rubric_criteria::rubric_id, Self> {
+        Self::belonging_to(rubric).select(Self::as_select())
+    }
+
+    pub fn batch_insert(criteria: Vec<Self>) -> Create<Vec<Self>, rubric_criteria::table> {
+        insert_into(rubric_criteria::table).values(criteria)
+    }
+}
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Associations,
+    Queryable,
+    Selectable,
+    Debug,
+    PartialEq,
+    Eq,
+    Clone,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(belongs_to(RubricCriterion, foreign_key = rubric_criterion_id))]
+#[diesel(table_name = rubric_levels)]
+pub struct RubricLevel {
+    pub id: Uuid,
+    pub rubric_criterion_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub value: BigDecimal,
+    pub position: u32,
+}
+
+impl RubricLevel {
+    pub fn new(rubric_criterion_id: Uuid, position: u32, level: &RubricLevelRest) -> Self {
+        Self {
+            id: Uuid::random(),
+            rubric_criterion_id,
+            name: level.name.clone(),
+            description: level.description.clone(),
+            value: level.value.clone(),
+            position,
+        }
+    }
+
+    pub fn by_criterion(
+        criterion: &RubricCriterion,
+    ) -> SelectByIdFromAllReference<rubric_levels::table, rubric_levels::rubric_criterion_id, Self>
+    {
+        Self::belonging_to(criterion).select(Self::as_select())
+    }
+
+    pub fn batch_insert(levels: Vec<Self>) -> Create<Vec<Self>, rubric_levels::table> {
+        insert_into(rubric_levels::table).values(levels)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricCriterionGradeResource {
+    pub criterion_id: Uuid,
+    pub level_id: Uuid,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricGradingResource {
+    pub grades: Vec<RubricCriterionGradeResource>,
+    pub comment: Option<String>,
+}
+
+impl RubricGradingResource {
+    pub fn levels_by_criterion(&self) -> HashMap<Uuid, Uuid> {
+        self.grades
+            .iter()
+            .map(|grade| (grade.criterion_id, grade.level_id))
+            .collect()
+    }
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = rubric_grades)]
+pub struct NewRubricGrade {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub rubric_criterion_id: Uuid,
+    pub rubric_level_id: Uuid,
+    pub grader_id: String,
+    pub comment: Option<String>,
+}
+
+impl NewRubricGrade {
+    pub fn new(
+        metric_id: Uuid,
+        grader_id: String,
+        criterion_grade: RubricCriterionGradeResource,
+    ) -> Self {
+        Self {
+            id: Uuid::random(),
+            metric_id,
+            rubric_criterion_id: criterion_grade.criterion_id,
+            rubric_level_id: criterion_grade.level_id,
+            grader_id,
+            comment: criterion_grade.comment,
+        }
+    }
+
+    pub fn batch_insert(grades: Vec<Self>) -> Create<Vec<Self>, rubric_grades::table> {
+        insert_into(rubric_grades::table).values(grades)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = rubric_grades)]
+pub struct RubricGrade {
+    pub id: Uuid,
+    pub metric_id: Uuid,
+    pub rubric_criterion_id: Uuid,
+    pub rubric_level_id: Uuid,
+    pub grader_id: String,
+    pub comment: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByMetricId<T> =
+    Filter<FilterExisting<T, rubric_grades::deleted_at>, Eq<rubric_grades::metric_id, Uuid>>;
+
+impl RubricGrade {
+    fn all_with_deleted() -> All<rubric_grades::table, Self> {
+        rubric_grades::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<rubric_grades::table, Self>, rubric_grades::deleted_at> {
+        Self::all_with_deleted().filter(rubric_grades::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_metric_id(metric_id: Uuid) -> ByMetricId<All<rubric_grades::table, Self>> {
+        Self::all().filter(rubric_grades::metric_id.eq(metric_id))
+    }
+
+    pub fn soft_delete_by_metric_id(
+        metric_id: Uuid,
+    ) -> SoftDelete<ByMetricId<rubric_grades::table>, rubric_grades::deleted_at> {
+        diesel::update(
+            rubric_grades::table
+                .filter(rubric_grades::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(rubric_grades::metric_id.eq(metric_id)),
+        )
+        .set(rubric_grades::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricGradeFeedback {
+    pub criterion_id: Uuid,
+    pub level_id: Uuid,
+    pub comment: Option<String>,
+}
+
+impl From<RubricGrade> for RubricGradeFeedback {
+    fn from(grade: RubricGrade) -> Self {
+        Self {
+            criterion_id: grade.rubric_criterion_id,
+            level_id: grade.rubric_level_id,
+            comment: grade.comment,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricFeedback {
+    pub metric_id: Uuid,
+    pub rubric: RubricRest,
+    pub grades: Vec<RubricGradeFeedback>,
+    pub score: Option<u32>,
+    pub max_score: u32,
+}
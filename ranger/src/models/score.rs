@@ -1,46 +1,40 @@
 use super::helpers::uuid::Uuid;
-use crate::models::ConditionMessage;
+use crate::{
+    constants::{NAIVEDATETIME_DEFAULT_VALUE, PENALTY_METRIC_KEY},
+    models::ConditionMessage,
+    schema::scores,
+    services::database::{All, Create, CreateOrReplace, FilterExisting, SelectById, SoftDelete},
+};
 use bigdecimal::BigDecimal;
 use chrono::NaiveDateTime;
-use sdl_parser::metric::Metric;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into,
+    prelude::QueryableByName,
+    replace_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use sdl_parser::{entity::ExerciseRole, metric::Metric};
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
-pub struct Score {
+#[diesel(table_name = scores)]
+pub struct NewScore {
     #[serde(default = "Uuid::random")]
     pub id: Uuid,
     pub exercise_id: Uuid,
     pub deployment_id: Uuid,
-    pub metric_name: Option<String>,
+    pub metric_id: Option<Uuid>,
     pub metric_key: String,
+    pub metric_name: Option<String>,
     pub vm_name: String,
+    pub role: Option<String>,
     pub value: BigDecimal,
+    pub reason: Option<String>,
     pub timestamp: NaiveDateTime,
 }
 
-impl Score {
-    pub fn new(
-        exercise_id: Uuid,
-        deployment_id: Uuid,
-        metric_name: Option<String>,
-        metric_key: String,
-        vm_name: String,
-        value: BigDecimal,
-        timestamp: NaiveDateTime,
-    ) -> Self {
-        Self {
-            id: Uuid::random(),
-            exercise_id,
-            deployment_id,
-            metric_name,
-            metric_key,
-            vm_name,
-            value,
-            timestamp,
-        }
-    }
-
+impl NewScore {
     pub fn from_conditionmessage_and_metric(
         condition_message: ConditionMessage,
         sdl_metric: (String, Metric),
@@ -50,30 +44,181 @@ impl Score {
             id: condition_message.id,
             exercise_id: condition_message.exercise_id,
             deployment_id: condition_message.deployment_id,
-            metric_name: sdl_metric.1.name,
+            metric_id: None,
             metric_key: sdl_metric.0,
+            metric_name: sdl_metric.1.name,
             vm_name,
+            role: None,
             value: condition_message.value * BigDecimal::from(sdl_metric.1.max_score),
+            reason: None,
             timestamp: condition_message.created_at,
         }
     }
+
+    pub fn create_insert(&self) -> Create<&Self, scores::table> {
+        insert_into(scores::table).values(self)
+    }
+
+    pub fn create_insert_or_replace(&self) -> CreateOrReplace<&Self, scores::table> {
+        replace_into(scores::table).values(self)
+    }
 }
 
-impl From<super::Metric> for Score {
+impl From<super::Metric> for NewScore {
     fn from(metric: super::Metric) -> Self {
         let score: BigDecimal = match metric.score {
             Some(score) => BigDecimal::from(score),
             None => BigDecimal::from(0),
         };
 
-        Score::new(
-            metric.exercise_id,
-            metric.deployment_id,
-            metric.name,
-            metric.sdl_key,
-            metric.entity_selector,
-            score,
-            metric.updated_at,
+        Self {
+            id: metric.id,
+            exercise_id: metric.exercise_id,
+            deployment_id: metric.deployment_id,
+            metric_id: Some(metric.id),
+            metric_key: metric.sdl_key,
+            metric_name: metric.name,
+            vm_name: metric.entity_selector,
+            role: Some(metric.role),
+            value: score,
+            reason: None,
+            timestamp: metric.updated_at,
+        }
+    }
+}
+
+impl From<super::Penalty> for NewScore {
+    fn from(penalty: super::Penalty) -> Self {
+        Self {
+            id: penalty.id,
+            exercise_id: penalty.exercise_id,
+            deployment_id: penalty.deployment_id,
+            metric_id: Some(penalty.id),
+            metric_key: PENALTY_METRIC_KEY.to_string(),
+            metric_name: None,
+            vm_name: penalty.entity_selector,
+            role: None,
+            value: -penalty.value,
+            reason: Some(penalty.reason),
+            timestamp: penalty.created_at,
+        }
+    }
+}
+
+#[derive(
+    Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize, QueryableByName,
+)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = scores)]
+pub struct Score {
+    #[serde(default = "Uuid::random")]
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub deployment_id: Uuid,
+    pub metric_id: Option<Uuid>,
+    pub metric_name: Option<String>,
+    pub metric_key: String,
+    pub vm_name: String,
+    pub role: Option<String>,
+    pub value: BigDecimal,
+    pub reason: Option<String>,
+    pub timestamp: NaiveDateTime,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConditionScoringEvent {
+    pub timestamp: NaiveDateTime,
+    pub condition_name: String,
+    pub condition_value: BigDecimal,
+    pub vm_name: String,
+    pub metric_key: String,
+    pub metric_name: Option<String>,
+    pub score: BigDecimal,
+    pub reason: Option<String>,
+}
+
+impl ConditionScoringEvent {
+    pub fn new(score: Score, condition_message: &ConditionMessage) -> Self {
+        Self {
+            timestamp: score.timestamp,
+            condition_name: condition_message.condition_name.clone(),
+            condition_value: condition_message.value.clone(),
+            vm_name: score.vm_name,
+            metric_key: score.metric_key,
+            metric_name: score.metric_name,
+            score: score.value,
+            reason: score.reason,
+        }
+    }
+}
+
+type ByDeploymentId<T> =
+    Filter<FilterExisting<T, scores::deleted_at>, Eq<scores::deployment_id, Uuid>>;
+
+type ByMetricId<T> = Filter<FilterExisting<T, scores::deleted_at>, Eq<scores::metric_id, Uuid>>;
+
+impl Score {
+    fn all_with_deleted() -> All<scores::table, Self> {
+        scores::table.select(Self::as_select())
+    }
+
+    pub fn all() -> FilterExisting<All<scores::table, Self>, scores::deleted_at> {
+        Self::all_with_deleted().filter(scores::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(id: Uuid) -> SelectById<scores::table, scores::id, scores::deleted_at, Self> {
+        Self::all().filter(scores::id.eq(id))
+    }
+
+    pub fn by_deployment_id(deployment_id: Uuid) -> ByDeploymentId<All<scores::table, Self>> {
+        Self::all().filter(scores::deployment_id.eq(deployment_id))
+    }
+
+    pub fn soft_delete_by_deployment_id(
+        deployment_id: Uuid,
+    ) -> SoftDelete<ByDeploymentId<scores::table>, scores::deleted_at> {
+        diesel::update(
+            scores::table
+                .filter(scores::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(scores::deployment_id.eq(deployment_id)),
         )
+        .set(scores::deleted_at.eq(diesel::dsl::now))
     }
+
+    pub fn soft_delete_by_metric_id(
+        metric_id: Uuid,
+    ) -> SoftDelete<ByMetricId<scores::table>, scores::deleted_at> {
+        diesel::update(
+            scores::table
+                .filter(scores::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(scores::metric_id.eq(metric_id)),
+        )
+        .set(scores::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreHistoryPoint {
+    pub timestamp: NaiveDateTime,
+    pub score: BigDecimal,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricScoreHistory {
+    pub metric_key: String,
+    pub metric_name: Option<String>,
+    pub points: Vec<ScoreHistoryPoint>,
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityScoreHistory {
+    pub entity_selector: String,
+    pub entity_name: Option<String>,
+    pub role: Option<ExerciseRole>,
+    pub points: Vec<ScoreHistoryPoint>,
+    pub metrics: Vec<MetricScoreHistory>,
 }
@@ -0,0 +1,381 @@
+use super::helpers::uuid::Uuid;
+use crate::{
+    constants::{BIG_DECIMAL_ONE, BIG_DECIMAL_ZERO, NAIVEDATETIME_DEFAULT_VALUE},
+    schema::scoring_strategies,
+    services::database::{All, Create, FilterExisting, SelectById, SoftDelete, SoftDeleteById},
+};
+use bigdecimal::BigDecimal;
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into,
+    sql_types::Text,
+    AsExpression, ExpressionMethods, FromSqlRow, Insertable, QueryDsl, Queryable, Selectable,
+    SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, FromSqlRow, AsExpression, Eq, Deserialize, Serialize, Default,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "camelCase")]
+pub enum ScoringStrategyType {
+    #[default]
+    Value,
+    Availability,
+    FirstSuccessBonus,
+    Decay,
+    Penalty,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct ConditionScoringHistory {
+    pub previous_message: Option<(NaiveDateTime, BigDecimal)>,
+    pub previous_score: Option<BigDecimal>,
+    pub first_success_at: Option<NaiveDateTime>,
+    pub is_first_success_in_deployment: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewScoringStrategyResource {
+    pub metric_key: String,
+    pub strategy: ScoringStrategyType,
+    pub bonus_score: Option<u32>,
+    pub decay_per_minute: Option<BigDecimal>,
+    pub minimum_ratio: Option<BigDecimal>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = scoring_strategies)]
+pub struct NewScoringStrategy {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub strategy: ScoringStrategyType,
+    pub bonus_score: Option<u32>,
+    pub decay_per_minute: Option<BigDecimal>,
+    pub minimum_ratio: Option<BigDecimal>,
+}
+
+impl NewScoringStrategy {
+    pub fn new(resource: NewScoringStrategyResource, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: resource.metric_key,
+            strategy: resource.strategy,
+            bonus_score: resource.bonus_score,
+            decay_per_minute: resource.decay_per_minute,
+            minimum_ratio: resource.minimum_ratio,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, scoring_strategies::table> {
+        insert_into(scoring_strategies::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = scoring_strategies)]
+pub struct ScoringStrategy {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub strategy: ScoringStrategyType,
+    pub bonus_score: Option<u32>,
+    pub decay_per_minute: Option<BigDecimal>,
+    pub minimum_ratio: Option<BigDecimal>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<
+    FilterExisting<T, scoring_strategies::deleted_at>,
+    Eq<scoring_strategies::exercise_id, Uuid>,
+>;
+type ByExerciseIdAndMetricKey<T> =
+    Filter<ByExerciseId<T>, Eq<scoring_strategies::metric_key, String>>;
+
+impl ScoringStrategy {
+    fn all_with_deleted() -> All<scoring_strategies::table, Self> {
+        scoring_strategies::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<scoring_strategies::table, Self>, scoring_strategies::deleted_at> {
+        Self::all_with_deleted()
+            .filter(scoring_strategies::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        scoring_strategies::table,
+        scoring_strategies::id,
+        scoring_strategies::deleted_at,
+        Self,
+    > {
+        Self::all().filter(scoring_strategies::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<scoring_strategies::table, Self>> {
+        Self::all().filter(scoring_strategies::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> ByExerciseIdAndMetricKey<All<scoring_strategies::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(scoring_strategies::metric_key.eq(metric_key))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        scoring_strategies::id,
+        scoring_strategies::deleted_at,
+        scoring_strategies::table,
+    > {
+        diesel::update(scoring_strategies::table.filter(scoring_strategies::id.eq(self.id)))
+            .set(scoring_strategies::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn soft_delete_by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> SoftDelete<
+        ByExerciseIdAndMetricKey<scoring_strategies::table>,
+        scoring_strategies::deleted_at,
+    > {
+        diesel::update(
+            scoring_strategies::table
+                .filter(scoring_strategies::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(scoring_strategies::exercise_id.eq(exercise_id))
+                .filter(scoring_strategies::metric_key.eq(metric_key)),
+        )
+        .set(scoring_strategies::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn is_success(value: &BigDecimal) -> bool {
+        value >= &*BIG_DECIMAL_ONE
+    }
+
+    pub fn requires_history(&self) -> bool {
+        !matches!(
+            self.strategy,
+            ScoringStrategyType::Value | ScoringStrategyType::Penalty
+        )
+    }
+
+    pub fn calculate_score(
+        &self,
+        max_score: u32,
+        value: &BigDecimal,
+        timestamp: NaiveDateTime,
+        deployment_start: NaiveDateTime,
+        history: &ConditionScoringHistory,
+    ) -> BigDecimal {
+        let max_score = BigDecimal::from(max_score);
+        let value_score = value * &max_score;
+
+        match self.strategy {
+            ScoringStrategyType::Value => value_score,
+            ScoringStrategyType::Availability => {
+                let elapsed_milliseconds = (timestamp - deployment_start).num_milliseconds();
+                if elapsed_milliseconds <= 0 || max_score == *BIG_DECIMAL_ZERO {
+                    return value_score;
+                }
+
+                let uptime_milliseconds = match (&history.previous_message, &history.previous_score)
+                {
+                    (Some((previous_timestamp, previous_value)), Some(previous_score)) => {
+                        let previous_elapsed_milliseconds =
+                            (*previous_timestamp - deployment_start).num_milliseconds();
+                        let interval_milliseconds =
+                            (timestamp - *previous_timestamp).num_milliseconds();
+
+                        previous_score / &max_score
+                            * BigDecimal::from(previous_elapsed_milliseconds)
+                            + previous_value.min(&*BIG_DECIMAL_ONE)
+                                * BigDecimal::from(interval_milliseconds)
+                    }
+                    _ => BigDecimal::from(0),
+                };
+
+                uptime_milliseconds / BigDecimal::from(elapsed_milliseconds) * max_score
+            }
+            ScoringStrategyType::FirstSuccessBonus => {
+                match history.first_success_at.is_some() && history.is_first_success_in_deployment {
+                    true => value_score + BigDecimal::from(self.bonus_score.unwrap_or_default()),
+                    false => value_score,
+                }
+            }
+            ScoringStrategyType::Decay => {
+                let achieved_at = history.first_success_at.unwrap_or(timestamp);
+                let elapsed_minutes =
+                    BigDecimal::from((achieved_at - deployment_start).num_seconds().max(0))
+                        / BigDecimal::from(60);
+                let decay = self.decay_per_minute.clone().unwrap_or_default() * elapsed_minutes;
+                let minimum_ratio = self.minimum_ratio.clone().unwrap_or_default();
+                let ratio = (&*BIG_DECIMAL_ONE - decay).max(minimum_ratio);
+
+                value_score * ratio
+            }
+            ScoringStrategyType::Penalty => {
+                -((&*BIG_DECIMAL_ONE - value.min(&*BIG_DECIMAL_ONE)) * max_score)
+            }
+        }
+    }
+
+    pub fn get_reason(&self, condition_name: &str, value: &BigDecimal) -> Option<String> {
+        match self.strategy == ScoringStrategyType::Penalty && !Self::is_success(value) {
+            true => Some(format!("Condition {condition_name} reported failure")),
+            false => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ConditionScoringHistory, ScoringStrategy, ScoringStrategyType};
+    use crate::models::helpers::uuid::Uuid;
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, NaiveDateTime};
+    use std::str::FromStr;
+
+    fn create_strategy(strategy: ScoringStrategyType) -> ScoringStrategy {
+        ScoringStrategy {
+            id: Uuid::random(),
+            exercise_id: Uuid::random(),
+            metric_key: "metric".to_string(),
+            strategy,
+            bonus_score: Some(5),
+            decay_per_minute: Some(BigDecimal::from_str("0.1").unwrap()),
+            minimum_ratio: Some(BigDecimal::from_str("0.5").unwrap()),
+            created_at: NaiveDateTime::default(),
+            updated_at: NaiveDateTime::default(),
+            deleted_at: NaiveDateTime::default(),
+        }
+    }
+
+    fn calculate_score(
+        strategy: ScoringStrategyType,
+        value: &str,
+        elapsed_seconds: i64,
+        history: &ConditionScoringHistory,
+    ) -> BigDecimal {
+        let deployment_start = NaiveDateTime::default();
+        create_strategy(strategy).calculate_score(
+            10,
+            &BigDecimal::from_str(value).unwrap(),
+            deployment_start + Duration::seconds(elapsed_seconds),
+            deployment_start,
+            history,
+        )
+    }
+
+    #[test]
+    fn value_scales_max_score() {
+        assert_eq!(
+            calculate_score(
+                ScoringStrategyType::Value,
+                "0.5",
+                60,
+                &ConditionScoringHistory::default()
+            ),
+            BigDecimal::from(5)
+        );
+    }
+
+    #[test]
+    fn penalty_deducts_missing_value() {
+        let history = ConditionScoringHistory::default();
+
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Penalty, "0", 60, &history),
+            BigDecimal::from(-10)
+        );
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Penalty, "1", 60, &history),
+            BigDecimal::from(0)
+        );
+    }
+
+    #[test]
+    fn first_success_bonus_only_applies_to_first_success_in_deployment() {
+        let mut history = ConditionScoringHistory {
+            first_success_at: Some(NaiveDateTime::default()),
+            is_first_success_in_deployment: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate_score(ScoringStrategyType::FirstSuccessBonus, "1", 60, &history),
+            BigDecimal::from(15)
+        );
+
+        history.is_first_success_in_deployment = false;
+        assert_eq!(
+            calculate_score(ScoringStrategyType::FirstSuccessBonus, "1", 60, &history),
+            BigDecimal::from(10)
+        );
+    }
+
+    #[test]
+    fn decay_is_limited_by_minimum_ratio() {
+        let history = ConditionScoringHistory::default();
+
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Decay, "1", 120, &history),
+            BigDecimal::from(8)
+        );
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Decay, "1", 600, &history),
+            BigDecimal::from(5)
+        );
+    }
+
+    #[test]
+    fn decay_uses_first_success_time() {
+        let history = ConditionScoringHistory {
+            first_success_at: Some(NaiveDateTime::default() + Duration::seconds(60)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Decay, "1", 600, &history),
+            BigDecimal::from(9)
+        );
+    }
+
+    #[test]
+    fn availability_accumulates_uptime() {
+        assert_eq!(
+            calculate_score(
+                ScoringStrategyType::Availability,
+                "1",
+                60,
+                &ConditionScoringHistory::default()
+            ),
+            BigDecimal::from(0)
+        );
+
+        let history = ConditionScoringHistory {
+            previous_message: Some((
+                NaiveDateTime::default() + Duration::seconds(60),
+                BigDecimal::from(1),
+            )),
+            previous_score: Some(BigDecimal::from(0)),
+            ..Default::default()
+        };
+        assert_eq!(
+            calculate_score(ScoringStrategyType::Availability, "1", 120, &history),
+            BigDecimal::from(5)
+        );
+    }
+}
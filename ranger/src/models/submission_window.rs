@@ -0,0 +1,183 @@
+use super::{helpers::uuid::Uuid, Deployment, Event};
+use crate::{
+    constants::NAIVEDATETIME_DEFAULT_VALUE,
+    schema::submission_windows,
+    services::database::{All, Create, FilterExisting, SelectById, SoftDelete, SoftDeleteById},
+};
+use chrono::NaiveDateTime;
+use diesel::{
+    helper_types::{Eq, Filter},
+    insert_into, ExpressionMethods, Insertable, QueryDsl, Queryable, Selectable, SelectableHelper,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewSubmissionWindowResource {
+    pub metric_key: String,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: Option<NaiveDateTime>,
+    pub event_name: Option<String>,
+}
+
+#[derive(Insertable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = submission_windows)]
+pub struct NewSubmissionWindow {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: Option<NaiveDateTime>,
+    pub event_name: Option<String>,
+}
+
+impl NewSubmissionWindow {
+    pub fn new(resource: NewSubmissionWindowResource, exercise_id: Uuid) -> Self {
+        Self {
+            id: Uuid::random(),
+            exercise_id,
+            metric_key: resource.metric_key,
+            opens_at: resource.opens_at,
+            closes_at: resource.closes_at,
+            event_name: resource.event_name,
+        }
+    }
+
+    pub fn create_insert(&self) -> Create<&Self, submission_windows::table> {
+        insert_into(submission_windows::table).values(self)
+    }
+}
+
+#[derive(Queryable, Selectable, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[diesel(table_name = submission_windows)]
+pub struct SubmissionWindow {
+    pub id: Uuid,
+    pub exercise_id: Uuid,
+    pub metric_key: String,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: Option<NaiveDateTime>,
+    pub event_name: Option<String>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub deleted_at: NaiveDateTime,
+}
+
+type ByExerciseId<T> = Filter<
+    FilterExisting<T, submission_windows::deleted_at>,
+    Eq<submission_windows::exercise_id, Uuid>,
+>;
+type ByExerciseIdAndMetricKey<T> =
+    Filter<ByExerciseId<T>, Eq<submission_windows::metric_key, String>>;
+
+impl SubmissionWindow {
+    fn all_with_deleted() -> All<submission_windows::table, Self> {
+        submission_windows::table.select(Self::as_select())
+    }
+
+    pub fn all(
+    ) -> FilterExisting<All<submission_windows::table, Self>, submission_windows::deleted_at> {
+        Self::all_with_deleted()
+            .filter(submission_windows::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+    }
+
+    pub fn by_id(
+        id: Uuid,
+    ) -> SelectById<
+        submission_windows::table,
+        submission_windows::id,
+        submission_windows::deleted_at,
+        Self,
+    > {
+        Self::all().filter(submission_windows::id.eq(id))
+    }
+
+    pub fn by_exercise_id(exercise_id: Uuid) -> ByExerciseId<All<submission_windows::table, Self>> {
+        Self::all().filter(submission_windows::exercise_id.eq(exercise_id))
+    }
+
+    pub fn by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> ByExerciseIdAndMetricKey<All<submission_windows::table, Self>> {
+        Self::by_exercise_id(exercise_id).filter(submission_windows::metric_key.eq(metric_key))
+    }
+
+    pub fn soft_delete(
+        &self,
+    ) -> SoftDeleteById<
+        submission_windows::id,
+        submission_windows::deleted_at,
+        submission_windows::table,
+    > {
+        diesel::update(submission_windows::table.filter(submission_windows::id.eq(self.id)))
+            .set(submission_windows::deleted_at.eq(diesel::dsl::now))
+    }
+
+    pub fn soft_delete_by_exercise_id_and_metric_key(
+        exercise_id: Uuid,
+        metric_key: String,
+    ) -> SoftDelete<
+        ByExerciseIdAndMetricKey<submission_windows::table>,
+        submission_windows::deleted_at,
+    > {
+        diesel::update(
+            submission_windows::table
+                .filter(submission_windows::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                .filter(submission_windows::exercise_id.eq(exercise_id))
+                .filter(submission_windows::metric_key.eq(metric_key)),
+        )
+        .set(submission_windows::deleted_at.eq(diesel::dsl::now))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmissionPeriod {
+    pub metric_key: String,
+    pub opens_at: Option<NaiveDateTime>,
+    pub closes_at: NaiveDateTime,
+    pub event_name: Option<String>,
+}
+
+impl SubmissionPeriod {
+    pub fn new(
+        metric_key: String,
+        submission_window: Option<SubmissionWindow>,
+        deployment: &Deployment,
+        events: &[Event],
+    ) -> Self {
+        let (opens_at, closes_at, event_name) = match submission_window {
+            Some(submission_window) => (
+                submission_window.opens_at,
+                submission_window.closes_at,
+                submission_window.event_name,
+            ),
+            None => (None, None, None),
+        };
+        let opens_at = opens_at.unwrap_or(deployment.start);
+        let opens_at = match &event_name {
+            Some(event_name) => events
+                .iter()
+                .find(|event| event.name.eq(event_name) && event.has_triggered)
+                .map(|event| event.triggered_at.max(opens_at)),
+            None => Some(opens_at),
+        };
+
+        Self {
+            metric_key,
+            opens_at,
+            closes_at: closes_at.unwrap_or(deployment.end),
+            event_name,
+        }
+    }
+
+    pub fn has_opened(&self, timestamp: NaiveDateTime) -> bool {
+        self.opens_at.is_some_and(|opens_at| timestamp >= opens_at)
+    }
+
+    pub fn has_closed(&self, timestamp: NaiveDateTime) -> bool {
+        timestamp > self.closes_at
+    }
+}
@@ -1,5 +1,7 @@
 use std::fmt::{Display, Formatter, Result};
 
+use actix_web::http::Method;
+use diesel::{sql_types::Text, AsExpression, FromSqlRow};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
@@ -18,3 +20,224 @@ impl Display for RangerRole {
         }
     }
 }
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, FromSqlRow, AsExpression,
+)]
+#[diesel(sql_type = Text)]
+#[serde(rename_all = "camelCase")]
+pub enum ExerciseStaffRole {
+    Observer,
+    WhiteTeam,
+    Grader,
+}
+
+const EXERCISE_ROUTE_PARAMETER: &str = "{exercise_uuid}";
+
+const EXERCISE_NAVIGATION_ROUTES: &[(&str, &str)] = &[
+    ("GET", ""),
+    ("GET", "/deployment"),
+    ("GET", "/deployment/{deployment_uuid}"),
+];
+
+const EXERCISE_READ_ROUTES: &[(&str, &str)] = &[
+    ("GET", "/websocket"),
+    ("GET", "/leaderboard"),
+    ("GET", "/analytics"),
+    ("GET", "/scoring-strategy"),
+    ("GET", "/secret"),
+    ("GET", "/flag"),
+    ("GET", "/hint"),
+    ("GET", "/submission-window"),
+    ("GET", "/certificate-template"),
+    ("GET", "/rubric"),
+    ("GET", "/banner"),
+    ("GET", "/email"),
+    ("GET", "/email/{email_uuid}"),
+    ("GET", "/email-form"),
+    ("GET", "/deployment/{deployment_uuid}/deployment_element"),
+    ("GET", "/deployment/{deployment_uuid}/participant"),
+    ("GET", "/deployment/{deployment_uuid}/score"),
+    ("GET", "/deployment/{deployment_uuid}/score/history"),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/score/export/{export_type}",
+    ),
+    ("GET", "/deployment/{deployment_uuid}/scenario"),
+    ("GET", "/deployment/{deployment_uuid}/users"),
+    ("GET", "/deployment/{deployment_uuid}/leaderboard"),
+    ("GET", "/deployment/{deployment_uuid}/evaluation"),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/grading-reconciliation",
+    ),
+    ("GET", "/deployment/{deployment_uuid}/hint-release"),
+    ("GET", "/deployment/{deployment_uuid}/report"),
+    ("GET", "/deployment/{deployment_uuid}/condition"),
+    ("GET", "/deployment/{deployment_uuid}/penalty"),
+    ("GET", "/deployment/{deployment_uuid}/certificate"),
+    ("GET", "/deployment/{deployment_uuid}/event"),
+    ("GET", "/deployment/{deployment_uuid}/event/{event_info_id}"),
+    ("GET", "/deployment/{deployment_uuid}/metric"),
+    ("GET", "/deployment/{deployment_uuid}/metric/{metric_uuid}"),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/metric/{metric_uuid}/grading",
+    ),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/metric/{metric_uuid}/submission",
+    ),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/metric/{metric_uuid}/submission/diff",
+    ),
+];
+
+const WHITE_TEAM_CONTROL_ROUTES: &[(&str, &str)] = &[
+    ("POST", "/banner"),
+    ("PUT", "/banner"),
+    ("DELETE", "/banner"),
+    ("POST", "/email"),
+    ("DELETE", "/email/{email_uuid}"),
+    (
+        "POST",
+        "/deployment/{deployment_uuid}/event/{event_uuid}/trigger",
+    ),
+];
+
+const GRADER_ROUTES: &[(&str, &str)] = &[
+    ("GET", "/rubric"),
+    ("GET", "/deployment/{deployment_uuid}/grading-queue"),
+    (
+        "PUT",
+        "/deployment/{deployment_uuid}/grading-queue/{grading_assignment_uuid}",
+    ),
+    (
+        "GET",
+        "/deployment/{deployment_uuid}/grading-queue/{grading_assignment_uuid}/download",
+    ),
+];
+
+impl ExerciseStaffRole {
+    fn permitted_routes(&self) -> Vec<&'static [(&'static str, &'static str)]> {
+        match self {
+            ExerciseStaffRole::Observer => vec![EXERCISE_NAVIGATION_ROUTES, EXERCISE_READ_ROUTES],
+            ExerciseStaffRole::WhiteTeam => vec![
+                EXERCISE_NAVIGATION_ROUTES,
+                EXERCISE_READ_ROUTES,
+                WHITE_TEAM_CONTROL_ROUTES,
+            ],
+            ExerciseStaffRole::Grader => vec![EXERCISE_NAVIGATION_ROUTES, GRADER_ROUTES],
+        }
+    }
+
+    pub fn permits(&self, method: &Method, route_pattern: &str) -> bool {
+        route_pattern
+            .split_once(EXERCISE_ROUTE_PARAMETER)
+            .map_or(false, |(_, exercise_route)| {
+                self.permitted_routes().into_iter().flatten().any(
+                    |(permitted_method, permitted_route)| {
+                        *permitted_method == method.as_str() && *permitted_route == exercise_route
+                    },
+                )
+            })
+    }
+}
+
+impl Display for ExerciseStaffRole {
+    fn fmt(&self, f: &mut Formatter) -> Result {
+        match self {
+            ExerciseStaffRole::Observer => write!(f, "observer"),
+            ExerciseStaffRole::WhiteTeam => write!(f, "whiteTeam"),
+            ExerciseStaffRole::Grader => write!(f, "grader"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ExerciseStaffRole;
+    use actix_web::http::Method;
+
+    const EXERCISE_ROUTE: &str = "/api/v1/admin/exercise/{exercise_uuid}";
+
+    fn route(route: &str) -> String {
+        format!("{EXERCISE_ROUTE}{route}")
+    }
+
+    #[test]
+    fn observer_can_only_read_allowed_routes() {
+        let observer = ExerciseStaffRole::Observer;
+
+        assert!(observer.permits(&Method::GET, &route("")));
+        assert!(observer.permits(&Method::GET, &route("/deployment/{deployment_uuid}/score")));
+        assert!(!observer.permits(&Method::PUT, &route("")));
+        assert!(!observer.permits(&Method::POST, &route("/banner")));
+        assert!(!observer.permits(&Method::GET, &route("/deployment/{deployment_uuid}/secret")));
+        assert!(!observer.permits(
+            &Method::GET,
+            &route("/deployment/{deployment_uuid}/flag-attempt")
+        ));
+        assert!(!observer.permits(&Method::GET, &route("/role")));
+        assert!(!observer.permits(&Method::GET, &route("/questionnaire")));
+        assert!(!observer.permits(
+            &Method::POST,
+            &route("/deployment/{deployment_uuid}/event/{event_uuid}/trigger")
+        ));
+    }
+
+    #[test]
+    fn white_team_can_control_banners_emails_and_events() {
+        let white_team = ExerciseStaffRole::WhiteTeam;
+
+        assert!(white_team.permits(&Method::POST, &route("/banner")));
+        assert!(white_team.permits(&Method::DELETE, &route("/email/{email_uuid}")));
+        assert!(white_team.permits(&Method::GET, &route("/deployment/{deployment_uuid}/event")));
+        assert!(white_team.permits(
+            &Method::POST,
+            &route("/deployment/{deployment_uuid}/event/{event_uuid}/trigger")
+        ));
+        assert!(!white_team.permits(&Method::DELETE, &route("")));
+        assert!(!white_team.permits(&Method::POST, &route("/role")));
+    }
+
+    #[test]
+    fn grader_is_limited_to_the_grading_queue() {
+        let grader = ExerciseStaffRole::Grader;
+
+        assert!(grader.permits(
+            &Method::GET,
+            &route("/deployment/{deployment_uuid}/grading-queue")
+        ));
+        assert!(grader.permits(
+            &Method::PUT,
+            &route("/deployment/{deployment_uuid}/grading-queue/{grading_assignment_uuid}")
+        ));
+        assert!(!grader.permits(
+            &Method::DELETE,
+            &route("/deployment/{deployment_uuid}/metric/{metric_uuid}")
+        ));
+        assert!(!grader.permits(
+            &Method::POST,
+            &route("/deployment/{deployment_uuid}/metric/{metric_uuid}/assignment")
+        ));
+        assert!(!grader.permits(
+            &Method::GET,
+            &route("/deployment/{deployment_uuid}/metric/{metric_uuid}/assignment")
+        ));
+        assert!(!grader.permits(&Method::GET, &route("/deployment/{deployment_uuid}/metric")));
+    }
+
+    #[test]
+    fn unknown_routes_are_denied() {
+        for role in [
+            ExerciseStaffRole::Observer,
+            ExerciseStaffRole::WhiteTeam,
+            ExerciseStaffRole::Grader,
+        ] {
+            assert!(!role.permits(&Method::GET, &route("/event-control")));
+            assert!(!role.permits(&Method::GET, "/api/v1/admin/exercise"));
+        }
+    }
+}
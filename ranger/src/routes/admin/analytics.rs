@@ -0,0 +1,60 @@
+use crate::{
+    errors::RangerError,
+    middleware::exercise::ExerciseInfo,
+    models::ExerciseAnalytics,
+    services::database::{
+        condition::GetConditionFirstSuccessesByExerciseId, deployment::GetDeployments,
+    },
+    utilities::{
+        analytics::get_deployment_analytics_data, create_database_error_handler,
+        create_mailbox_error_handler,
+    },
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json},
+};
+use anyhow::Result;
+use chrono::Utc;
+use futures::future::try_join_all;
+
+#[get("analytics")]
+pub async fn get_admin_exercise_analytics(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<ExerciseAnalytics>, RangerError> {
+    let deployments = app_state
+        .database_address
+        .send(GetDeployments(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployments"))?;
+    let condition_first_successes = app_state
+        .database_address
+        .send(GetConditionFirstSuccessesByExerciseId(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler(
+            "Get condition first successes",
+        ))?;
+
+    let deployment_analytics_data = try_join_all(deployments.iter().map(|deployment| {
+        let database_address = app_state.database_address.clone();
+        async move { get_deployment_analytics_data(&database_address, deployment).await }
+    }))
+    .await
+    .map_err(create_database_error_handler(
+        "Get deployment analytics data",
+    ))?
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    Ok(Json(ExerciseAnalytics::new(
+        exercise.id,
+        &deployment_analytics_data,
+        &condition_first_successes,
+        Utc::now().naive_utc(),
+    )))
+}
@@ -0,0 +1,102 @@
+use crate::{
+    errors::RangerError,
+    middleware::authentication::UserInfo,
+    models::{
+        generate_api_token, helpers::uuid::Uuid, ApiTokenAuditLog, ApiTokenRest, CreatedApiToken,
+        NewApiToken, NewApiTokenResource,
+    },
+    services::database::api_token::{
+        CreateApiToken, GetApiTokenAuditLogs, GetApiTokens, RevokeApiToken,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler, Validation},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+use log::error;
+
+fn reject_api_token_user(user_info: &UserInfo) -> Result<(), RangerError> {
+    if user_info.api_token_id.is_some() {
+        error!("Api tokens can not manage api tokens");
+        return Err(RangerError::AccessForbidden);
+    }
+    Ok(())
+}
+
+#[get("")]
+pub async fn get_admin_api_tokens(
+    app_state: Data<AppState>,
+    user_info: UserInfo,
+) -> Result<Json<Vec<ApiTokenRest>>, RangerError> {
+    reject_api_token_user(&user_info)?;
+    let api_tokens = app_state
+        .database_address
+        .send(GetApiTokens)
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get api tokens"))?;
+
+    Ok(Json(api_tokens))
+}
+
+#[post("")]
+pub async fn add_admin_api_token(
+    app_state: Data<AppState>,
+    user_info: UserInfo,
+    new_api_token: Json<NewApiTokenResource>,
+) -> Result<Json<CreatedApiToken>, RangerError> {
+    reject_api_token_user(&user_info)?;
+    let new_api_token = new_api_token.into_inner();
+    new_api_token.validate()?;
+
+    let token = generate_api_token();
+    let api_token = app_state
+        .database_address
+        .send(CreateApiToken(
+            NewApiToken::new(&new_api_token, &token, user_info.id.clone()),
+            new_api_token.exercise_ids,
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create api token"))?;
+    log::debug!("Created api token: {}", api_token.id);
+
+    Ok(Json(CreatedApiToken { api_token, token }))
+}
+
+#[delete("/{api_token_uuid}")]
+pub async fn revoke_admin_api_token(
+    path_variable: Path<Uuid>,
+    app_state: Data<AppState>,
+    user_info: UserInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    reject_api_token_user(&user_info)?;
+    let api_token_uuid = app_state
+        .database_address
+        .send(RevokeApiToken(path_variable.into_inner()))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Revoke api token"))?;
+
+    Ok(Json(api_token_uuid))
+}
+
+#[get("/{api_token_uuid}/audit")]
+pub async fn get_admin_api_token_audit_logs(
+    path_variable: Path<Uuid>,
+    app_state: Data<AppState>,
+    user_info: UserInfo,
+) -> Result<Json<Vec<ApiTokenAuditLog>>, RangerError> {
+    reject_api_token_user(&user_info)?;
+    let audit_logs = app_state
+        .database_address
+        .send(GetApiTokenAuditLogs(path_variable.into_inner()))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get api token audit logs"))?;
+
+    Ok(Json(audit_logs))
+}
@@ -0,0 +1,306 @@
+use crate::{
+    errors::RangerError,
+    middleware::{
+        deployment::DeploymentInfo, exercise::ExerciseInfo, identity::IdentityProviderInfo,
+    },
+    models::{
+        helpers::uuid::Uuid, Certificate, CertificateTemplate, EmailResource,
+        IssueCertificatesResource, NewCertificate, NewCertificateTemplate,
+        NewCertificateTemplateResource, ReportFormat,
+    },
+    services::database::certificate::{
+        CreateCertificateTemplate, CreateCertificates, DeleteCertificateTemplate,
+        GetCertificateTemplates, GetCertificates, RevokeCertificate,
+    },
+    utilities::{
+        certificate::{
+            get_certificate_email_addresses, get_certificate_recipients, get_participant_users,
+        },
+        create_database_error_handler, create_mailbox_error_handler,
+        email::send_and_record_email,
+        evaluation::get_deployment_evaluation,
+        report::render_pdf,
+    },
+    AppState,
+};
+use actix_web::{
+    delete, get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    post,
+    web::{Data, Json, Path, Query},
+    HttpResponse,
+};
+use anyhow::Result;
+use log::error;
+use std::collections::HashMap;
+
+#[get("")]
+pub async fn get_admin_certificate_templates(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<CertificateTemplate>>, RangerError> {
+    let certificate_templates = app_state
+        .database_address
+        .send(GetCertificateTemplates(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificate templates"))?;
+
+    Ok(Json(certificate_templates))
+}
+
+#[post("")]
+pub async fn add_admin_certificate_template(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_certificate_template: Json<NewCertificateTemplateResource>,
+) -> Result<Json<CertificateTemplate>, RangerError> {
+    let certificate_template = app_state
+        .database_address
+        .send(CreateCertificateTemplate(NewCertificateTemplate::new(
+            new_certificate_template.into_inner(),
+            exercise.id,
+        )))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create certificate template"))?;
+
+    Ok(Json(certificate_template))
+}
+
+#[delete("/{certificate_template_uuid}")]
+pub async fn delete_admin_certificate_template(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, certificate_template_uuid) = path_variables.into_inner();
+    let certificate_templates = app_state
+        .database_address
+        .send(GetCertificateTemplates(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificate templates"))?;
+    if !certificate_templates
+        .iter()
+        .any(|certificate_template| certificate_template.id == certificate_template_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteCertificateTemplate(certificate_template_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete certificate template"))?;
+
+    Ok(Json(id))
+}
+
+#[get("")]
+pub async fn get_admin_certificates(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<Certificate>>, RangerError> {
+    let certificates = app_state
+        .database_address
+        .send(GetCertificates(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificates"))?;
+
+    Ok(Json(certificates))
+}
+
+#[post("")]
+pub async fn issue_admin_certificates(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    deployment: DeploymentInfo,
+    identity_provider: IdentityProviderInfo,
+    issue_certificates: Json<IssueCertificatesResource>,
+) -> Result<Json<Vec<Certificate>>, RangerError> {
+    let issue_certificates = issue_certificates.into_inner();
+    let deployment = deployment.into_inner();
+    let signing_key = app_state
+        .configuration
+        .certificate_signing_key
+        .clone()
+        .ok_or(RangerError::CertificateSigningKeyNotConfigured)?;
+    let mailer_configuration = match issue_certificates.send_email {
+        true => Some(
+            app_state
+                .configuration
+                .mailer_configuration
+                .clone()
+                .ok_or(RangerError::MailerConfigurationNotFound)?,
+        ),
+        false => None,
+    };
+
+    let certificate_templates = app_state
+        .database_address
+        .send(GetCertificateTemplates(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificate templates"))?;
+    let certificate_template = certificate_templates
+        .into_iter()
+        .find(|certificate_template| {
+            certificate_template.id == issue_certificates.certificate_template_id
+        })
+        .ok_or(RangerError::DatabaseRecordNotFound)?;
+    let evaluation = get_deployment_evaluation(&app_state.database_address, &deployment)
+        .await
+        .map_err(create_database_error_handler("Get deployment evaluation"))?;
+    let participant_users = match issue_certificates.per_participant
+        || issue_certificates.send_email
+    {
+        true => get_participant_users(&app_state, identity_provider.as_ref(), &deployment).await?,
+        false => vec![],
+    };
+    let existing_certificates = app_state
+        .database_address
+        .send(GetCertificates(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificates"))?;
+
+    let new_certificates =
+        get_certificate_recipients(&evaluation, &participant_users, &issue_certificates)
+            .into_iter()
+            .filter(|recipient| {
+                !existing_certificates
+                    .iter()
+                    .any(|certificate| certificate.is_issued_to(certificate_template.id, recipient))
+            })
+            .map(|recipient| {
+                NewCertificate::new(
+                    &certificate_template,
+                    &exercise,
+                    &deployment,
+                    recipient,
+                    &signing_key,
+                )
+            })
+            .collect::<Vec<NewCertificate>>();
+    let certificates = app_state
+        .database_address
+        .send(CreateCertificates(new_certificates))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create certificates"))?;
+
+    if let Some(mailer_configuration) = mailer_configuration {
+        for certificate in &certificates {
+            let to_addresses = get_certificate_email_addresses(certificate, &participant_users);
+            if to_addresses.is_empty() {
+                continue;
+            }
+            let email_resource = EmailResource {
+                id: Uuid::random(),
+                to_addresses,
+                reply_to_addresses: None,
+                cc_addresses: None,
+                bcc_addresses: None,
+                subject: format!("Certificate of completion: {}", certificate.exercise_name),
+                body: certificate.content.clone(),
+                user_id: certificate.user_id.clone(),
+            };
+            if let Err(error) = send_and_record_email(
+                &app_state.database_address,
+                mailer_configuration.clone(),
+                exercise.id,
+                email_resource,
+            )
+            .await
+            {
+                error!("Failed to email certificate {}: {error}", certificate.id);
+            }
+        }
+    }
+
+    Ok(Json(certificates))
+}
+
+#[get("/{certificate_uuid}")]
+pub async fn download_admin_certificate(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<HttpResponse, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, certificate_uuid) = path_variables.into_inner();
+    let format = params.get("format").map(String::as_str).unwrap_or("html");
+    let format = match ReportFormat::from_name(format) {
+        Some(ReportFormat::Markdown) | None => {
+            return Err(RangerError::InvalidParameter("format".to_string()))
+        }
+        Some(format) => format,
+    };
+    let certificates = app_state
+        .database_address
+        .send(GetCertificates(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificates"))?;
+    let certificate = certificates
+        .into_iter()
+        .find(|certificate| certificate.id == certificate_uuid)
+        .ok_or(RangerError::DatabaseRecordNotFound)?;
+
+    let content = match format {
+        ReportFormat::Pdf => {
+            let pdf_renderer = app_state
+                .configuration
+                .pdf_renderer
+                .as_ref()
+                .ok_or(RangerError::PdfRendererNotConfigured)?;
+            render_pdf(pdf_renderer, certificate.content).await?
+        }
+        _ => certificate.content.into_bytes(),
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .append_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "certificate-{}.{}",
+                certificate.id,
+                format.file_extension()
+            ))],
+        })
+        .body(content))
+}
+
+#[delete("/{certificate_uuid}")]
+pub async fn revoke_admin_certificate(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, certificate_uuid) = path_variables.into_inner();
+    let certificates = app_state
+        .database_address
+        .send(GetCertificates(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificates"))?;
+    if !certificates
+        .iter()
+        .any(|certificate| certificate.id == certificate_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(RevokeCertificate(certificate_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Revoke certificate"))?;
+
+    Ok(Json(id))
+}
@@ -0,0 +1,105 @@
+use crate::{
+    constants::CONDITION_DIAGNOSTICS_DEFAULT_RESOLUTION_SECONDS,
+    errors::RangerError,
+    middleware::deployment::DeploymentInfo,
+    models::{ConditionDiagnostics, ConditionMessage},
+    services::database::{
+        condition::GetConditionMessagesByDeploymentId,
+        deployment::GetDeploymentElementByDeploymentId,
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        scenario::get_metric_by_condition,
+    },
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json, Query},
+};
+use anyhow::Result;
+use log::error;
+use ranger_grpc::capabilities::DeployerType as GrpcDeployerType;
+use sdl_parser::parse_sdl;
+use std::collections::HashMap;
+
+#[get("")]
+pub async fn get_admin_condition_diagnostics(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Vec<ConditionDiagnostics>>, RangerError> {
+    let deployment = deployment.into_inner();
+    let resolution_seconds = match params.get("resolution") {
+        Some(resolution) => resolution
+            .parse::<i64>()
+            .ok()
+            .filter(|resolution| *resolution > 0)
+            .ok_or_else(|| RangerError::InvalidParameter("resolution".to_string()))?,
+        None => CONDITION_DIAGNOSTICS_DEFAULT_RESOLUTION_SECONDS,
+    };
+    let scenario = parse_sdl(&deployment.sdl_schema).map_err(|error| {
+        error!("Failed to parse sdl: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+
+    let deployment_elements = app_state
+        .database_address
+        .send(GetDeploymentElementByDeploymentId(deployment.id, false))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployment elements"))?;
+    let condition_messages = app_state
+        .database_address
+        .send(GetConditionMessagesByDeploymentId(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get condition_messages"))?;
+
+    let vm_names_by_id: HashMap<String, String> = deployment_elements
+        .iter()
+        .filter(|element| matches!(element.deployer_type.0, GrpcDeployerType::VirtualMachine))
+        .filter_map(|element| {
+            element
+                .handler_reference
+                .clone()
+                .map(|handler_reference| (handler_reference, element.scenario_reference.clone()))
+        })
+        .collect();
+
+    let mut condition_messages_by_condition_id: HashMap<String, Vec<&ConditionMessage>> =
+        HashMap::new();
+    for condition_message in condition_messages.iter() {
+        condition_messages_by_condition_id
+            .entry(condition_message.condition_id.to_string())
+            .or_default()
+            .push(condition_message);
+    }
+
+    let condition_diagnostics = deployment_elements
+        .iter()
+        .filter(|element| matches!(element.deployer_type.0, GrpcDeployerType::Condition))
+        .map(|condition_element| {
+            let vm_name = condition_element
+                .parent_node_id
+                .and_then(|vm_id| vm_names_by_id.get(&vm_id.to_string()).cloned())
+                .unwrap_or_default();
+            let condition_messages = condition_element
+                .handler_reference
+                .as_ref()
+                .and_then(|condition_id| condition_messages_by_condition_id.get(condition_id))
+                .cloned()
+                .unwrap_or_default();
+
+            ConditionDiagnostics::new(
+                vm_name,
+                condition_element,
+                &condition_messages,
+                get_metric_by_condition(&scenario.metrics, &condition_element.scenario_reference),
+                resolution_seconds,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Json(condition_diagnostics))
+}
@@ -0,0 +1,110 @@
+use crate::{
+    constants::{DEFAULT_SECRET_LENGTH, MAX_SECRET_LENGTH},
+    errors::RangerError,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::{
+        helpers::uuid::Uuid, DeploymentSecret, NewSecretDefinition, NewSecretDefinitionResource,
+        SecretDefinition,
+    },
+    services::database::deployment_secret::{
+        CreateSecretDefinition, DeleteSecretDefinition, GetDeploymentSecrets, GetSecretDefinitions,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_secret_definitions(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<SecretDefinition>>, RangerError> {
+    let secret_definitions = app_state
+        .database_address
+        .send(GetSecretDefinitions(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get secret definitions"))?;
+
+    Ok(Json(secret_definitions))
+}
+
+#[post("")]
+pub async fn add_admin_secret_definition(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_secret_definition: Json<NewSecretDefinitionResource>,
+) -> Result<Json<SecretDefinition>, RangerError> {
+    let new_secret_definition = new_secret_definition.into_inner();
+    if !SecretDefinition::is_valid_name(&new_secret_definition.name) {
+        return Err(RangerError::InvalidParameter("name".to_string()));
+    }
+    let length = new_secret_definition
+        .length
+        .unwrap_or(DEFAULT_SECRET_LENGTH);
+    if length == 0 || length > MAX_SECRET_LENGTH {
+        return Err(RangerError::InvalidParameter("length".to_string()));
+    }
+
+    let secret_definition = app_state
+        .database_address
+        .send(CreateSecretDefinition(NewSecretDefinition::new(
+            new_secret_definition,
+            exercise.id,
+            length,
+        )))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create secret definition"))?;
+
+    Ok(Json(secret_definition))
+}
+
+#[delete("/{secret_definition_uuid}")]
+pub async fn delete_admin_secret_definition(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, secret_definition_uuid) = path_variables.into_inner();
+    let secret_definitions = app_state
+        .database_address
+        .send(GetSecretDefinitions(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get secret definitions"))?;
+    if !secret_definitions
+        .iter()
+        .any(|secret_definition| secret_definition.id == secret_definition_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteSecretDefinition(secret_definition_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete secret definition"))?;
+
+    Ok(Json(id))
+}
+
+#[get("secret")]
+pub async fn get_admin_deployment_secrets(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<DeploymentSecret>>, RangerError> {
+    let deployment_secrets = app_state
+        .database_address
+        .send(GetDeploymentSecrets(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployment secrets"))?;
+
+    Ok(Json(deployment_secrets))
+}
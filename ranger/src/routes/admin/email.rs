@@ -2,20 +2,18 @@ use crate::{
     errors::RangerError,
     middleware::exercise::ExerciseInfo,
     models::{
-        helpers::uuid::Uuid, Email, EmailResource, EmailTemplate, EmailWithStatus, NewEmail,
-        NewEmailStatus, NewEmailTemplate,
+        helpers::uuid::Uuid, Email, EmailResource, EmailTemplate, EmailWithStatus, NewEmailTemplate,
     },
-    services::{
-        database::{
-            email::{
-                CreateEmail, CreateEmailTemplate, DeleteEmail, DeleteEmailTemplate, GetEmail,
-                GetEmailTemplate, GetEmailTemplates, GetEmails,
-            },
-            email_status::{CreateEmailStatus, GetEmailStatus, GetEmailStatuses},
+    services::database::{
+        email::{
+            CreateEmailTemplate, DeleteEmail, DeleteEmailTemplate, GetEmail, GetEmailTemplate,
+            GetEmailTemplates, GetEmails,
         },
-        mailer::Mailer,
+        email_status::{GetEmailStatus, GetEmailStatuses},
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler, email::send_and_record_email,
     },
-    utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
 use actix_web::{
@@ -23,7 +21,6 @@ use actix_web::{
     web::{Data, Json, Path},
 };
 use anyhow::Result;
-use log::error;
 
 #[post("")]
 pub async fn send_email(
@@ -31,78 +28,20 @@ pub async fn send_email(
     app_state: Data<AppState>,
     email_resource: Json<EmailResource>,
 ) -> Result<Json<Email>, RangerError> {
-    let email;
     let email_resource = email_resource.into_inner();
-    let mailer_configuration = app_state.configuration.mailer_configuration.clone();
-
-    if let Some(mailer_configuration) = mailer_configuration {
-        let mailer = Mailer::new(mailer_configuration.clone());
-        let new_email = NewEmail::new(
-            email_resource.clone(),
-            mailer_configuration.from_address.clone(),
-            exercise.id,
-        );
-        let email_status_pending = NewEmailStatus::new_pending(new_email.id);
-
-        email = app_state
-            .database_address
-            .send(CreateEmail(new_email.clone()))
-            .await
-            .map_err(create_mailbox_error_handler("Database"))?
-            .map_err(create_database_error_handler("Create email"))?;
-
-        app_state
-            .database_address
-            .send(CreateEmailStatus(email_status_pending))
-            .await
-            .map_err(create_mailbox_error_handler("Database"))?
-            .map_err(create_database_error_handler("Create email status"))?;
-
-        let message = match email_resource.create_message(mailer_configuration.from_address) {
-            Ok(message) => message,
-            Err(error) => {
-                error!("Failed to create message: {error}");
-                let email_status_error =
-                    NewEmailStatus::new_failed(new_email.id, error.to_string());
-
-                app_state
-                    .database_address
-                    .send(CreateEmailStatus(email_status_error))
-                    .await
-                    .map_err(create_mailbox_error_handler("Database"))?
-                    .map_err(create_database_error_handler("Create email status"))?;
-
-                return Err(RangerError::EmailMessageCreationFailed);
-            }
-        };
-
-        match mailer.send_message(message) {
-            Ok(response) => {
-                let email_status_sent =
-                    NewEmailStatus::new_sent(new_email.id, response.first_line());
-                app_state
-                    .database_address
-                    .send(CreateEmailStatus(email_status_sent))
-                    .await
-                    .map_err(create_mailbox_error_handler("Database"))?
-                    .map_err(create_database_error_handler("Create email status"))?;
-            }
-            Err(error) => {
-                error!("Failed to send email: {error}");
-                let email_status_error =
-                    NewEmailStatus::new_failed(new_email.id, error.to_string());
-
-                app_state
-                    .database_address
-                    .send(CreateEmailStatus(email_status_error))
-                    .await
-                    .map_err(create_mailbox_error_handler("Database"))?
-                    .map_err(create_database_error_handler("Create email status"))?;
-            }
-        }
-    } else {
-        return Err(RangerError::MailerConfigurationNotFound);
-    }
+    let mailer_configuration = app_state
+        .configuration
+        .mailer_configuration
+        .clone()
+        .ok_or(RangerError::MailerConfigurationNotFound)?;
+
+    let email = send_and_record_email(
+        &app_state.database_address,
+        mailer_configuration,
+        exercise.id,
+        email_resource,
+    )
+    .await?;
 
     Ok(Json(email))
 }
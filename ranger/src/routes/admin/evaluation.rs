@@ -0,0 +1,25 @@
+use crate::{
+    errors::RangerError,
+    middleware::deployment::DeploymentInfo,
+    models::DeploymentEvaluation,
+    utilities::{create_database_error_handler, evaluation::get_deployment_evaluation},
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json},
+};
+use anyhow::Result;
+
+#[get("evaluation")]
+pub async fn get_admin_deployment_evaluation(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<DeploymentEvaluation>, RangerError> {
+    let deployment = deployment.into_inner();
+    let evaluation = get_deployment_evaluation(&app_state.database_address, &deployment)
+        .await
+        .map_err(create_database_error_handler("Get deployment evaluation"))?;
+
+    Ok(Json(evaluation))
+}
@@ -2,15 +2,19 @@ use crate::{
     errors::RangerError,
     middleware::deployment::DeploymentInfo,
     models::{event_info::EventInfo, helpers::uuid::Uuid, Event},
-    services::database::{event::GetEventsByDeploymentId, event_info::GetEventInfo},
+    services::database::{
+        event::{GetEvent, GetEventsByDeploymentId, UpdateEvent},
+        event_info::GetEventInfo,
+    },
     utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
 use actix_web::{
-    get,
+    get, post,
     web::{Data, Json, Path},
 };
 use anyhow::Result;
+use chrono::Utc;
 
 #[get("")]
 pub async fn get_exercise_deployment_events(
@@ -46,3 +50,41 @@ pub async fn get_admin_event_info_data(
 
     Ok(Json(event))
 }
+
+#[post("/{event_uuid}/trigger")]
+pub async fn trigger_admin_event(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Event>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, event_uuid) = path_variables.into_inner();
+    let event = app_state
+        .database_address
+        .send(GetEvent(event_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get event"))?;
+    if event.deployment_id != deployment.id {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+    let now = Utc::now().naive_utc();
+    if event.has_triggered || now < event.start || now > event.end {
+        return Err(RangerError::EventNotActive);
+    }
+
+    let event = app_state
+        .database_address
+        .send(UpdateEvent(
+            deployment.exercise_id,
+            event.id,
+            crate::models::UpdateEvent {
+                has_triggered: true,
+                triggered_at: now,
+            },
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Trigger event"))?;
+
+    Ok(Json(event))
+}
@@ -0,0 +1,89 @@
+use crate::{
+    errors::RangerError,
+    middleware::{authentication::UserInfo, exercise::ExerciseInfo},
+    models::{
+        helpers::uuid::Uuid, ExerciseRoleAssignment, NewExerciseRoleAssignment,
+        NewExerciseRoleAssignmentResource,
+    },
+    services::database::exercise_role_assignment::{
+        CreateExerciseRoleAssignment, DeleteExerciseRoleAssignment, GetExerciseRoleAssignments,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler, Validation},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_exercise_roles(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<ExerciseRoleAssignment>>, RangerError> {
+    let exercise_role_assignments = app_state
+        .database_address
+        .send(GetExerciseRoleAssignments(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get exercise roles"))?;
+
+    Ok(Json(exercise_role_assignments))
+}
+
+#[post("")]
+pub async fn add_admin_exercise_role(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    user_info: UserInfo,
+    new_exercise_role_assignment: Json<NewExerciseRoleAssignmentResource>,
+) -> Result<Json<ExerciseRoleAssignment>, RangerError> {
+    let new_exercise_role_assignment = new_exercise_role_assignment.into_inner();
+    new_exercise_role_assignment.validate()?;
+
+    let exercise_role_assignment = app_state
+        .database_address
+        .send(CreateExerciseRoleAssignment(
+            NewExerciseRoleAssignment::new(
+                new_exercise_role_assignment,
+                exercise.id,
+                Some(user_info.id.clone()),
+            ),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create exercise role"))?;
+
+    Ok(Json(exercise_role_assignment))
+}
+
+#[delete("/{exercise_role_uuid}")]
+pub async fn delete_admin_exercise_role(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, exercise_role_uuid) = path_variables.into_inner();
+    let exercise_role_assignments = app_state
+        .database_address
+        .send(GetExerciseRoleAssignments(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get exercise roles"))?;
+    if !exercise_role_assignments
+        .iter()
+        .any(|exercise_role_assignment| exercise_role_assignment.id == exercise_role_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteExerciseRoleAssignment(exercise_role_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete exercise role"))?;
+
+    Ok(Json(id))
+}
@@ -0,0 +1,114 @@
+use crate::{
+    errors::RangerError,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::{helpers::uuid::Uuid, Flag, FlagAttempt, NewFlag, NewFlagResource},
+    services::database::{
+        deployment_secret::GetSecretDefinitions,
+        flag::{CreateFlag, DeleteFlag, GetFlagAttempts, GetFlags},
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_flags(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<Flag>>, RangerError> {
+    let flags = app_state
+        .database_address
+        .send(GetFlags(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get flags"))?;
+
+    Ok(Json(flags))
+}
+
+#[post("")]
+pub async fn add_admin_flag(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_flag: Json<NewFlagResource>,
+) -> Result<Json<Flag>, RangerError> {
+    let new_flag = new_flag.into_inner();
+    match new_flag.secret_definition_id {
+        Some(secret_definition_id) => {
+            let secret_definitions = app_state
+                .database_address
+                .send(GetSecretDefinitions(exercise.id))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Get secret definitions"))?;
+            if !secret_definitions
+                .iter()
+                .any(|secret_definition| secret_definition.id == secret_definition_id)
+            {
+                return Err(RangerError::InvalidParameter(
+                    "secretDefinitionId".to_string(),
+                ));
+            }
+        }
+        None => {
+            if new_flag.answer.trim().is_empty() {
+                return Err(RangerError::InvalidParameter("answer".to_string()));
+            }
+        }
+    }
+
+    let flag = app_state
+        .database_address
+        .send(CreateFlag(NewFlag::new(new_flag, exercise.id)))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create flag"))?;
+
+    Ok(Json(flag))
+}
+
+#[delete("/{flag_uuid}")]
+pub async fn delete_admin_flag(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, flag_uuid) = path_variables.into_inner();
+    let flags = app_state
+        .database_address
+        .send(GetFlags(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get flags"))?;
+    if !flags.iter().any(|flag| flag.id == flag_uuid) {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteFlag(flag_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete flag"))?;
+
+    Ok(Json(id))
+}
+
+#[get("flag-attempt")]
+pub async fn get_admin_deployment_flag_attempts(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<FlagAttempt>>, RangerError> {
+    let flag_attempts = app_state
+        .database_address
+        .send(GetFlagAttempts(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get flag attempts"))?;
+
+    Ok(Json(flag_attempts))
+}
@@ -0,0 +1,194 @@
+use crate::{
+    constants::{ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST, OCTET_STREAM_MIME_TYPE},
+    errors::RangerError,
+    middleware::{authentication::UserInfo, deployment::DeploymentInfo, metric::MetricInfo},
+    models::{
+        helpers::uuid::Uuid, AnonymisedSubmission, GradingAssignment, GradingReconciliation,
+        NewGradingAssignmentsResource, SubmitGradingAssignmentResource,
+    },
+    services::database::{
+        grading_assignment::{
+            AssignGraders, GetGradingAssignment, GetGradingQueue, GetGradingReconciliation,
+            GetGradingReconciliations, SubmitGrade,
+        },
+        metric::GetMetric,
+        upload::GetArtifactByMetricId,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler, get_file_extension},
+    AppState,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    post, put,
+    web::{Data, Json, Path},
+    HttpResponse,
+};
+use anyhow::Result;
+
+async fn get_graders_assignment(
+    app_state: &Data<AppState>,
+    deployment: &DeploymentInfo,
+    user_details: &UserInfo,
+    grading_assignment_uuid: Uuid,
+) -> Result<GradingAssignment, RangerError> {
+    let grading_assignment = app_state
+        .database_address
+        .send(GetGradingAssignment(grading_assignment_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get grading assignment"))?;
+    if grading_assignment.deployment_id != deployment.id
+        || grading_assignment.grader_id != user_details.id
+    {
+        return Err(RangerError::NotAuthorized);
+    }
+
+    Ok(grading_assignment)
+}
+
+#[get("/assignment")]
+pub async fn get_admin_metric_grading_assignments(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+) -> Result<Json<GradingReconciliation>, RangerError> {
+    let grading_reconciliation = app_state
+        .database_address
+        .send(GetGradingReconciliation(metric_info.into_inner().id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get grading assignments"))?;
+
+    Ok(Json(grading_reconciliation))
+}
+
+#[post("/assignment")]
+pub async fn assign_admin_metric_graders(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    new_assignments: Json<NewGradingAssignmentsResource>,
+) -> Result<Json<GradingReconciliation>, RangerError> {
+    let mut new_assignments = new_assignments.into_inner();
+    new_assignments.grader_ids.sort();
+    new_assignments.grader_ids.dedup();
+    if new_assignments.grader_ids.is_empty() {
+        return Err(RangerError::InvalidParameter("graderIds".to_string()));
+    }
+
+    let grading_reconciliation = app_state
+        .database_address
+        .send(AssignGraders(metric_info.into_inner(), new_assignments))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Assign graders"))?;
+
+    Ok(Json(grading_reconciliation))
+}
+
+#[get("grading-reconciliation")]
+pub async fn get_admin_deployment_grading_reconciliations(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<GradingReconciliation>>, RangerError> {
+    let grading_reconciliations = app_state
+        .database_address
+        .send(GetGradingReconciliations(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get grading reconciliations"))?;
+
+    Ok(Json(grading_reconciliations))
+}
+
+#[get("")]
+pub async fn get_admin_grading_queue(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    user_details: UserInfo,
+) -> Result<Json<Vec<AnonymisedSubmission>>, RangerError> {
+    let submissions = app_state
+        .database_address
+        .send(GetGradingQueue(deployment.id, user_details.id.clone()))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get grading queue"))?;
+
+    Ok(Json(submissions))
+}
+
+#[put("/{grading_assignment_uuid}")]
+pub async fn submit_admin_grading_assignment(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    user_details: UserInfo,
+    submit_resource: Json<SubmitGradingAssignmentResource>,
+) -> Result<Json<AnonymisedSubmission>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, grading_assignment_uuid) = path_variables.into_inner();
+    let submit_resource = submit_resource.into_inner();
+    let grading_assignment = get_graders_assignment(
+        &app_state,
+        &deployment,
+        &user_details,
+        grading_assignment_uuid,
+    )
+    .await?;
+    let metric = app_state
+        .database_address
+        .send(GetMetric(grading_assignment.metric_id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric"))?;
+    if submit_resource.score > metric.max_score {
+        return Err(RangerError::InvalidParameter("score".to_string()));
+    }
+
+    let submission = app_state
+        .database_address
+        .send(SubmitGrade(grading_assignment.id, submit_resource))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Submit grade"))?;
+
+    Ok(Json(submission))
+}
+
+#[get("/{grading_assignment_uuid}/download")]
+pub async fn download_admin_grading_assignment_artifact(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    user_details: UserInfo,
+) -> Result<HttpResponse, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, grading_assignment_uuid) = path_variables.into_inner();
+    let grading_assignment = get_graders_assignment(
+        &app_state,
+        &deployment,
+        &user_details,
+        grading_assignment_uuid,
+    )
+    .await?;
+    let artifact = app_state
+        .database_address
+        .send(GetArtifactByMetricId(grading_assignment.metric_id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get Metric Artifact"))?;
+
+    let file_extension = get_file_extension(&artifact.name).unwrap_or_default();
+    let content_type = ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST
+        .get(file_extension)
+        .unwrap_or(&OCTET_STREAM_MIME_TYPE)
+        .to_owned();
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .append_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "submission-{}.{file_extension}",
+                grading_assignment.id
+            ))],
+        })
+        .body(artifact.content))
+}
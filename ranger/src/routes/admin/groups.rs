@@ -1,56 +1,29 @@
-use crate::{errors::RangerError, middleware::keycloak::KeycloakInfo, AppState};
+use crate::{
+    errors::RangerError,
+    middleware::identity::IdentityProviderInfo,
+    services::identity::{IdentityGroup, IdentityUser},
+};
 use actix_web::{
     get,
-    web::{Data, Json, Path},
+    web::{Json, Path},
 };
-use keycloak::types::{RoleRepresentation, UserRepresentation};
-use log::error;
 
 #[get("")]
 pub async fn get_participant_groups(
-    app_state: Data<AppState>,
-    keycloak_info: KeycloakInfo,
-) -> Result<Json<Vec<RoleRepresentation>>, RangerError> {
-    let roles = keycloak_info
-        .service_user
-        .realm_clients_with_id_roles_get(
-            &app_state.configuration.keycloak.realm,
-            &keycloak_info.client_id,
-            None,
-            None,
-            None,
-            None,
-        )
-        .await
-        .map_err(|error| {
-            error!("Failed to get keycloak clients: {error}");
-            RangerError::KeycloakQueryFailed
-        })?;
+    identity_provider: IdentityProviderInfo,
+) -> Result<Json<Vec<IdentityGroup>>, RangerError> {
+    let groups = identity_provider.get_groups().await?;
 
-    Ok(Json(roles))
+    Ok(Json(groups))
 }
 
 #[get("/{group_name}/users")]
 pub async fn get_participant_groups_users(
     path_variables: Path<String>,
-    app_state: Data<AppState>,
-    keycloak_info: KeycloakInfo,
-) -> Result<Json<Vec<UserRepresentation>>, RangerError> {
-    let role_name = path_variables.into_inner();
-    let users = keycloak_info
-        .service_user
-        .realm_clients_with_id_roles_with_role_name_users_get(
-            &app_state.configuration.keycloak.realm,
-            &keycloak_info.client_id,
-            &role_name,
-            None,
-            None,
-        )
-        .await
-        .map_err(|error| {
-            error!("Failed to get keycloak clients: {error}");
-            RangerError::KeycloakQueryFailed
-        })?;
+    identity_provider: IdentityProviderInfo,
+) -> Result<Json<Vec<IdentityUser>>, RangerError> {
+    let group_name = path_variables.into_inner();
+    let users = identity_provider.get_group_users(&group_name).await?;
 
     Ok(Json(users))
 }
@@ -0,0 +1,110 @@
+use crate::{
+    constants::BIG_DECIMAL_ZERO,
+    errors::RangerError,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::{helpers::uuid::Uuid, Hint, HintRelease, HintReleaseType, NewHint, NewHintResource},
+    services::database::hint::{CreateHint, DeleteHint, GetHintReleases, GetHints},
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_hints(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<Hint>>, RangerError> {
+    let hints = app_state
+        .database_address
+        .send(GetHints(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get hints"))?;
+
+    Ok(Json(hints))
+}
+
+#[post("")]
+pub async fn add_admin_hint(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_hint: Json<NewHintResource>,
+) -> Result<Json<Hint>, RangerError> {
+    let new_hint = new_hint.into_inner();
+    if new_hint.title.trim().is_empty() {
+        return Err(RangerError::InvalidParameter("title".to_string()));
+    }
+    if new_hint
+        .cost
+        .as_ref()
+        .is_some_and(|cost| *cost < *BIG_DECIMAL_ZERO)
+    {
+        return Err(RangerError::InvalidParameter("cost".to_string()));
+    }
+    match new_hint.release_type {
+        HintReleaseType::Timer if new_hint.release_after_minutes.is_none() => {
+            return Err(RangerError::MissingParameter(
+                "releaseAfterMinutes".to_string(),
+            ));
+        }
+        HintReleaseType::Event if new_hint.event_name.is_none() => {
+            return Err(RangerError::MissingParameter("eventName".to_string()));
+        }
+        _ => {}
+    }
+
+    let hint = app_state
+        .database_address
+        .send(CreateHint(NewHint::new(new_hint, exercise.id)))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create hint"))?;
+
+    Ok(Json(hint))
+}
+
+#[delete("/{hint_uuid}")]
+pub async fn delete_admin_hint(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, hint_uuid) = path_variables.into_inner();
+    let hints = app_state
+        .database_address
+        .send(GetHints(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get hints"))?;
+    if !hints.iter().any(|hint| hint.id == hint_uuid) {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteHint(hint_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete hint"))?;
+
+    Ok(Json(id))
+}
+
+#[get("hint-release")]
+pub async fn get_admin_deployment_hint_releases(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<HintRelease>>, RangerError> {
+    let hint_releases = app_state
+        .database_address
+        .send(GetHintReleases(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get hint releases"))?;
+
+    Ok(Json(hint_releases))
+}
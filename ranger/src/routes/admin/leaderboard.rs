@@ -0,0 +1,60 @@
+use crate::{
+    errors::RangerError,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::Leaderboard,
+    services::database::deployment::GetDeployments,
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        score::get_deployment_entity_scores,
+    },
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json},
+};
+use anyhow::Result;
+use futures::future::try_join_all;
+
+#[get("leaderboard")]
+pub async fn get_admin_deployment_leaderboard(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Leaderboard>, RangerError> {
+    let deployment = deployment.into_inner();
+    let entity_scores = get_deployment_entity_scores(&app_state.database_address, &deployment)
+        .await
+        .map_err(create_database_error_handler("Get entity scores"))?;
+
+    Ok(Json(Leaderboard::new(vec![(
+        deployment.id,
+        deployment.name,
+        entity_scores,
+    )])))
+}
+
+#[get("leaderboard")]
+pub async fn get_admin_exercise_leaderboard(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Leaderboard>, RangerError> {
+    let deployments = app_state
+        .database_address
+        .send(GetDeployments(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployments"))?;
+
+    let deployment_entity_scores = try_join_all(deployments.into_iter().map(|deployment| {
+        let database_address = app_state.database_address.clone();
+        async move {
+            let entity_scores =
+                get_deployment_entity_scores(&database_address, &deployment).await?;
+            Ok((deployment.id, deployment.name, entity_scores))
+        }
+    }))
+    .await
+    .map_err(create_database_error_handler("Get entity scores"))?;
+
+    Ok(Json(Leaderboard::new(deployment_entity_scores)))
+}
@@ -1,37 +1,50 @@
 use crate::{
     constants::{ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST, OCTET_STREAM_MIME_TYPE},
     errors::RangerError,
-    middleware::{deployment::DeploymentInfo, metric::MetricInfo},
-    models::{helpers::uuid::Uuid, metric::UpdateMetric, Metric},
+    middleware::{authentication::UserInfo, deployment::DeploymentInfo, metric::MetricInfo},
+    models::{
+        helpers::uuid::Uuid, upload::Artifact, GradeMetricResource, Metric, MetricGrading,
+        MetricSubmission, MetricSubmissionDiff, RevertMetricGradingResource,
+    },
     services::database::{
+        grading_assignment::GetPendingGradingMetricIds,
         metric::{DeleteMetric, GetMetrics},
-        upload::GetArtifactByMetricId,
+        metric_grading::{GetMetricGradings, GradeMetric, RevertMetricGrading},
+        metric_submission::{GetMetricSubmission, GetMetricSubmissions},
+        upload::{GetArtifact, GetArtifactByMetricId},
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler, diff::diff_lines,
+        get_file_extension,
     },
-    utilities::{create_database_error_handler, create_mailbox_error_handler, get_file_extension},
     AppState,
 };
 use actix_web::{
     delete, get,
     http::header::{ContentDisposition, DispositionParam, DispositionType},
-    put,
-    web::{Data, Json},
+    post, put,
+    web::{Data, Json, Path, Query},
     HttpResponse,
 };
+use std::collections::HashMap;
 
 #[put("")]
 pub async fn update_admin_metric(
     app_state: Data<AppState>,
     metric_info: MetricInfo,
-    update_metric: Json<UpdateMetric>,
+    user_details: UserInfo,
+    grade_metric_resource: Json<GradeMetricResource>,
 ) -> Result<Json<Metric>, RangerError> {
     let metric = metric_info.into_inner();
-    let update_metric = update_metric.into_inner();
+    let grade_metric_resource = grade_metric_resource.into_inner();
 
     let metric = app_state
         .database_address
-        .send(crate::services::database::metric::UpdateMetric(
+        .send(GradeMetric(
             metric.id,
-            update_metric,
+            grade_metric_resource.update_metric,
+            user_details.id.clone(),
+            grade_metric_resource.comment,
         ))
         .await
         .map_err(create_mailbox_error_handler("Deployment"))?
@@ -48,17 +61,76 @@ pub async fn get_admin_metric(metric_info: MetricInfo) -> Result<Json<Metric>, R
 pub async fn get_admin_metrics(
     app_state: Data<AppState>,
     deployment: DeploymentInfo,
+    user_details: UserInfo,
 ) -> Result<Json<Vec<Metric>>, RangerError> {
+    let pending_metric_ids = app_state
+        .database_address
+        .send(GetPendingGradingMetricIds(
+            deployment.id,
+            user_details.id.clone(),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler(
+            "Get pending grading assignments",
+        ))?;
+    let metrics = app_state
+        .database_address
+        .send(GetMetrics(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get Manual Metrics"))?
+        .into_iter()
+        .filter(|metric| !pending_metric_ids.contains(&metric.id))
+        .collect::<Vec<Metric>>();
+
+    Ok(Json(metrics))
+}
+
+#[get("/grading")]
+pub async fn get_admin_metric_gradings(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+) -> Result<Json<Vec<MetricGrading>>, RangerError> {
     Ok(Json(
         app_state
             .database_address
-            .send(GetMetrics(deployment.id))
+            .send(GetMetricGradings(metric_info.into_inner().id))
             .await
             .map_err(create_mailbox_error_handler("Database"))?
-            .map_err(create_database_error_handler("Get Manual Metrics"))?,
+            .map_err(create_database_error_handler("Get metric gradings"))?,
     ))
 }
 
+#[post("/grading/{metric_grading_uuid}/revert")]
+pub async fn revert_admin_metric_grading(
+    path_variables: Path<(Uuid, Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    user_details: UserInfo,
+    revert_resource: Option<Json<RevertMetricGradingResource>>,
+) -> Result<Json<Metric>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, _metric_uuid, metric_grading_uuid) =
+        path_variables.into_inner();
+    let revert_resource = revert_resource
+        .map(|revert_resource| revert_resource.into_inner())
+        .unwrap_or_default();
+
+    let metric = app_state
+        .database_address
+        .send(RevertMetricGrading(
+            metric_info.into_inner().id,
+            metric_grading_uuid,
+            user_details.id.clone(),
+            revert_resource.comment,
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Revert metric grading"))?;
+
+    Ok(Json(metric))
+}
+
 #[delete("")]
 pub async fn delete_metric(
     app_state: Data<AppState>,
@@ -75,6 +147,22 @@ pub async fn delete_metric(
     Ok(Json(metric.id))
 }
 
+fn create_artifact_response(artifact: Artifact) -> HttpResponse {
+    let file_extension = get_file_extension(&artifact.name).unwrap_or_default();
+    let content_type = ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST
+        .get(file_extension)
+        .unwrap_or(&OCTET_STREAM_MIME_TYPE)
+        .to_owned();
+
+    HttpResponse::Ok()
+        .content_type(content_type)
+        .append_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(artifact.name.to_owned())],
+        })
+        .body(artifact.content)
+}
+
 #[get("/download")]
 pub async fn download_metric_artifact(
     app_state: Data<AppState>,
@@ -87,17 +175,91 @@ pub async fn download_metric_artifact(
         .map_err(create_mailbox_error_handler("Database"))?
         .map_err(create_database_error_handler("Get Metric Artifact"))?;
 
-    let file_extension = get_file_extension(&artifact.name).unwrap_or_default();
-    let content_type = ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST
-        .get(file_extension)
-        .unwrap_or(&OCTET_STREAM_MIME_TYPE)
-        .to_owned();
+    Ok(create_artifact_response(artifact))
+}
 
-    Ok(HttpResponse::Ok()
-        .content_type(content_type)
-        .append_header(ContentDisposition {
-            disposition: DispositionType::Attachment,
-            parameters: vec![DispositionParam::Filename(artifact.name.to_owned())],
-        })
-        .body(artifact.content))
+#[get("/submission")]
+pub async fn get_admin_metric_submissions(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+) -> Result<Json<Vec<MetricSubmission>>, RangerError> {
+    Ok(Json(
+        app_state
+            .database_address
+            .send(GetMetricSubmissions(metric_info.into_inner().id))
+            .await
+            .map_err(create_mailbox_error_handler("Database"))?
+            .map_err(create_database_error_handler("Get metric submissions"))?,
+    ))
+}
+
+#[get("/submission/diff")]
+pub async fn get_admin_metric_submission_diff(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<MetricSubmissionDiff>, RangerError> {
+    let metric = metric_info.into_inner();
+    let parse_version = |name: &str| {
+        params
+            .get(name)
+            .ok_or_else(|| RangerError::MissingParameter(name.to_string()))?
+            .parse::<u32>()
+            .map_err(|_| RangerError::InvalidParameter(name.to_string()))
+    };
+    let from_version = parse_version("from")?;
+    let to_version = parse_version("to")?;
+
+    let from_submission = app_state
+        .database_address
+        .send(GetMetricSubmission(metric.id, from_version))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric submission"))?;
+    let to_submission = app_state
+        .database_address
+        .send(GetMetricSubmission(metric.id, to_version))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric submission"))?;
+
+    Ok(Json(MetricSubmissionDiff {
+        metric_id: metric.id,
+        from_version,
+        to_version,
+        lines: diff_lines(
+            from_submission
+                .text_submission
+                .as_deref()
+                .unwrap_or_default(),
+            to_submission.text_submission.as_deref().unwrap_or_default(),
+        ),
+        artifact_changed: from_submission.artifact_id != to_submission.artifact_id,
+    }))
+}
+
+#[get("/submission/{version}/download")]
+pub async fn download_admin_metric_submission_artifact(
+    path_variables: Path<(Uuid, Uuid, Uuid, u32)>,
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+) -> Result<HttpResponse, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, _metric_uuid, version) = path_variables.into_inner();
+    let metric_submission = app_state
+        .database_address
+        .send(GetMetricSubmission(metric_info.into_inner().id, version))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric submission"))?;
+    let artifact_id = metric_submission
+        .artifact_id
+        .ok_or(RangerError::FileNotFound)?;
+    let artifact = app_state
+        .database_address
+        .send(GetArtifact(artifact_id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get Metric Artifact"))?;
+
+    Ok(create_artifact_response(artifact))
 }
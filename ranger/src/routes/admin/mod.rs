@@ -1,6 +1,24 @@
+pub mod analytics;
+pub mod api_token;
+pub mod certificate;
+pub mod condition;
+pub mod deployment_secret;
 pub mod email;
+pub mod evaluation;
 pub mod event;
+pub mod exercise_role;
+pub mod flag;
+pub mod grading;
 pub mod groups;
+pub mod hint;
+pub mod leaderboard;
 pub mod metric;
 pub mod order;
+pub mod penalty;
+pub mod questionnaire;
+pub mod report;
+pub mod rubric;
 pub mod scenario;
+pub mod score;
+pub mod scoring_strategy;
+pub mod submission_window;
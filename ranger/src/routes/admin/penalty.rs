@@ -0,0 +1,96 @@
+use crate::{
+    constants::BIG_DECIMAL_ZERO,
+    errors::RangerError,
+    middleware::{authentication::UserInfo, deployment::DeploymentInfo},
+    models::{helpers::uuid::Uuid, NewPenalty, NewPenaltyResource, Penalty},
+    services::database::penalty::{CreatePenalty, DeletePenalty, GetPenalties},
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+use log::error;
+use sdl_parser::{entity::Flatten, parse_sdl};
+
+#[get("")]
+pub async fn get_admin_penalties(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<Penalty>>, RangerError> {
+    let penalties = app_state
+        .database_address
+        .send(GetPenalties(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get penalties"))?;
+
+    Ok(Json(penalties))
+}
+
+#[post("")]
+pub async fn add_admin_penalty(
+    app_state: Data<AppState>,
+    user_details: UserInfo,
+    deployment: DeploymentInfo,
+    new_penalty: Json<NewPenaltyResource>,
+) -> Result<Json<Penalty>, RangerError> {
+    let new_penalty = new_penalty.into_inner();
+    if new_penalty.value <= *BIG_DECIMAL_ZERO {
+        return Err(RangerError::InvalidParameter("value".to_string()));
+    }
+    let scenario = parse_sdl(&deployment.sdl_schema).map_err(|error| {
+        error!("Failed to parse sdl: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+    let is_scenario_entity = scenario
+        .entities
+        .map(|entities| entities.flatten())
+        .is_some_and(|entities| entities.contains_key(&new_penalty.entity_selector));
+    if !is_scenario_entity {
+        return Err(RangerError::EntityNotFound);
+    }
+
+    let penalty = app_state
+        .database_address
+        .send(CreatePenalty(NewPenalty::new(
+            new_penalty,
+            deployment.exercise_id,
+            deployment.id,
+            Some(user_details.id.clone()),
+        )))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create penalty"))?;
+
+    Ok(Json(penalty))
+}
+
+#[delete("/{penalty_uuid}")]
+pub async fn delete_admin_penalty(
+    path_variables: Path<(Uuid, Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, penalty_uuid) = path_variables.into_inner();
+    let penalties = app_state
+        .database_address
+        .send(GetPenalties(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get penalties"))?;
+    if !penalties.iter().any(|penalty| penalty.id == penalty_uuid) {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeletePenalty(penalty_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete penalty"))?;
+
+    Ok(Json(id))
+}
@@ -0,0 +1,123 @@
+use crate::{
+    errors::RangerError,
+    middleware::exercise::ExerciseInfo,
+    models::{helpers::uuid::Uuid, QuestionType, QuestionnaireRest},
+    services::database::questionnaire::{
+        CreateQuestionnaire, DeleteQuestionnaire, GetQuestionnaires,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+use bigdecimal::BigDecimal;
+
+#[get("")]
+pub async fn get_admin_questionnaires(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<QuestionnaireRest>>, RangerError> {
+    let questionnaires = app_state
+        .database_address
+        .send(GetQuestionnaires(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get questionnaires"))?;
+
+    Ok(Json(questionnaires))
+}
+
+#[post("")]
+pub async fn add_admin_questionnaire(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    questionnaire: Json<QuestionnaireRest>,
+) -> Result<Json<QuestionnaireRest>, RangerError> {
+    let questionnaire = questionnaire.into_inner();
+    if questionnaire.questions.is_empty() {
+        return Err(RangerError::InvalidParameter("questions".to_string()));
+    }
+    if questionnaire
+        .questions
+        .iter()
+        .fold(BigDecimal::from(0), |total, question| {
+            total + &question.points
+        })
+        <= BigDecimal::from(0)
+    {
+        return Err(RangerError::InvalidParameter("points".to_string()));
+    }
+    for question in &questionnaire.questions {
+        if question.prompt.trim().is_empty() {
+            return Err(RangerError::InvalidParameter("prompt".to_string()));
+        }
+        if question.points < BigDecimal::from(0) {
+            return Err(RangerError::InvalidParameter("points".to_string()));
+        }
+        let correct_choice_count = question
+            .choices
+            .iter()
+            .filter(|choice| choice.is_correct)
+            .count();
+        match question.question_type {
+            QuestionType::SingleChoice if correct_choice_count != 1 => {
+                return Err(RangerError::InvalidParameter("choices".to_string()));
+            }
+            QuestionType::MultipleChoice if correct_choice_count == 0 => {
+                return Err(RangerError::InvalidParameter("choices".to_string()));
+            }
+            QuestionType::ShortAnswer => match question.answer_pattern() {
+                None => {
+                    return Err(RangerError::MissingParameter("expectedAnswer".to_string()));
+                }
+                Some(Err(_)) => {
+                    return Err(RangerError::InvalidParameter("expectedAnswer".to_string()));
+                }
+                Some(Ok(_)) => {}
+            },
+            _ => {}
+        }
+    }
+
+    let questionnaire = app_state
+        .database_address
+        .send(CreateQuestionnaire(exercise.id, questionnaire))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create questionnaire"))?;
+
+    Ok(Json(questionnaire))
+}
+
+#[delete("/{questionnaire_uuid}")]
+pub async fn delete_admin_questionnaire(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, questionnaire_uuid) = path_variables.into_inner();
+    let questionnaires = app_state
+        .database_address
+        .send(GetQuestionnaires(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get questionnaires"))?;
+    if !questionnaires
+        .iter()
+        .any(|questionnaire| questionnaire.id == questionnaire_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteQuestionnaire(questionnaire_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete questionnaire"))?;
+
+    Ok(Json(id))
+}
@@ -0,0 +1,66 @@
+use crate::{
+    errors::RangerError,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::ReportFormat,
+    utilities::report::{create_after_action_report, render_pdf},
+    AppState,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{Data, Query},
+    HttpResponse,
+};
+use anyhow::Result;
+use log::error;
+use std::collections::HashMap;
+
+#[get("report")]
+pub async fn get_admin_deployment_report(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    deployment: DeploymentInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<HttpResponse, RangerError> {
+    let deployment = deployment.into_inner();
+    let format = params.get("format").map(String::as_str).unwrap_or("html");
+    let format = ReportFormat::from_name(format)
+        .ok_or_else(|| RangerError::InvalidParameter("format".to_string()))?;
+    let pdf_renderer = match format {
+        ReportFormat::Pdf => Some(
+            app_state
+                .configuration
+                .pdf_renderer
+                .as_ref()
+                .ok_or(RangerError::PdfRendererNotConfigured)?,
+        ),
+        _ => None,
+    };
+
+    let report =
+        create_after_action_report(&app_state.database_address, &exercise, &deployment).await?;
+    let content = match format {
+        ReportFormat::Markdown => report.to_markdown(),
+        ReportFormat::Html | ReportFormat::Pdf => report.to_html(),
+    }
+    .map_err(|error| {
+        error!("Failed to write the report: {error}");
+        RangerError::ReportGenerationFailed
+    })?;
+    let content = match pdf_renderer {
+        Some(pdf_renderer) => render_pdf(pdf_renderer, content).await?,
+        None => content.into_bytes(),
+    };
+
+    Ok(HttpResponse::Ok()
+        .content_type(format.content_type())
+        .append_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!(
+                "{}-report.{}",
+                deployment.name,
+                format.file_extension()
+            ))],
+        })
+        .body(content))
+}
@@ -0,0 +1,151 @@
+use crate::{
+    errors::RangerError,
+    middleware::{authentication::UserInfo, exercise::ExerciseInfo, metric::MetricInfo},
+    models::{helpers::uuid::Uuid, Metric, RubricFeedback, RubricGradingResource, RubricRest},
+    services::database::rubric::{
+        CreateRubric, DeleteRubric, GetRubricByMetricKey, GetRubricFeedback, GetRubrics,
+        GradeMetricWithRubric,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+use bigdecimal::BigDecimal;
+
+#[get("")]
+pub async fn get_admin_rubrics(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<RubricRest>>, RangerError> {
+    let rubrics = app_state
+        .database_address
+        .send(GetRubrics(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get rubrics"))?;
+
+    Ok(Json(rubrics))
+}
+
+#[post("")]
+pub async fn add_admin_rubric(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    rubric: Json<RubricRest>,
+) -> Result<Json<RubricRest>, RangerError> {
+    let rubric = rubric.into_inner();
+    if rubric.criteria.is_empty() {
+        return Err(RangerError::InvalidParameter("criteria".to_string()));
+    }
+    for criterion in &rubric.criteria {
+        if criterion.weight <= BigDecimal::from(0) {
+            return Err(RangerError::InvalidParameter("weight".to_string()));
+        }
+        if criterion.levels.is_empty() {
+            return Err(RangerError::InvalidParameter("levels".to_string()));
+        }
+        if criterion
+            .levels
+            .iter()
+            .any(|level| level.value < BigDecimal::from(0) || level.value > BigDecimal::from(1))
+        {
+            return Err(RangerError::InvalidParameter("value".to_string()));
+        }
+    }
+
+    let rubric = app_state
+        .database_address
+        .send(CreateRubric(exercise.id, rubric))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create rubric"))?;
+
+    Ok(Json(rubric))
+}
+
+#[delete("/{rubric_uuid}")]
+pub async fn delete_admin_rubric(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, rubric_uuid) = path_variables.into_inner();
+    let rubrics = app_state
+        .database_address
+        .send(GetRubrics(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get rubrics"))?;
+    if !rubrics.iter().any(|rubric| rubric.id == rubric_uuid) {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteRubric(rubric_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete rubric"))?;
+
+    Ok(Json(id))
+}
+
+#[get("/rubric")]
+pub async fn get_admin_metric_rubric_feedback(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+) -> Result<Json<RubricFeedback>, RangerError> {
+    let rubric_feedback = app_state
+        .database_address
+        .send(GetRubricFeedback(metric_info.into_inner()))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get rubric feedback"))?;
+
+    Ok(Json(rubric_feedback))
+}
+
+#[post("/rubric")]
+pub async fn grade_admin_metric_with_rubric(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    user_details: UserInfo,
+    rubric_grading: Json<RubricGradingResource>,
+) -> Result<Json<Metric>, RangerError> {
+    let metric = metric_info.into_inner();
+    let rubric_grading = rubric_grading.into_inner();
+    let rubric = app_state
+        .database_address
+        .send(GetRubricByMetricKey(
+            metric.exercise_id,
+            metric.sdl_key.clone(),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get rubric"))?
+        .ok_or(RangerError::DatabaseRecordNotFound)?;
+    if rubric_grading.grades.len() != rubric.criteria.len() {
+        return Err(RangerError::InvalidParameter("grades".to_string()));
+    }
+    let score = rubric
+        .calculate_score(&rubric_grading.levels_by_criterion(), metric.max_score)
+        .ok_or_else(|| RangerError::InvalidParameter("grades".to_string()))?;
+
+    let metric = app_state
+        .database_address
+        .send(GradeMetricWithRubric(
+            metric.id,
+            rubric_grading,
+            user_details.id.clone(),
+            score,
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Grade metric with rubric"))?;
+
+    Ok(Json(metric))
+}
@@ -0,0 +1,159 @@
+use crate::{
+    constants::{SCORE_HISTORY_DEFAULT_RESOLUTION_SECONDS, SCORE_HISTORY_MAX_POINTS},
+    errors::RangerError,
+    middleware::deployment::DeploymentInfo,
+    models::{helpers::uuid::Uuid, ConditionMessage, ConditionScoringEvent, EntityScoreHistory},
+    services::database::{
+        condition::GetConditionMessagesByDeploymentId, metric::GetMetrics,
+        score::GetScoresByDeploymentId,
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        score::calculate_entity_score_history, serialize_to_csv,
+    },
+    AppState,
+};
+use actix_web::{
+    get,
+    http::header::{ContentDisposition, DispositionParam, DispositionType},
+    web::{Data, Json, Path, Query},
+    HttpResponse,
+};
+use anyhow::Result;
+use chrono::Utc;
+use log::error;
+use sdl_parser::parse_sdl;
+use serde::Serialize;
+use std::collections::HashMap;
+
+#[get("score/history")]
+pub async fn get_admin_deployment_score_history(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<Vec<EntityScoreHistory>>, RangerError> {
+    let deployment = deployment.into_inner();
+    let resolution_seconds = match params.get("resolution") {
+        Some(resolution) => resolution
+            .parse::<i64>()
+            .ok()
+            .filter(|resolution| *resolution > 0)
+            .ok_or_else(|| RangerError::InvalidParameter("resolution".to_string()))?,
+        None => SCORE_HISTORY_DEFAULT_RESOLUTION_SECONDS,
+    };
+    let end = deployment
+        .end
+        .min(Utc::now().naive_utc())
+        .max(deployment.start);
+    if (end - deployment.start).num_seconds() / resolution_seconds > SCORE_HISTORY_MAX_POINTS {
+        return Err(RangerError::InvalidParameter("resolution".to_string()));
+    }
+    let scenario = parse_sdl(&deployment.sdl_schema).map_err(|error| {
+        error!("Failed to parse sdl: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+
+    let scores = app_state
+        .database_address
+        .send(GetScoresByDeploymentId(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get scores"))?;
+
+    Ok(Json(calculate_entity_score_history(
+        &scenario,
+        &scores,
+        deployment.start,
+        end,
+        resolution_seconds,
+    )))
+}
+
+fn create_export_response<T: Serialize>(
+    records: &[T],
+    format: &str,
+    file_name: String,
+) -> Result<HttpResponse, RangerError> {
+    let (content_type, content) = match format {
+        "csv" => ("text/csv", serialize_to_csv(records)),
+        "json" => (
+            "application/json",
+            serde_json::to_string(records).map_err(anyhow::Error::from),
+        ),
+        _ => return Err(RangerError::InvalidParameter("format".to_string())),
+    };
+    let content = content.map_err(|error| {
+        error!("Failed to serialize {format} export: {error}");
+        RangerError::ExportFailed
+    })?;
+
+    Ok(HttpResponse::Ok()
+        .content_type(content_type)
+        .append_header(ContentDisposition {
+            disposition: DispositionType::Attachment,
+            parameters: vec![DispositionParam::Filename(format!("{file_name}.{format}"))],
+        })
+        .body(content))
+}
+
+#[get("score/export/{export_type}")]
+pub async fn export_admin_deployment_scores(
+    path_variables: Path<(Uuid, Uuid, String)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<HttpResponse, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, export_type) = path_variables.into_inner();
+    let deployment = deployment.into_inner();
+    let format = params.get("format").map(String::as_str).unwrap_or("json");
+    let file_name = format!("{}-{export_type}", deployment.name);
+
+    match export_type.as_str() {
+        "scores" => {
+            let scores = app_state
+                .database_address
+                .send(GetScoresByDeploymentId(deployment.id))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Get scores"))?;
+            create_export_response(&scores, format, file_name)
+        }
+        "metrics" => {
+            let metrics = app_state
+                .database_address
+                .send(GetMetrics(deployment.id))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Get metrics"))?;
+            create_export_response(&metrics, format, file_name)
+        }
+        "condition-events" => {
+            let condition_messages = app_state
+                .database_address
+                .send(GetConditionMessagesByDeploymentId(deployment.id))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Get condition messages"))?
+                .into_iter()
+                .map(|condition_message| (condition_message.id, condition_message))
+                .collect::<HashMap<Uuid, ConditionMessage>>();
+            let scores = app_state
+                .database_address
+                .send(GetScoresByDeploymentId(deployment.id))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Get scores"))?;
+            let condition_scoring_events = scores
+                .into_iter()
+                .filter(|score| score.metric_id.is_none())
+                .filter_map(|score| {
+                    condition_messages.get(&score.id).map(|condition_message| {
+                        ConditionScoringEvent::new(score, condition_message)
+                    })
+                })
+                .collect::<Vec<ConditionScoringEvent>>();
+            create_export_response(&condition_scoring_events, format, file_name)
+        }
+        _ => Err(RangerError::InvalidParameter("export_type".to_string())),
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    errors::RangerError,
+    middleware::exercise::ExerciseInfo,
+    models::{
+        helpers::uuid::Uuid, NewScoringStrategy, NewScoringStrategyResource, ScoringStrategy,
+        ScoringStrategyType,
+    },
+    services::database::scoring_strategy::{
+        CreateScoringStrategy, DeleteScoringStrategy, GetScoringStrategies,
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler, score::rebuild_exercise_scores,
+    },
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_scoring_strategies(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<ScoringStrategy>>, RangerError> {
+    let scoring_strategies = app_state
+        .database_address
+        .send(GetScoringStrategies(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get scoring strategies"))?;
+
+    Ok(Json(scoring_strategies))
+}
+
+#[post("")]
+pub async fn add_admin_scoring_strategy(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_scoring_strategy: Json<NewScoringStrategyResource>,
+) -> Result<Json<ScoringStrategy>, RangerError> {
+    let new_scoring_strategy = new_scoring_strategy.into_inner();
+    match new_scoring_strategy.strategy {
+        ScoringStrategyType::FirstSuccessBonus if new_scoring_strategy.bonus_score.is_none() => {
+            return Err(RangerError::InvalidParameter("bonusScore".to_string()));
+        }
+        ScoringStrategyType::Decay if new_scoring_strategy.decay_per_minute.is_none() => {
+            return Err(RangerError::InvalidParameter("decayPerMinute".to_string()));
+        }
+        _ => {}
+    }
+
+    let scoring_strategy = app_state
+        .database_address
+        .send(CreateScoringStrategy(NewScoringStrategy::new(
+            new_scoring_strategy,
+            exercise.id,
+        )))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create scoring strategy"))?;
+    rebuild_exercise_scores(&app_state.database_address, exercise.id)
+        .await
+        .map_err(create_database_error_handler("Rebuild scores"))?;
+
+    Ok(Json(scoring_strategy))
+}
+
+#[delete("/{scoring_strategy_uuid}")]
+pub async fn delete_admin_scoring_strategy(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, scoring_strategy_uuid) = path_variables.into_inner();
+    let scoring_strategies = app_state
+        .database_address
+        .send(GetScoringStrategies(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get scoring strategies"))?;
+    if !scoring_strategies
+        .iter()
+        .any(|scoring_strategy| scoring_strategy.id == scoring_strategy_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteScoringStrategy(scoring_strategy_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete scoring strategy"))?;
+    rebuild_exercise_scores(&app_state.database_address, exercise.id)
+        .await
+        .map_err(create_database_error_handler("Rebuild scores"))?;
+
+    Ok(Json(id))
+}
@@ -0,0 +1,98 @@
+use crate::{
+    errors::RangerError,
+    middleware::exercise::ExerciseInfo,
+    models::{
+        helpers::uuid::Uuid, NewSubmissionWindow, NewSubmissionWindowResource, SubmissionWindow,
+    },
+    services::database::submission_window::{
+        CreateSubmissionWindow, DeleteSubmissionWindow, GetSubmissionWindows,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    delete, get, post,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_admin_submission_windows(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Vec<SubmissionWindow>>, RangerError> {
+    let submission_windows = app_state
+        .database_address
+        .send(GetSubmissionWindows(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get submission windows"))?;
+
+    Ok(Json(submission_windows))
+}
+
+#[post("")]
+pub async fn add_admin_submission_window(
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    new_submission_window: Json<NewSubmissionWindowResource>,
+) -> Result<Json<SubmissionWindow>, RangerError> {
+    let new_submission_window = new_submission_window.into_inner();
+    if let (Some(opens_at), Some(closes_at)) = (
+        new_submission_window.opens_at,
+        new_submission_window.closes_at,
+    ) {
+        if opens_at >= closes_at {
+            return Err(RangerError::InvalidParameter("closesAt".to_string()));
+        }
+    }
+    if new_submission_window
+        .event_name
+        .as_ref()
+        .is_some_and(|event_name| event_name.trim().is_empty())
+    {
+        return Err(RangerError::InvalidParameter("eventName".to_string()));
+    }
+
+    let submission_window = app_state
+        .database_address
+        .send(CreateSubmissionWindow(NewSubmissionWindow::new(
+            new_submission_window,
+            exercise.id,
+        )))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create submission window"))?;
+
+    Ok(Json(submission_window))
+}
+
+#[delete("/{submission_window_uuid}")]
+pub async fn delete_admin_submission_window(
+    path_variables: Path<(Uuid, Uuid)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+) -> Result<Json<Uuid>, RangerError> {
+    let (_exercise_uuid, submission_window_uuid) = path_variables.into_inner();
+    let submission_windows = app_state
+        .database_address
+        .send(GetSubmissionWindows(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get submission windows"))?;
+    if !submission_windows
+        .iter()
+        .any(|submission_window| submission_window.id == submission_window_uuid)
+    {
+        return Err(RangerError::DatabaseRecordNotFound);
+    }
+
+    let id = app_state
+        .database_address
+        .send(DeleteSubmissionWindow(submission_window_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Delete submission window"))?;
+
+    Ok(Json(id))
+}
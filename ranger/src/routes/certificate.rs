@@ -0,0 +1,33 @@
+use crate::{
+    errors::RangerError,
+    models::{helpers::uuid::Uuid, CertificateVerification},
+    services::database::certificate::GetCertificateForVerification,
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("/{certificate_uuid}")]
+pub async fn verify_certificate(
+    path_variables: Path<Uuid>,
+    app_state: Data<AppState>,
+) -> Result<Json<CertificateVerification>, RangerError> {
+    let certificate_uuid = path_variables.into_inner();
+    let signing_key = app_state
+        .configuration
+        .certificate_signing_key
+        .as_ref()
+        .ok_or(RangerError::CertificateSigningKeyNotConfigured)?;
+    let certificate = app_state
+        .database_address
+        .send(GetCertificateForVerification(certificate_uuid))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get certificate"))?;
+
+    Ok(Json(CertificateVerification::new(certificate, signing_key)))
+}
@@ -13,21 +13,21 @@ use crate::{
         database::{
             account::GetAccount,
             banner::{CreateBanner, DeleteBanner, GetBanner},
-            condition::GetConditionMessagesByDeploymentId,
             deployment::{
                 CreateDeployment, DeleteDeployment, GetDeploymentElementByDeploymentId,
                 GetDeploymentElementByDeploymentIdByScenarioReference, GetDeployments,
             },
             exercise::{CreateExercise, DeleteExercise, GetExercises},
-            metric::GetMetrics,
             participant::{CreateParticipant, DeleteParticipant, GetParticipants},
+            score::GetScoresByDeploymentId,
         },
         deployment::{RemoveDeployment, StartDeployment},
         websocket::ExerciseWebsocket,
     },
     utilities::{
         create_database_error_handler, create_mailbox_error_handler,
-        scenario::filter_node_roles_by_entity, try_some, Validation,
+        scenario::filter_node_roles_by_entity, score::rebuild_deployment_scores, try_some,
+        Validation,
     },
     AppState,
 };
@@ -38,10 +38,8 @@ use actix_web::{
 };
 use actix_web_actors::ws;
 use anyhow::Result;
-use bigdecimal::BigDecimal;
 use futures::future::try_join_all;
 use log::{error, info};
-use ranger_grpc::capabilities::DeployerType as GrpcDeployerType;
 use sdl_parser::{
     entity::Flatten,
     node::{NodeType, VM},
@@ -318,82 +316,28 @@ pub async fn delete_participant(
 pub async fn get_exercise_deployment_scores(
     path_variables: Path<(Uuid, Uuid)>,
     app_state: Data<AppState>,
-    deployment: DeploymentInfo,
 ) -> Result<Json<Vec<Score>>, RangerError> {
     let (_exercise_uuid, deployment_uuid) = path_variables.into_inner();
-    let scenario = parse_sdl(&deployment.sdl_schema).map_err(|error| {
-        error!("Failed to parse sdl: {error}");
-        RangerError::ScenarioParsingFailed
-    })?;
 
-    let mut condition_messages = app_state
+    let scores = app_state
         .database_address
-        .send(GetConditionMessagesByDeploymentId(deployment_uuid))
+        .send(GetScoresByDeploymentId(deployment_uuid))
         .await
         .map_err(create_mailbox_error_handler("Database"))?
-        .map_err(create_database_error_handler("Get condition_messages"))?;
-    condition_messages.retain(|condition| {
-        condition.created_at > deployment.start && condition.created_at < deployment.end
-    });
-
-    let scenario_metrics = match scenario.metrics {
-        Some(metrics) => metrics,
-        None => return Ok(Json(vec![])),
-    };
+        .map_err(create_database_error_handler("Get scores"))?;
 
-    let deployment_elements = app_state
-        .database_address
-        .send(GetDeploymentElementByDeploymentId(deployment_uuid, false))
-        .await
-        .map_err(create_mailbox_error_handler("Database"))?
-        .map_err(create_database_error_handler("Get deployment elements"))?;
-
-    let vm_scenario_refs_by_id = deployment_elements
-        .iter()
-        .filter(|element| {
-            matches!(element.deployer_type.0, GrpcDeployerType::VirtualMachine)
-                && element.handler_reference.is_some()
-        })
-        .map(|element| {
-            let vm_id = try_some(
-                element.handler_reference.to_owned(),
-                "VM element missing handler reference",
-            )?;
-            Ok((vm_id, element.scenario_reference.to_owned()))
-        })
-        .collect::<Result<HashMap<String, String>>>()
-        .map_err(create_database_error_handler("Get deployment elements"))?;
+    Ok(Json(scores))
+}
 
-    let manual_metrics = app_state
-        .database_address
-        .send(GetMetrics(deployment.id))
+#[post("score/rebuild")]
+pub async fn rebuild_exercise_deployment_scores(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<Score>>, RangerError> {
+    let deployment = deployment.into_inner();
+    let scores = rebuild_deployment_scores(&app_state.database_address, &deployment)
         .await
-        .map_err(create_mailbox_error_handler("Database"))?
-        .map_err(create_database_error_handler("Get Manual Metrics"))?;
-
-    let mut scores: Vec<Score> = manual_metrics.into_iter().map(Into::into).collect();
-
-    for condition_message in condition_messages.iter() {
-        if let Some((metric_key, metric)) = scenario_metrics.iter().find(|(_, metric)| {
-            metric
-                .condition
-                .eq(&Some(condition_message.clone().condition_name))
-        }) {
-            if let Some(vm_name) =
-                vm_scenario_refs_by_id.get(&condition_message.virtual_machine_id.to_string())
-            {
-                scores.push(Score::new(
-                    condition_message.exercise_id,
-                    condition_message.deployment_id,
-                    metric.name.clone(),
-                    metric_key.to_owned(),
-                    vm_name.to_owned(),
-                    condition_message.clone().value * BigDecimal::from(metric.max_score),
-                    condition_message.created_at,
-                ))
-            }
-        }
-    }
+        .map_err(create_database_error_handler("Rebuild scores"))?;
 
     Ok(Json(scores))
 }
@@ -1,5 +1,6 @@
 pub mod admin;
 pub mod basic;
+pub mod certificate;
 pub mod client;
 pub mod deployers;
 pub mod deputy_query;
@@ -2,7 +2,7 @@ use crate::{
     errors::RangerError,
     middleware::{
         authentication::UserInfo, deployment::DeploymentInfo, exercise::ExerciseInfo,
-        keycloak::KeycloakInfo,
+        identity::IdentityProviderInfo,
     },
     models::{helpers::uuid::Uuid, DeploymentElement, ParticipantDeployment},
     services::database::deployment::{GetDeploymentElementByDeploymentId, GetDeployments},
@@ -28,7 +28,7 @@ use std::collections::HashMap;
 pub async fn get_participant_deployments(
     app_state: Data<AppState>,
     user_info: UserInfo,
-    keycloak_info: KeycloakInfo,
+    identity_provider: IdentityProviderInfo,
     exercise: ExerciseInfo,
 ) -> Result<Json<Vec<ParticipantDeployment>>, RangerError> {
     let deployments = app_state
@@ -41,10 +41,9 @@ pub async fn get_participant_deployments(
     let deployment_with_members = join_all(deployments.into_iter().map(|deployment| async {
         let is_connected = deployment
             .is_connected(
-                user_info.id.clone(),
+                &user_info,
                 &app_state.database_address,
-                KeycloakInfo(keycloak_info.clone()),
-                app_state.configuration.keycloak.realm.clone(),
+                identity_provider.as_ref(),
             )
             .await
             .unwrap_or(false);
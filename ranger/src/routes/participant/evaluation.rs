@@ -0,0 +1,27 @@
+use crate::{
+    errors::RangerError,
+    middleware::deployment::DeploymentInfo,
+    models::{helpers::uuid::Uuid, DeploymentEvaluation},
+    utilities::{create_database_error_handler, evaluation::get_deployment_evaluation},
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+
+#[get("")]
+pub async fn get_participant_deployment_evaluation(
+    path_variables: Path<(Uuid, Uuid, String)>,
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<DeploymentEvaluation>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    let deployment = deployment.into_inner();
+    let evaluation = get_deployment_evaluation(&app_state.database_address, &deployment)
+        .await
+        .map_err(create_database_error_handler("Get deployment evaluation"))?;
+
+    Ok(Json(evaluation.filter_by_entity(&entity_selector)))
+}
@@ -0,0 +1,77 @@
+use crate::{
+    errors::RangerError,
+    middleware::{authentication::UserInfo, deployment::DeploymentInfo},
+    models::{helpers::uuid::Uuid, ParticipantHint},
+    services::database::hint::{GetParticipantHints, TakeHint},
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+use actix_web::{
+    get, post,
+    web::{Data, Json, Path},
+};
+
+#[get("")]
+pub async fn get_participant_hints(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    path_variables: Path<(Uuid, Uuid, String)>,
+) -> Result<Json<Vec<ParticipantHint>>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    let participant_hints = app_state
+        .database_address
+        .send(GetParticipantHints(
+            deployment.into_inner(),
+            entity_selector,
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get hints"))?;
+
+    Ok(Json(participant_hints))
+}
+
+#[post("/{hint_uuid}")]
+pub async fn take_participant_hint(
+    app_state: Data<AppState>,
+    user_details: UserInfo,
+    deployment: DeploymentInfo,
+    path_variables: Path<(Uuid, Uuid, String, Uuid)>,
+) -> Result<Json<ParticipantHint>, RangerError> {
+    let deployment = deployment.into_inner();
+    let (_exercise_uuid, _deployment_uuid, entity_selector, hint_uuid) =
+        path_variables.into_inner();
+    let participant_hints = app_state
+        .database_address
+        .send(GetParticipantHints(
+            deployment.clone(),
+            entity_selector.clone(),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get hints"))?;
+    let participant_hint = participant_hints
+        .into_iter()
+        .find(|participant_hint| participant_hint.id == hint_uuid)
+        .ok_or(RangerError::DatabaseRecordNotFound)?;
+    if participant_hint.taken_at.is_some() {
+        return Ok(Json(participant_hint));
+    }
+    if !participant_hint.is_unlocked {
+        return Err(RangerError::HintLocked);
+    }
+
+    let participant_hint = app_state
+        .database_address
+        .send(TakeHint(
+            deployment,
+            hint_uuid,
+            entity_selector,
+            Some(user_details.id.clone()),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Take hint"))?;
+
+    Ok(Json(participant_hint))
+}
@@ -0,0 +1,90 @@
+use crate::{
+    errors::RangerError,
+    middleware::{authentication::UserInfo, deployment::DeploymentInfo, exercise::ExerciseInfo},
+    models::{helpers::uuid::Uuid, Leaderboard},
+    services::database::{deployment::GetDeployments, participant::GetParticipants},
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        score::get_deployment_entity_scores,
+    },
+    AppState,
+};
+use actix_web::{
+    get,
+    web::{Data, Json, Path},
+};
+use anyhow::Result;
+use futures::future::try_join_all;
+
+#[get("")]
+pub async fn get_participant_deployment_leaderboard(
+    path_variables: Path<(Uuid, Uuid, String)>,
+    app_state: Data<AppState>,
+    exercise: ExerciseInfo,
+    deployment: DeploymentInfo,
+) -> Result<Json<Leaderboard>, RangerError> {
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    let deployment = deployment.into_inner();
+    let entity_scores = get_deployment_entity_scores(&app_state.database_address, &deployment)
+        .await
+        .map_err(create_database_error_handler("Get entity scores"))?;
+
+    let leaderboard = Leaderboard::new(vec![(deployment.id, deployment.name, entity_scores)]);
+    match exercise.hide_leaderboard_names {
+        true => Ok(Json(leaderboard.hide_other_entities(&entity_selector))),
+        false => Ok(Json(leaderboard)),
+    }
+}
+
+#[get("leaderboard")]
+pub async fn get_participant_exercise_leaderboard(
+    app_state: Data<AppState>,
+    user_info: UserInfo,
+    exercise: ExerciseInfo,
+) -> Result<Json<Leaderboard>, RangerError> {
+    let deployments = app_state
+        .database_address
+        .send(GetDeployments(exercise.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployments"))?;
+    let deployment_ids = deployments
+        .iter()
+        .map(|deployment| deployment.id)
+        .collect::<Vec<Uuid>>();
+
+    let deployment_entity_scores = try_join_all(deployments.into_iter().map(|deployment| {
+        let database_address = app_state.database_address.clone();
+        async move {
+            let entity_scores =
+                get_deployment_entity_scores(&database_address, &deployment).await?;
+            Ok((deployment.id, deployment.name, entity_scores))
+        }
+    }))
+    .await
+    .map_err(create_database_error_handler("Get entity scores"))?;
+    let leaderboard = Leaderboard::new(deployment_entity_scores);
+
+    match exercise.hide_leaderboard_names {
+        true => {
+            let own_entities = try_join_all(deployment_ids.into_iter().map(|deployment_id| {
+                let database_address = app_state.database_address.clone();
+                async move {
+                    database_address
+                        .send(GetParticipants(deployment_id))
+                        .await?
+                }
+            }))
+            .await
+            .map_err(create_database_error_handler("Get participants"))?
+            .into_iter()
+            .flatten()
+            .filter(|participant| participant.user_id == user_info.id)
+            .map(|participant| (participant.deployment_id, participant.selector))
+            .collect::<Vec<(Uuid, String)>>();
+
+            Ok(Json(leaderboard.hide_unowned_entities(&own_entities)))
+        }
+        false => Ok(Json(leaderboard)),
+    }
+}
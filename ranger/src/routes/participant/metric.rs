@@ -1,19 +1,36 @@
 use crate::{
+    constants::FLAG_ATTEMPT_WINDOW_SECONDS,
     errors::RangerError,
-    middleware::{deployment::DeploymentInfo, metric::MetricInfo},
+    middleware::{
+        authentication::UserInfo, deployment::DeploymentInfo, exercise::ExerciseInfo,
+        metric::MetricInfo,
+    },
     models::{
         helpers::uuid::Uuid,
         metric::{NewMetric, NewMetricResource},
-        Metric, UpdateMetric,
+        FlagSubmissionResource, FlagSubmissionResult, Metric, MetricSubmission,
+        ParticipantQuestionnaire, QuestionnaireSubmissionResource, QuestionnaireSubmissionResult,
+        RubricFeedback, SubmissionPeriod, UpdateMetric,
+    },
+    services::database::{
+        flag::SubmitFlag,
+        metric::{CreateMetric, GetMetrics},
+        metric_submission::GetMetricSubmissions,
+        questionnaire::{GetQuestionnaires, SubmitQuestionnaire},
+        rubric::GetRubricFeedback,
+        submission_window::GetSubmissionPeriods,
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        submission::{get_submission_period, validate_submission},
     },
-    services::database::metric::{CreateMetric, GetMetrics},
-    utilities::{create_database_error_handler, create_mailbox_error_handler},
     AppState,
 };
 use actix_web::{
     get, post, put,
     web::{Data, Json, Path},
 };
+use chrono::{Duration, Utc};
 use log::error;
 use sdl_parser::Scenario;
 
@@ -31,6 +48,28 @@ pub async fn get_participant_metric(
     }
 }
 
+#[get("/rubric")]
+pub async fn get_participant_metric_rubric_feedback(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    path_variables: Path<(Uuid, Uuid, String)>,
+) -> Result<Json<RubricFeedback>, RangerError> {
+    let metric = metric_info.into_inner();
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    if !metric.entity_selector.eq(&entity_selector) {
+        return Err(RangerError::NotAuthorized);
+    }
+
+    let rubric_feedback = app_state
+        .database_address
+        .send(GetRubricFeedback(metric))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get rubric feedback"))?;
+
+    Ok(Json(rubric_feedback))
+}
+
 #[get("")]
 pub async fn get_participant_metrics(
     app_state: Data<AppState>,
@@ -52,10 +91,59 @@ pub async fn get_participant_metrics(
     Ok(Json(users_metrics))
 }
 
+#[get("/submission")]
+pub async fn get_participant_metric_submissions(
+    app_state: Data<AppState>,
+    metric_info: MetricInfo,
+    path_variables: Path<(Uuid, Uuid, String)>,
+) -> Result<Json<Vec<MetricSubmission>>, RangerError> {
+    let metric = metric_info.into_inner();
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    if !metric.entity_selector.eq(&entity_selector) {
+        return Err(RangerError::NotAuthorized);
+    }
+
+    let metric_submissions = app_state
+        .database_address
+        .send(GetMetricSubmissions(metric.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric submissions"))?;
+
+    Ok(Json(metric_submissions))
+}
+
+#[get("/submission-window")]
+pub async fn get_participant_submission_periods(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<SubmissionPeriod>>, RangerError> {
+    let deployment = deployment.into_inner();
+    let scenario = Scenario::from_yaml(&deployment.sdl_schema).map_err(|error| {
+        error!("Deployment error: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+    let metric_keys = scenario
+        .metrics
+        .map(|metrics| metrics.keys().cloned().collect())
+        .unwrap_or_default();
+
+    let submission_periods = app_state
+        .database_address
+        .send(GetSubmissionPeriods(deployment, metric_keys))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get submission periods"))?;
+
+    Ok(Json(submission_periods))
+}
+
 #[put("")]
 pub async fn update_participant_metric(
     app_state: Data<AppState>,
     metric_info: MetricInfo,
+    deployment: DeploymentInfo,
+    exercise: ExerciseInfo,
     path_variables: Path<(Uuid, Uuid, String)>,
     update_metric: Json<UpdateMetric>,
 ) -> Result<Json<Metric>, RangerError> {
@@ -66,6 +154,13 @@ pub async fn update_participant_metric(
     if metric.score.is_some() {
         return Err(RangerError::MetricAlreadyScored);
     };
+    let submission_period = get_submission_period(
+        &app_state.database_address,
+        deployment.into_inner(),
+        metric.sdl_key.clone(),
+    )
+    .await?;
+    validate_submission(Some(&metric), &submission_period, &exercise)?;
     if metric.entity_selector.eq(&entity_selector) || update_metric.score.is_some() {
         let metric = app_state
             .database_address
@@ -86,6 +181,7 @@ pub async fn update_participant_metric(
 pub async fn add_metric(
     app_state: Data<AppState>,
     deployment: DeploymentInfo,
+    exercise: ExerciseInfo,
     new_metric: Json<NewMetricResource>,
 ) -> Result<Json<Uuid>, RangerError> {
     let deployment = deployment.into_inner();
@@ -106,6 +202,13 @@ pub async fn add_metric(
                 return Err(RangerError::MetricNotFound);
             }
         };
+        let submission_period = get_submission_period(
+            &app_state.database_address,
+            deployment.clone(),
+            new_metric_resource.metric_key.clone(),
+        )
+        .await?;
+        validate_submission(None, &submission_period, &exercise)?;
 
         let new_metric = NewMetric::new(
             metric.name.clone(),
@@ -127,3 +230,178 @@ pub async fn add_metric(
         Err(RangerError::MetricNotFound)
     }
 }
+
+#[post("/flag")]
+pub async fn submit_participant_flag(
+    app_state: Data<AppState>,
+    user_details: UserInfo,
+    deployment: DeploymentInfo,
+    exercise: ExerciseInfo,
+    path_variables: Path<(Uuid, Uuid, String)>,
+    flag_submission: Json<FlagSubmissionResource>,
+) -> Result<Json<FlagSubmissionResult>, RangerError> {
+    let deployment = deployment.into_inner();
+    let flag_submission = flag_submission.into_inner();
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    let scenario = Scenario::from_yaml(&deployment.sdl_schema).map_err(|error| {
+        error!("Deployment error: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+    let sdl_metrics = scenario.metrics.unwrap_or_default();
+    let sdl_metric = sdl_metrics
+        .get(&flag_submission.metric_key)
+        .ok_or_else(|| {
+            error!(
+                "Metric '{}' not found in Scenario",
+                &flag_submission.metric_key
+            );
+            RangerError::MetricNotFound
+        })?;
+
+    let metrics = app_state
+        .database_address
+        .send(GetMetrics(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get Manual Metrics"))?;
+    if metrics.iter().any(|metric| {
+        metric.entity_selector.eq(&entity_selector)
+            && metric.sdl_key.eq(&flag_submission.metric_key)
+            && metric.score.is_some()
+    }) {
+        return Err(RangerError::MetricAlreadyScored);
+    }
+    let submission_period = get_submission_period(
+        &app_state.database_address,
+        deployment.clone(),
+        flag_submission.metric_key.clone(),
+    )
+    .await?;
+    validate_submission(None, &submission_period, &exercise)?;
+
+    let new_metric = NewMetric::new(
+        sdl_metric.name.clone(),
+        sdl_metric.description.clone(),
+        sdl_metric.max_score,
+        NewMetricResource {
+            exercise_id: deployment.exercise_id,
+            deployment_id: deployment.id,
+            entity_selector,
+            metric_key: flag_submission.metric_key.clone(),
+            role: flag_submission.role.clone(),
+            text_submission: None,
+        },
+    );
+    let flag_submission_result = app_state
+        .database_address
+        .send(SubmitFlag(
+            new_metric,
+            flag_submission,
+            Some(user_details.id.clone()),
+            Utc::now().naive_utc() - Duration::seconds(FLAG_ATTEMPT_WINDOW_SECONDS),
+        ))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(|error| match error.downcast_ref::<RangerError>() {
+            Some(RangerError::FlagAttemptLimitReached) => RangerError::FlagAttemptLimitReached,
+            _ => create_database_error_handler("Submit flag")(error),
+        })?;
+
+    Ok(Json(flag_submission_result))
+}
+
+#[get("/questionnaire")]
+pub async fn get_participant_questionnaires(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+) -> Result<Json<Vec<ParticipantQuestionnaire>>, RangerError> {
+    let questionnaires = app_state
+        .database_address
+        .send(GetQuestionnaires(deployment.exercise_id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get questionnaires"))?
+        .into_iter()
+        .map(ParticipantQuestionnaire::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(questionnaires))
+}
+
+#[post("/questionnaire")]
+pub async fn submit_participant_questionnaire(
+    app_state: Data<AppState>,
+    deployment: DeploymentInfo,
+    exercise: ExerciseInfo,
+    path_variables: Path<(Uuid, Uuid, String)>,
+    questionnaire_submission: Json<QuestionnaireSubmissionResource>,
+) -> Result<Json<QuestionnaireSubmissionResult>, RangerError> {
+    let deployment = deployment.into_inner();
+    let questionnaire_submission = questionnaire_submission.into_inner();
+    let (_exercise_uuid, _deployment_uuid, entity_selector) = path_variables.into_inner();
+    let scenario = Scenario::from_yaml(&deployment.sdl_schema).map_err(|error| {
+        error!("Deployment error: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+    let sdl_metrics = scenario.metrics.unwrap_or_default();
+    let sdl_metric = sdl_metrics
+        .get(&questionnaire_submission.metric_key)
+        .ok_or_else(|| {
+            error!(
+                "Metric '{}' not found in Scenario",
+                &questionnaire_submission.metric_key
+            );
+            RangerError::MetricNotFound
+        })?;
+
+    let metrics = app_state
+        .database_address
+        .send(GetMetrics(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get Manual Metrics"))?;
+    if metrics.iter().any(|metric| {
+        metric.entity_selector.eq(&entity_selector)
+            && metric.sdl_key.eq(&questionnaire_submission.metric_key)
+            && metric.score.is_some()
+    }) {
+        return Err(RangerError::MetricAlreadyScored);
+    }
+    let submission_period = get_submission_period(
+        &app_state.database_address,
+        deployment.clone(),
+        questionnaire_submission.metric_key.clone(),
+    )
+    .await?;
+    validate_submission(None, &submission_period, &exercise)?;
+
+    let text_submission =
+        serde_json::to_string_pretty(&questionnaire_submission.answers).map_err(|error| {
+            error!("Failed to serialize questionnaire answers: {error}");
+            RangerError::InvalidParameter("answers".to_string())
+        })?;
+    let new_metric = NewMetric::new(
+        sdl_metric.name.clone(),
+        sdl_metric.description.clone(),
+        sdl_metric.max_score,
+        NewMetricResource {
+            exercise_id: deployment.exercise_id,
+            deployment_id: deployment.id,
+            entity_selector,
+            metric_key: questionnaire_submission.metric_key.clone(),
+            role: questionnaire_submission.role.clone(),
+            text_submission: Some(text_submission),
+        },
+    );
+    let questionnaire_submission_result = app_state
+        .database_address
+        .send(SubmitQuestionnaire(new_metric, questionnaire_submission))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(|error| match error.downcast_ref::<RangerError>() {
+            Some(RangerError::MetricAlreadyScored) => RangerError::MetricAlreadyScored,
+            _ => create_database_error_handler("Submit questionnaire")(error),
+        })?;
+
+    Ok(Json(questionnaire_submission_result))
+}
@@ -1,6 +1,9 @@
 pub mod deployment;
+pub mod evaluation;
 pub mod event_info;
 pub mod events;
+pub mod hint;
+pub mod leaderboard;
 pub mod metric;
 pub mod participants;
 pub mod scenario;
@@ -8,7 +11,9 @@ pub mod score;
 
 use crate::{
     errors::RangerError,
-    middleware::{authentication::UserInfo, exercise::ExerciseInfo, keycloak::KeycloakInfo},
+    middleware::{
+        authentication::UserInfo, exercise::ExerciseInfo, identity::IdentityProviderInfo,
+    },
     models::{Exercise, ParticipantExercise},
     services::database::exercise::GetExercises,
     utilities::{create_database_error_handler, create_mailbox_error_handler},
@@ -24,7 +29,7 @@ use futures_util::future::join_all;
 pub async fn get_participant_exercises(
     app_state: Data<AppState>,
     user_info: UserInfo,
-    keycloak_info: KeycloakInfo,
+    identity_provider: IdentityProviderInfo,
 ) -> Result<Json<Vec<ParticipantExercise>>, RangerError> {
     let exercises = app_state
         .database_address
@@ -36,11 +41,7 @@ pub async fn get_participant_exercises(
     let exercise_with_members: Vec<(Exercise, bool)> =
         join_all(exercises.into_iter().map(|exercise| async {
             let is_member = exercise
-                .is_member(
-                    user_info.id.clone(),
-                    KeycloakInfo(keycloak_info.clone()),
-                    app_state.configuration.keycloak.realm.clone(),
-                )
+                .is_member(&user_info, identity_provider.as_ref())
                 .await
                 .unwrap_or(false);
             (exercise, is_member)
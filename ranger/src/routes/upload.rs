@@ -1,10 +1,13 @@
 use crate::{
     constants::{ARTIFACT_EXTENSION_MIME_TYPE_WHITELIST, MAX_ARTIFACT_FILE_SIZE},
     errors::RangerError,
-    middleware::metric::MetricInfo,
+    middleware::{deployment::DeploymentInfo, exercise::ExerciseInfo, metric::MetricInfo},
     models::upload::NewArtifact,
     services::database::{metric::GetMetric, upload::UploadArtifact},
-    utilities::{create_database_error_handler, create_mailbox_error_handler, get_file_extension},
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler, get_file_extension,
+        submission::{get_submission_period, validate_submission},
+    },
     AppState,
 };
 use actix_multipart::Multipart;
@@ -16,6 +19,8 @@ use log::error;
 pub async fn upload_participant_artifact(
     app_state: Data<AppState>,
     metric_info: MetricInfo,
+    deployment: DeploymentInfo,
+    exercise: ExerciseInfo,
     mut payload: Multipart,
     req: HttpRequest,
 ) -> Result<String, RangerError> {
@@ -31,6 +36,13 @@ pub async fn upload_participant_artifact(
     if metric.score.is_some() {
         return Err(RangerError::MetricAlreadyScored);
     };
+    let submission_period = get_submission_period(
+        &app_state.database_address,
+        deployment.into_inner(),
+        metric.sdl_key.clone(),
+    )
+    .await?;
+    validate_submission(Some(&metric), &submission_period, &exercise)?;
     let content_length: usize = match req.headers().get(CONTENT_LENGTH) {
         Some(length) => match length.to_str() {
             Ok(length) => match length.parse::<usize>() {
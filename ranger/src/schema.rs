@@ -17,6 +17,48 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    api_token_audit_logs (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        api_token_id -> Binary,
+        method -> Tinytext,
+        path -> Text,
+        status_code -> Unsigned<Smallint>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    api_token_exercises (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        api_token_id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    api_tokens (id) {
+        #[max_length = 16]
+        id -> Binary,
+        name -> Tinytext,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        roles -> Tinytext,
+        created_by -> Text,
+        expires_at -> Nullable<Timestamp>,
+        last_used_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     artifacts (id) {
         #[max_length = 16]
@@ -42,6 +84,45 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    certificate_templates (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        name -> Tinytext,
+        content -> Mediumtext,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    certificates (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        certificate_template_id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        entity_selector -> Text,
+        user_id -> Nullable<Text>,
+        recipient_name -> Tinytext,
+        recipient_email -> Nullable<Tinytext>,
+        exercise_name -> Tinytext,
+        training_objectives -> Text,
+        content -> Mediumtext,
+        signature -> Tinytext,
+        issued_at -> Timestamp,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     condition_messages (id) {
         #[max_length = 16]
@@ -96,6 +177,23 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    deployment_secrets (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        #[max_length = 16]
+        secret_definition_id -> Binary,
+        name -> Tinytext,
+        entity_selector -> Nullable<Text>,
+        value -> Text,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     deployments (id) {
         #[max_length = 16]
@@ -219,6 +317,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    exercise_role_assignments (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 255]
+        user_id -> Varchar,
+        role -> Tinytext,
+        assigned_by -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     exercises (id) {
         #[max_length = 16]
@@ -227,6 +341,138 @@ diesel::table! {
         group_name -> Nullable<Tinytext>,
         deployment_group -> Tinytext,
         sdl_schema -> Nullable<Longtext>,
+        hide_leaderboard_names -> Bool,
+        allow_late_resubmission -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    flag_attempts (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        metric_key -> Text,
+        entity_selector -> Text,
+        submitter_id -> Nullable<Text>,
+        answer -> Nullable<Text>,
+        is_correct -> Bool,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    flags (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Text,
+        entity_selector -> Nullable<Text>,
+        #[max_length = 16]
+        secret_definition_id -> Nullable<Binary>,
+        #[max_length = 32]
+        answer_salt -> Nullable<Char>,
+        #[max_length = 64]
+        answer_hash -> Nullable<Char>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    grading_assignments (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        #[max_length = 16]
+        metric_id -> Binary,
+        grader_id -> Text,
+        disagreement_threshold -> Nullable<Unsigned<Integer>>,
+        score -> Nullable<Unsigned<Integer>>,
+        comment -> Nullable<Text>,
+        graded_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    hint_releases (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        hint_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        entity_selector -> Text,
+        #[max_length = 16]
+        penalty_id -> Nullable<Binary>,
+        requested_by -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    hints (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Nullable<Text>,
+        training_objective_key -> Nullable<Text>,
+        title -> Tinytext,
+        content -> Text,
+        cost -> Nullable<Decimal>,
+        release_type -> Tinytext,
+        release_after_minutes -> Nullable<Unsigned<Integer>>,
+        event_name -> Nullable<Tinytext>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    metric_gradings (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        metric_id -> Binary,
+        grader_id -> Text,
+        previous_score -> Nullable<Unsigned<Integer>>,
+        score -> Unsigned<Integer>,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    metric_submissions (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        metric_id -> Binary,
+        version -> Unsigned<Integer>,
+        text_submission -> Nullable<Text>,
+        #[max_length = 16]
+        artifact_id -> Nullable<Binary>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Timestamp,
@@ -283,6 +529,24 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    penalties (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        entity_selector -> Text,
+        value -> Decimal,
+        reason -> Text,
+        issued_by -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     plot_point_structures (id) {
         #[max_length = 16]
@@ -321,6 +585,161 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    questionnaire_choices (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        questionnaire_question_id -> Binary,
+        text -> Text,
+        is_correct -> Bool,
+        position -> Unsigned<Integer>,
+    }
+}
+
+diesel::table! {
+    questionnaire_questions (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        questionnaire_id -> Binary,
+        prompt -> Text,
+        question_type -> Tinytext,
+        expected_answer -> Nullable<Text>,
+        answer_matching -> Tinytext,
+        case_sensitive -> Bool,
+        points -> Decimal,
+        position -> Unsigned<Integer>,
+    }
+}
+
+diesel::table! {
+    questionnaires (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Text,
+        name -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    rubric_criteria (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        rubric_id -> Binary,
+        name -> Text,
+        description -> Nullable<Text>,
+        weight -> Decimal,
+        position -> Unsigned<Integer>,
+    }
+}
+
+diesel::table! {
+    rubric_grades (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        metric_id -> Binary,
+        #[max_length = 16]
+        rubric_criterion_id -> Binary,
+        #[max_length = 16]
+        rubric_level_id -> Binary,
+        grader_id -> Text,
+        comment -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    rubric_levels (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        rubric_criterion_id -> Binary,
+        name -> Text,
+        description -> Nullable<Text>,
+        value -> Decimal,
+        position -> Unsigned<Integer>,
+    }
+}
+
+diesel::table! {
+    rubrics (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Text,
+        name -> Nullable<Text>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    scores (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        #[max_length = 16]
+        deployment_id -> Binary,
+        #[max_length = 16]
+        metric_id -> Nullable<Binary>,
+        metric_key -> Text,
+        metric_name -> Nullable<Text>,
+        vm_name -> Text,
+        role -> Nullable<Tinytext>,
+        value -> Decimal,
+        reason -> Nullable<Text>,
+        timestamp -> Timestamp,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    scoring_strategies (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Text,
+        strategy -> Tinytext,
+        bonus_score -> Nullable<Unsigned<Integer>>,
+        decay_per_minute -> Nullable<Decimal>,
+        minimum_ratio -> Nullable<Decimal>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    secret_definitions (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        name -> Tinytext,
+        per_entity -> Bool,
+        length -> Unsigned<Integer>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     skills (id) {
         #[max_length = 16]
@@ -365,6 +784,22 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    submission_windows (id) {
+        #[max_length = 16]
+        id -> Binary,
+        #[max_length = 16]
+        exercise_id -> Binary,
+        metric_key -> Text,
+        opens_at -> Nullable<Timestamp>,
+        closes_at -> Nullable<Timestamp>,
+        event_name -> Nullable<Tinytext>,
+        created_at -> Timestamp,
+        updated_at -> Timestamp,
+        deleted_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     threats (id) {
         #[max_length = 16]
@@ -386,38 +821,81 @@ diesel::table! {
 }
 
 diesel::joinable!(accounts -> exercises (exercise_id));
+diesel::joinable!(api_token_audit_logs -> api_tokens (api_token_id));
+diesel::joinable!(api_token_exercises -> api_tokens (api_token_id));
+diesel::joinable!(api_token_exercises -> exercises (exercise_id));
 diesel::joinable!(banners -> exercises (exercise_id));
+diesel::joinable!(certificate_templates -> exercises (exercise_id));
+diesel::joinable!(certificates -> certificate_templates (certificate_template_id));
+diesel::joinable!(certificates -> deployments (deployment_id));
+diesel::joinable!(certificates -> exercises (exercise_id));
 diesel::joinable!(condition_messages -> deployments (deployment_id));
 diesel::joinable!(custom_elements -> orders (order_id));
 diesel::joinable!(deployment_elements -> deployments (deployment_id));
 diesel::joinable!(deployment_elements -> events (event_id));
+diesel::joinable!(deployment_secrets -> deployments (deployment_id));
+diesel::joinable!(deployment_secrets -> secret_definitions (secret_definition_id));
 diesel::joinable!(deployments -> exercises (exercise_id));
 diesel::joinable!(email_statuses -> emails (email_id));
 diesel::joinable!(emails -> exercises (exercise_id));
 diesel::joinable!(environment_strength -> environments (environment_id));
 diesel::joinable!(environment_weakness -> environments (environment_id));
 diesel::joinable!(environments -> orders (order_id));
+diesel::joinable!(exercise_role_assignments -> exercises (exercise_id));
+diesel::joinable!(flag_attempts -> deployments (deployment_id));
+diesel::joinable!(flag_attempts -> exercises (exercise_id));
+diesel::joinable!(flags -> exercises (exercise_id));
+diesel::joinable!(flags -> secret_definitions (secret_definition_id));
+diesel::joinable!(grading_assignments -> deployments (deployment_id));
+diesel::joinable!(grading_assignments -> metrics (metric_id));
+diesel::joinable!(hint_releases -> deployments (deployment_id));
+diesel::joinable!(hint_releases -> hints (hint_id));
+diesel::joinable!(hint_releases -> penalties (penalty_id));
+diesel::joinable!(hints -> exercises (exercise_id));
+diesel::joinable!(metric_gradings -> metrics (metric_id));
+diesel::joinable!(metric_submissions -> artifacts (artifact_id));
+diesel::joinable!(metric_submissions -> metrics (metric_id));
 diesel::joinable!(metrics -> deployments (deployment_id));
 diesel::joinable!(participants -> deployments (deployment_id));
+diesel::joinable!(penalties -> deployments (deployment_id));
 diesel::joinable!(plot_point_structures -> plot_points (plot_point_id));
 diesel::joinable!(plot_point_structures -> structures (structure_id));
 diesel::joinable!(plot_points -> plots (plot_id));
 diesel::joinable!(plots -> orders (order_id));
+diesel::joinable!(questionnaire_choices -> questionnaire_questions (questionnaire_question_id));
+diesel::joinable!(questionnaire_questions -> questionnaires (questionnaire_id));
+diesel::joinable!(questionnaires -> exercises (exercise_id));
+diesel::joinable!(rubric_criteria -> rubrics (rubric_id));
+diesel::joinable!(rubric_grades -> metrics (metric_id));
+diesel::joinable!(rubric_grades -> rubric_criteria (rubric_criterion_id));
+diesel::joinable!(rubric_grades -> rubric_levels (rubric_level_id));
+diesel::joinable!(rubric_levels -> rubric_criteria (rubric_criterion_id));
+diesel::joinable!(rubrics -> exercises (exercise_id));
+diesel::joinable!(scores -> deployments (deployment_id));
+diesel::joinable!(scoring_strategies -> exercises (exercise_id));
+diesel::joinable!(secret_definitions -> exercises (exercise_id));
 diesel::joinable!(skills -> structures (structure_id));
 diesel::joinable!(structure_training_objectives -> structures (structure_id));
 diesel::joinable!(structure_training_objectives -> training_objectives (training_objective_id));
 diesel::joinable!(structure_weaknesses -> structures (structure_id));
 diesel::joinable!(structures -> orders (order_id));
+diesel::joinable!(submission_windows -> exercises (exercise_id));
 diesel::joinable!(threats -> training_objectives (training_objective_id));
 diesel::joinable!(training_objectives -> orders (order_id));
 
 diesel::allow_tables_to_appear_in_same_query!(
     accounts,
+    api_token_audit_logs,
+    api_token_exercises,
+    api_tokens,
     artifacts,
     banners,
+    certificate_templates,
+    certificates,
     condition_messages,
     custom_elements,
     deployment_elements,
+    deployment_secrets,
     deployments,
     email_statuses,
     email_templates,
@@ -427,17 +905,37 @@ diesel::allow_tables_to_appear_in_same_query!(
     environments,
     event_info_data,
     events,
+    exercise_role_assignments,
     exercises,
+    flag_attempts,
+    flags,
+    grading_assignments,
+    hint_releases,
+    hints,
+    metric_gradings,
+    metric_submissions,
     metrics,
     orders,
     participants,
+    penalties,
     plot_point_structures,
     plot_points,
     plots,
+    questionnaire_choices,
+    questionnaire_questions,
+    questionnaires,
+    rubric_criteria,
+    rubric_grades,
+    rubric_levels,
+    rubrics,
+    scores,
+    scoring_strategies,
+    secret_definitions,
     skills,
     structure_training_objectives,
     structure_weaknesses,
     structures,
+    submission_windows,
     threats,
     training_objectives,
 );
@@ -1,11 +1,17 @@
 use crate::{
-    constants::{BIG_DECIMAL_ONE, BIG_DECIMAL_ZERO},
+    constants::{
+        BIG_DECIMAL_ONE, BIG_DECIMAL_ZERO, CONDITION_STREAM_CLOSED_CHECK_INTERVAL,
+        CONDITION_STREAM_RECONNECT_RETRY_DURATION,
+    },
     models::{DeploymentElement, ElementStatus, NewConditionMessage},
     services::{
         database::{
-            condition::CreateConditionMessage, deployment::GetDeployment, event::GetEvent, Database,
+            condition::CreateConditionMessage,
+            deployment::{GetDeployment, GetDeploymentElementByDeploymentIdByHandlerReference},
+            event::GetEvent,
+            Database,
         },
-        deployer::DeployerDistribution,
+        deployer::{DeployerDistribution, ReconnectConditionStream, UnDeploy},
     },
     utilities::try_some,
 };
@@ -13,18 +19,24 @@ use crate::{
 use super::{DeploymentClient, DeploymentClientResponse, DeploymentInfo};
 use crate::models::helpers::uuid::Uuid;
 use actix::{
-    Actor, Addr, Context, Handler, Message, ResponseActFuture, ResponseFuture, WrapFuture,
+    Actor, Addr, AsyncContext, Context, Handler, Message, ResponseActFuture, ResponseFuture,
+    WrapFuture,
 };
 use anyhow::{anyhow, Ok, Result};
 use async_trait::async_trait;
 use bigdecimal::{BigDecimal, FromPrimitive};
 use log::{debug, warn};
 use ranger_grpc::{
+    capabilities::DeployerType as GrpcDeployerType,
     condition_service_client::ConditionServiceClient, Condition as GrpcCondition,
     ConditionStreamResponse, Identifier,
 };
 use sdl_parser::metric::Metric;
-use std::any::Any;
+use std::{
+    any::Any,
+    time::{Duration, Instant},
+};
+use tokio::time::{sleep, timeout};
 use tonic::{transport::Channel, Streaming};
 
 impl DeploymentInfo for GrpcCondition {
@@ -157,12 +169,115 @@ pub struct ConditionStream {
     pub database_address: Addr<Database>,
     pub condition_stream: Streaming<ConditionStreamResponse>,
     pub condition_metric: Option<(String, Metric)>,
+    pub deployers: Vec<String>,
+    pub silence_threshold: Duration,
+}
+
+impl ConditionStream {
+    async fn is_closed(&self) -> Result<bool> {
+        let deployment_element_result = self
+            .database_address
+            .send(GetDeploymentElementByDeploymentIdByHandlerReference(
+                self.condition_deployment_element.deployment_id,
+                self.condition_deployment_element
+                    .handler_reference
+                    .clone()
+                    .unwrap_or_default(),
+            ))
+            .await?;
+
+        match deployment_element_result {
+            Result::Ok(deployment_element) => {
+                Ok(deployment_element.status == ElementStatus::ConditionClosed)
+            }
+            Err(_) => Ok(true),
+        }
+    }
+
+    async fn close(&mut self, distributor_address: &Addr<DeployerDistribution>) -> Result<()> {
+        if let Some(handler_reference) = self.condition_deployment_element.handler_reference.clone()
+        {
+            if let Err(error) = distributor_address
+                .send(UnDeploy(
+                    GrpcDeployerType::Condition,
+                    handler_reference,
+                    self.deployers.clone(),
+                ))
+                .await?
+            {
+                warn!(
+                    "Failed to delete condition '{condition_name}' for '{node_name}': {error}",
+                    condition_name = self.condition_deployment_element.scenario_reference,
+                    node_name = self.node_deployment_element.scenario_reference
+                );
+            }
+        }
+
+        self.condition_deployment_element.error_message = None;
+        self.condition_deployment_element
+            .update(
+                &self.database_address,
+                self.exercise_id,
+                ElementStatus::ConditionClosed,
+                self.condition_deployment_element.handler_reference.clone(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn has_expired(
+        &mut self,
+        distributor_address: &Addr<DeployerDistribution>,
+    ) -> Result<bool> {
+        if self.is_closed().await? {
+            debug!(
+                "Condition '{condition_name}' for '{node_name}' has been closed, not reconnecting stream",
+                condition_name = self.condition_deployment_element.scenario_reference,
+                node_name = self.node_deployment_element.scenario_reference
+            );
+            return Ok(true);
+        }
+
+        if let Some(event_id) = self.condition_deployment_element.event_id {
+            let event = self.database_address.send(GetEvent(event_id)).await??;
+            if event.end < chrono::Utc::now().naive_utc() {
+                debug!(
+                    "Event '{}' window has ended, not reconnecting '{condition_name}' stream for '{node_name}'",
+                    event.name,
+                    condition_name = self.condition_deployment_element.scenario_reference,
+                    node_name = self.node_deployment_element.scenario_reference
+                );
+                return Ok(true);
+            }
+        }
+
+        let deployment = self
+            .database_address
+            .send(GetDeployment(self.node_deployment_element.deployment_id))
+            .await??;
+        if deployment.end < chrono::Utc::now().naive_utc() {
+            debug!(
+                "Deployment '{}' has ended, not reconnecting '{condition_name}' stream for '{node_name}'",
+                deployment.name,
+                condition_name = self.condition_deployment_element.scenario_reference,
+                node_name = self.node_deployment_element.scenario_reference
+            );
+            self.close(distributor_address).await?;
+
+            return Ok(true);
+        }
+
+        Ok(false)
+    }
 }
 
 impl Handler<ConditionStream> for DeployerDistribution {
     type Result = ResponseActFuture<Self, Result<()>>;
 
-    fn handle(&mut self, mut msg: ConditionStream, _ctx: &mut Self::Context) -> Self::Result {
+    fn handle(&mut self, mut msg: ConditionStream, ctx: &mut Self::Context) -> Self::Result {
+        let distributor_address = ctx.address();
+
         Box::pin(
             async move {
                 let virtual_machine_id = try_some(
@@ -176,16 +291,100 @@ impl Handler<ConditionStream> for DeployerDistribution {
                     node_name = msg.node_deployment_element.scenario_reference,
                 );
 
-                let condition_id: Uuid = msg.condition_deployment_element
+                let condition_handler_reference = msg.condition_deployment_element
                     .clone()
                     .handler_reference
-                    .ok_or_else(|| anyhow!("Condition id not found"))?
-                    .as_str()
-                    .try_into()?;
+                    .ok_or_else(|| anyhow!("Condition id not found"))?;
+                let condition_id: Uuid = condition_handler_reference.as_str().try_into()?;
+                let mut status_before_interruption: Option<ElementStatus> = None;
+                let mut closed_checked_at = Instant::now();
+
+                loop {
+                    let stream_result = match timeout(msg.silence_threshold, msg.condition_stream.message()).await {
+                        Result::Ok(Result::Ok(Some(stream_item))) => Result::Ok(stream_item),
+                        Result::Ok(Result::Ok(None)) => Err("stream was closed by the deployer".to_string()),
+                        Result::Ok(Err(status)) => Err(format!("stream returned an error: {}", status.message())),
+                        Err(_) => Err(format!(
+                            "no messages received in {} seconds",
+                            msg.silence_threshold.as_secs()
+                        )),
+                    };
+
+                    let stream_item = match stream_result {
+                        Result::Ok(stream_item) => stream_item,
+                        Err(interruption_reason) => {
+                            if msg.has_expired(&distributor_address).await? {
+                                break;
+                            }
 
-                while let Some(stream_item) = msg.condition_stream.message().await? {
+                            let warning_message = format!(
+                                "Condition '{condition_name}' stream for VM '{vm_name}' interrupted, {interruption_reason}, reconnecting",
+                                condition_name = msg.condition_deployment_element.scenario_reference,
+                                vm_name = msg.node_deployment_element.scenario_reference,
+                            );
+                            warn!("{}", warning_message);
+
+                            status_before_interruption.get_or_insert(msg.condition_deployment_element.status);
+                            msg.condition_deployment_element.error_message = Some(warning_message);
+                            msg.condition_deployment_element.update(
+                                &msg.database_address,
+                                msg.exercise_id,
+                                ElementStatus::ConditionWarning,
+                                msg.condition_deployment_element.handler_reference.clone(),
+                            ).await?;
+
+                            match distributor_address
+                                .send(ReconnectConditionStream(
+                                    Identifier {
+                                        value: condition_handler_reference.clone(),
+                                    },
+                                    msg.deployers.clone(),
+                                ))
+                                .await?
+                            {
+                                Result::Ok(condition_stream) => {
+                                    msg.condition_stream = condition_stream;
+                                }
+                                Err(error) => {
+                                    warn!(
+                                        "Failed to reconnect '{condition_name}' stream for '{node_name}': {error}",
+                                        condition_name = msg.condition_deployment_element.scenario_reference,
+                                        node_name = msg.node_deployment_element.scenario_reference,
+                                    );
+                                    sleep(CONDITION_STREAM_RECONNECT_RETRY_DURATION).await;
+                                }
+                            }
+
+                            continue;
+                        }
+                    };
+                    if closed_checked_at.elapsed() >= CONDITION_STREAM_CLOSED_CHECK_INTERVAL {
+                        closed_checked_at = Instant::now();
+                        if msg.is_closed().await? {
+                            debug!(
+                                "Condition '{condition_name}' for '{node_name}' has been closed, closing stream",
+                                condition_name = msg.condition_deployment_element.scenario_reference,
+                                node_name = msg.node_deployment_element.scenario_reference,
+                            );
+                            break;
+                        }
+                    }
                     msg.condition_deployment_element.error_message = None;
 
+                    if let Some(previous_status) = status_before_interruption.take() {
+                        debug!(
+                            "Condition '{condition_name}' stream for '{node_name}' recovered",
+                            condition_name = msg.condition_deployment_element.scenario_reference,
+                            node_name = msg.node_deployment_element.scenario_reference,
+                        );
+                        msg.condition_deployment_element.update(
+                            &msg.database_address,
+                            msg.exercise_id,
+                            previous_status,
+                            msg.condition_deployment_element.handler_reference.clone(),
+                        ).await?;
+                    }
+
                     let value = BigDecimal::from_f32(stream_item.command_return_value)
                         .ok_or_else(|| anyhow!("Error converting Condition Return value"))?;
 
@@ -244,12 +443,7 @@ impl Handler<ConditionStream> for DeployerDistribution {
                             node_name = msg.node_deployment_element.scenario_reference
                         );
 
-                        msg.condition_deployment_element.update(
-                            &msg.database_address,
-                            msg.exercise_id,
-                            ElementStatus::ConditionClosed,
-                            msg.condition_deployment_element.handler_reference.clone(),
-                        ).await?;
+                        msg.close(&distributor_address).await?;
 
                         break;
                     }
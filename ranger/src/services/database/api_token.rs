@@ -0,0 +1,230 @@
+use super::Database;
+use crate::{
+    models::{
+        helpers::uuid::Uuid, ApiToken, ApiTokenAuditLog, ApiTokenExercise, ApiTokenRest,
+        NewApiToken, NewApiTokenAuditLog, NewApiTokenExercise,
+    },
+    schema::{api_token_audit_logs, api_tokens},
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{
+    Connection, ExpressionMethods, MysqlConnection, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+
+fn load_api_token_rest(
+    connection: &mut MysqlConnection,
+    api_token: ApiToken,
+) -> Result<ApiTokenRest> {
+    let api_token_exercises: Vec<ApiTokenExercise> =
+        ApiTokenExercise::by_api_token_id(api_token.id).load(connection)?;
+
+    Ok(ApiTokenRest::from((api_token, api_token_exercises)))
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<ApiTokenRest>")]
+pub struct CreateApiToken(pub NewApiToken, pub Vec<Uuid>);
+
+impl Handler<CreateApiToken> for Database {
+    type Result = ResponseActFuture<Self, Result<ApiTokenRest>>;
+
+    fn handle(&mut self, msg: CreateApiToken, _ctx: &mut Self::Context) -> Self::Result {
+        let CreateApiToken(new_api_token, exercise_ids) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let api_token = block(move || {
+                    let api_token =
+                        connection.transaction::<_, anyhow::Error, _>(|connection| {
+                            new_api_token.create_insert().execute(connection)?;
+                            NewApiTokenExercise::batch_insert(
+                                exercise_ids
+                                    .into_iter()
+                                    .map(|exercise_id| {
+                                        NewApiTokenExercise::new(new_api_token.id, exercise_id)
+                                    })
+                                    .collect(),
+                            )
+                            .execute(connection)?;
+                            let api_token: ApiToken =
+                                ApiToken::by_id(new_api_token.id).first(connection)?;
+
+                            load_api_token_rest(connection, api_token)
+                        })?;
+
+                    Ok(api_token)
+                })
+                .await??;
+
+                Ok(api_token)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ApiTokenRest>>")]
+pub struct GetApiTokens;
+
+impl Handler<GetApiTokens> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ApiTokenRest>>>;
+
+    fn handle(&mut self, _: GetApiTokens, _ctx: &mut Self::Context) -> Self::Result {
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let api_tokens = block(move || {
+                    let api_tokens: Vec<ApiToken> = ApiToken::all().load(&mut connection)?;
+                    let api_tokens = api_tokens
+                        .into_iter()
+                        .map(|api_token| load_api_token_rest(&mut connection, api_token))
+                        .collect::<Result<Vec<ApiTokenRest>>>()?;
+
+                    Ok(api_tokens)
+                })
+                .await??;
+
+                Ok(api_tokens)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Option<ApiTokenRest>>")]
+pub struct GetApiTokenByHash(pub String);
+
+impl Handler<GetApiTokenByHash> for Database {
+    type Result = ResponseActFuture<Self, Result<Option<ApiTokenRest>>>;
+
+    fn handle(&mut self, msg: GetApiTokenByHash, _ctx: &mut Self::Context) -> Self::Result {
+        let token_hash = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let api_token = block(move || {
+                    let api_token: Option<ApiToken> = ApiToken::by_token_hash(token_hash)
+                        .first(&mut connection)
+                        .optional()?;
+                    let api_token = api_token
+                        .map(|api_token| load_api_token_rest(&mut connection, api_token))
+                        .transpose()?;
+
+                    Ok(api_token)
+                })
+                .await??;
+
+                Ok(api_token)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct RevokeApiToken(pub Uuid);
+
+impl Handler<RevokeApiToken> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: RevokeApiToken, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let api_token: ApiToken = ApiToken::by_id(id).first(&mut connection)?;
+                    api_token.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<()>")]
+pub struct CreateApiTokenAuditLog(pub NewApiTokenAuditLog);
+
+impl Handler<CreateApiTokenAuditLog> for Database {
+    type Result = ResponseActFuture<Self, Result<()>>;
+
+    fn handle(&mut self, msg: CreateApiTokenAuditLog, _ctx: &mut Self::Context) -> Self::Result {
+        let new_api_token_audit_log = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                block(move || {
+                    connection.transaction::<_, anyhow::Error, _>(|connection| {
+                        new_api_token_audit_log
+                            .create_insert()
+                            .execute(connection)?;
+                        diesel::update(
+                            api_tokens::table
+                                .filter(api_tokens::id.eq(new_api_token_audit_log.api_token_id)),
+                        )
+                        .set(api_tokens::last_used_at.eq(diesel::dsl::now))
+                        .execute(connection)?;
+
+                        Ok(())
+                    })
+                })
+                .await??;
+
+                Ok(())
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ApiTokenAuditLog>>")]
+pub struct GetApiTokenAuditLogs(pub Uuid);
+
+impl Handler<GetApiTokenAuditLogs> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ApiTokenAuditLog>>>;
+
+    fn handle(&mut self, msg: GetApiTokenAuditLogs, _ctx: &mut Self::Context) -> Self::Result {
+        let api_token_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let audit_logs = block(move || {
+                    let audit_logs: Vec<ApiTokenAuditLog> =
+                        ApiTokenAuditLog::by_api_token_id(api_token_id)
+                            .order_by(api_token_audit_logs::created_at.desc())
+                            .load(&mut connection)?;
+
+                    Ok(audit_logs)
+                })
+                .await??;
+
+                Ok(audit_logs)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,232 @@
+use super::Database;
+use crate::models::{
+    helpers::uuid::Uuid, Certificate, CertificateTemplate, NewCertificate, NewCertificateTemplate,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{Connection, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<CertificateTemplate>")]
+pub struct CreateCertificateTemplate(pub NewCertificateTemplate);
+
+impl Handler<CreateCertificateTemplate> for Database {
+    type Result = ResponseActFuture<Self, Result<CertificateTemplate>>;
+
+    fn handle(&mut self, msg: CreateCertificateTemplate, _ctx: &mut Self::Context) -> Self::Result {
+        let new_certificate_template = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let certificate_template = block(move || {
+                    new_certificate_template
+                        .create_insert()
+                        .execute(&mut connection)?;
+                    let certificate_template =
+                        CertificateTemplate::by_id(new_certificate_template.id)
+                            .first(&mut connection)?;
+
+                    Ok(certificate_template)
+                })
+                .await??;
+
+                Ok(certificate_template)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<CertificateTemplate>>")]
+pub struct GetCertificateTemplates(pub Uuid);
+
+impl Handler<GetCertificateTemplates> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<CertificateTemplate>>>;
+
+    fn handle(&mut self, msg: GetCertificateTemplates, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let certificate_templates = block(move || {
+                    let certificate_templates =
+                        CertificateTemplate::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(certificate_templates)
+                })
+                .await??;
+
+                Ok(certificate_templates)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteCertificateTemplate(pub Uuid);
+
+impl Handler<DeleteCertificateTemplate> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteCertificateTemplate, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let certificate_template: CertificateTemplate =
+                        CertificateTemplate::by_id(id).first(&mut connection)?;
+                    certificate_template
+                        .soft_delete()
+                        .execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Certificate>>")]
+pub struct CreateCertificates(pub Vec<NewCertificate>);
+
+impl Handler<CreateCertificates> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Certificate>>>;
+
+    fn handle(&mut self, msg: CreateCertificates, _ctx: &mut Self::Context) -> Self::Result {
+        let new_certificates = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let certificates = block(move || {
+                    let certificates =
+                        connection.transaction::<_, anyhow::Error, _>(|connection| {
+                            new_certificates
+                                .iter()
+                                .map(|new_certificate| {
+                                    new_certificate.create_insert().execute(connection)?;
+                                    let certificate =
+                                        Certificate::by_id(new_certificate.id).first(connection)?;
+
+                                    Ok(certificate)
+                                })
+                                .collect::<Result<Vec<Certificate>>>()
+                        })?;
+
+                    Ok(certificates)
+                })
+                .await??;
+
+                Ok(certificates)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Certificate>>")]
+pub struct GetCertificates(pub Uuid);
+
+impl Handler<GetCertificates> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Certificate>>>;
+
+    fn handle(&mut self, msg: GetCertificates, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let certificates = block(move || {
+                    let certificates =
+                        Certificate::by_deployment_id(deployment_id).load(&mut connection)?;
+
+                    Ok(certificates)
+                })
+                .await??;
+
+                Ok(certificates)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Certificate>")]
+pub struct GetCertificateForVerification(pub Uuid);
+
+impl Handler<GetCertificateForVerification> for Database {
+    type Result = ResponseActFuture<Self, Result<Certificate>>;
+
+    fn handle(
+        &mut self,
+        msg: GetCertificateForVerification,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let certificate = block(move || {
+                    let certificate = Certificate::by_id_with_deleted(id).first(&mut connection)?;
+
+                    Ok(certificate)
+                })
+                .await??;
+
+                Ok(certificate)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct RevokeCertificate(pub Uuid);
+
+impl Handler<RevokeCertificate> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: RevokeCertificate, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let certificate: Certificate = Certificate::by_id(id).first(&mut connection)?;
+                    certificate.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -1,12 +1,71 @@
 use super::Database;
+use crate::constants::{BIG_DECIMAL_ONE, NAIVEDATETIME_DEFAULT_VALUE};
 use crate::models::helpers::uuid::Uuid;
-use crate::models::{ConditionMessage, NewConditionMessage, Score};
-use crate::services::websocket::SocketScoring;
+use crate::models::{
+    ConditionFirstSuccess, ConditionMessage, ConditionScoringHistory, Deployment, EvaluationUpdate,
+    NewConditionMessage, NewScore, Score, ScoringStrategy,
+};
+use crate::schema::{condition_messages, scores};
+use crate::services::websocket::{SocketEvaluation, SocketScoring};
 use actix::{Handler, Message, ResponseActFuture, WrapFuture};
 use actix_web::web::block;
 use anyhow::{anyhow, Ok, Result};
-use diesel::RunQueryDsl;
+use chrono::NaiveDateTime;
+use diesel::{
+    dsl::min, mysql::MysqlConnection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl,
+};
 use sdl_parser::metric::Metric;
+use std::collections::HashMap;
+
+fn get_condition_scoring_history(
+    connection: &mut MysqlConnection,
+    deployment: &Deployment,
+    condition_message: &ConditionMessage,
+    scoring_strategy: &ScoringStrategy,
+    previous_score: Option<&Score>,
+) -> Result<ConditionScoringHistory> {
+    if !scoring_strategy.requires_history() {
+        return Ok(ConditionScoringHistory::default());
+    }
+
+    let previous_message: Option<ConditionMessage> =
+        ConditionMessage::by_deployment_id(deployment.id)
+            .filter(condition_messages::condition_id.eq(condition_message.condition_id))
+            .filter(condition_messages::virtual_machine_id.eq(condition_message.virtual_machine_id))
+            .filter(condition_messages::id.ne(condition_message.id))
+            .filter(condition_messages::created_at.gt(deployment.start))
+            .filter(condition_messages::created_at.le(condition_message.created_at))
+            .order_by(condition_messages::created_at.desc())
+            .first(connection)
+            .optional()?;
+    let first_success: Option<ConditionMessage> = ConditionMessage::by_deployment_id(deployment.id)
+        .filter(condition_messages::condition_id.eq(condition_message.condition_id))
+        .filter(condition_messages::virtual_machine_id.eq(condition_message.virtual_machine_id))
+        .filter(condition_messages::value.ge(BIG_DECIMAL_ONE.clone()))
+        .filter(condition_messages::created_at.gt(deployment.start))
+        .filter(condition_messages::created_at.lt(deployment.end))
+        .order_by(condition_messages::created_at.asc())
+        .first(connection)
+        .optional()?;
+    let first_success_in_deployment: Option<ConditionMessage> =
+        ConditionMessage::by_deployment_id(deployment.id)
+            .filter(condition_messages::condition_name.eq(&condition_message.condition_name))
+            .filter(condition_messages::value.ge(BIG_DECIMAL_ONE.clone()))
+            .filter(condition_messages::created_at.gt(deployment.start))
+            .filter(condition_messages::created_at.lt(deployment.end))
+            .order_by(condition_messages::created_at.asc())
+            .first(connection)
+            .optional()?;
+
+    Ok(ConditionScoringHistory {
+        previous_message: previous_message.map(|message| (message.created_at, message.value)),
+        previous_score: previous_score.map(|score| score.value.clone()),
+        first_success_at: first_success.map(|message| message.created_at),
+        is_first_success_in_deployment: first_success_in_deployment.is_some_and(|message| {
+            message.virtual_machine_id == condition_message.virtual_machine_id
+        }),
+    })
+}
 
 #[derive(Message)]
 #[rtype(result = "Result<ConditionMessage>")]
@@ -42,16 +101,78 @@ impl Handler<CreateConditionMessage> for Database {
                         .first(&mut *connection)?;
 
                     if let Some(metric) = metric {
-                        let score: Score = Score::from_conditionmessage_and_metric(
+                        let deployment: Deployment =
+                            Deployment::by_id(condition_message.deployment_id)
+                                .first(&mut *connection)?;
+                        if condition_message.created_at <= deployment.start
+                            || condition_message.created_at >= deployment.end
+                        {
+                            return Ok(condition_message);
+                        }
+
+                        let metric_max_score = metric.1.max_score;
+                        let mut new_score = NewScore::from_conditionmessage_and_metric(
                             condition_message.clone(),
                             metric,
                             vm_name,
                         );
+                        let previous_score: Option<Score> = Score::by_deployment_id(deployment.id)
+                            .filter(scores::metric_key.eq(&new_score.metric_key))
+                            .filter(scores::vm_name.eq(&new_score.vm_name))
+                            .order_by(scores::timestamp.desc())
+                            .first(&mut *connection)
+                            .optional()?;
+                        let scoring_strategy: Option<ScoringStrategy> =
+                            ScoringStrategy::by_exercise_id_and_metric_key(
+                                deployment.exercise_id,
+                                new_score.metric_key.clone(),
+                            )
+                            .first(&mut *connection)
+                            .optional()?;
+                        if let Some(scoring_strategy) = scoring_strategy {
+                            let history = get_condition_scoring_history(
+                                &mut connection,
+                                &deployment,
+                                &condition_message,
+                                &scoring_strategy,
+                                previous_score.as_ref(),
+                            )?;
+                            new_score.value = scoring_strategy.calculate_score(
+                                metric_max_score,
+                                &condition_message.value,
+                                condition_message.created_at,
+                                deployment.start,
+                                &history,
+                            );
+                            new_score.reason = scoring_strategy.get_reason(
+                                &condition_message.condition_name,
+                                &condition_message.value,
+                            );
+                        }
+                        new_score.create_insert().execute(&mut *connection)?;
+                        let score: Score = Score::by_id(new_score.id).first(&mut *connection)?;
+                        let has_score_changed = previous_score
+                            .map_or(true, |previous_score| previous_score.value != score.value);
+
                         let scoring_msg = SocketScoring(
                             score.exercise_id,
                             (score.id, score.exercise_id, score).into(),
                         );
                         websocket_manager.do_send(scoring_msg);
+
+                        if has_score_changed {
+                            websocket_manager.do_send(SocketEvaluation(
+                                deployment.exercise_id,
+                                (
+                                    deployment.exercise_id,
+                                    deployment.id,
+                                    EvaluationUpdate {
+                                        deployment_id: deployment.id,
+                                    },
+                                )
+                                    .into(),
+                            ));
+                        }
                     }
 
                     Ok(condition_message)
@@ -159,6 +280,95 @@ impl Handler<GetConditionMessagesByExerciseId> for Database {
     }
 }
 
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ConditionFirstSuccess>>")]
+pub struct GetConditionFirstSuccessesByExerciseId(pub Uuid);
+
+impl Handler<GetConditionFirstSuccessesByExerciseId> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ConditionFirstSuccess>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetConditionFirstSuccessesByExerciseId,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let connection_result = self.get_connection();
+        let exercise_id = msg.0;
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let condition_first_successes = block(move || {
+                    let exercise_condition_messages = condition_messages::table
+                        .filter(condition_messages::deleted_at.eq(*NAIVEDATETIME_DEFAULT_VALUE))
+                        .filter(condition_messages::exercise_id.eq(exercise_id));
+                    let observed_conditions = exercise_condition_messages
+                        .clone()
+                        .select((
+                            condition_messages::condition_name,
+                            condition_messages::deployment_id,
+                            condition_messages::virtual_machine_id,
+                        ))
+                        .distinct()
+                        .load::<(String, Uuid, Uuid)>(&mut connection)?;
+                    let first_successes: HashMap<(String, Uuid, Uuid), Option<NaiveDateTime>> =
+                        exercise_condition_messages
+                            .filter(condition_messages::value.ge(BIG_DECIMAL_ONE.clone()))
+                            .group_by((
+                                condition_messages::condition_name,
+                                condition_messages::deployment_id,
+                                condition_messages::virtual_machine_id,
+                            ))
+                            .select((
+                                condition_messages::condition_name,
+                                condition_messages::deployment_id,
+                                condition_messages::virtual_machine_id,
+                                min(condition_messages::created_at),
+                            ))
+                            .load::<(String, Uuid, Uuid, Option<NaiveDateTime>)>(&mut connection)?
+                            .into_iter()
+                            .map(
+                                |(
+                                    condition_name,
+                                    deployment_id,
+                                    virtual_machine_id,
+                                    created_at,
+                                )| {
+                                    (
+                                        (condition_name, deployment_id, virtual_machine_id),
+                                        created_at,
+                                    )
+                                },
+                            )
+                            .collect();
+
+                    let condition_first_successes = observed_conditions
+                        .into_iter()
+                        .map(|(condition_name, deployment_id, virtual_machine_id)| {
+                            let first_success_at = first_successes
+                                .get(&(condition_name.clone(), deployment_id, virtual_machine_id))
+                                .copied()
+                                .flatten();
+                            ConditionFirstSuccess {
+                                condition_name,
+                                deployment_id,
+                                virtual_machine_id,
+                                first_success_at,
+                            }
+                        })
+                        .collect::<Vec<ConditionFirstSuccess>>();
+
+                    Ok(condition_first_successes)
+                })
+                .await??;
+
+                Ok(condition_first_successes)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
 #[derive(Message)]
 #[rtype(result = "Result<Uuid>")]
 pub struct DeleteConditionMessage(pub Uuid);
@@ -0,0 +1,188 @@
+use super::Database;
+use crate::models::{
+    helpers::uuid::Uuid, Deployment, DeploymentSecret, NewDeploymentSecret, NewSecretDefinition,
+    SecretDefinition,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{Connection, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<SecretDefinition>")]
+pub struct CreateSecretDefinition(pub NewSecretDefinition);
+
+impl Handler<CreateSecretDefinition> for Database {
+    type Result = ResponseActFuture<Self, Result<SecretDefinition>>;
+
+    fn handle(&mut self, msg: CreateSecretDefinition, _ctx: &mut Self::Context) -> Self::Result {
+        let new_secret_definition = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let secret_definition = block(move || {
+                    new_secret_definition
+                        .create_insert()
+                        .execute(&mut connection)?;
+                    let secret_definition =
+                        SecretDefinition::by_id(new_secret_definition.id).first(&mut connection)?;
+
+                    Ok(secret_definition)
+                })
+                .await??;
+
+                Ok(secret_definition)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<SecretDefinition>>")]
+pub struct GetSecretDefinitions(pub Uuid);
+
+impl Handler<GetSecretDefinitions> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<SecretDefinition>>>;
+
+    fn handle(&mut self, msg: GetSecretDefinitions, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let secret_definitions = block(move || {
+                    let secret_definitions =
+                        SecretDefinition::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(secret_definitions)
+                })
+                .await??;
+
+                Ok(secret_definitions)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteSecretDefinition(pub Uuid);
+
+impl Handler<DeleteSecretDefinition> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteSecretDefinition, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let secret_definition: SecretDefinition =
+                        SecretDefinition::by_id(id).first(&mut connection)?;
+                    secret_definition.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<DeploymentSecret>>")]
+pub struct GenerateDeploymentSecrets(pub Deployment, pub Vec<String>);
+
+impl Handler<GenerateDeploymentSecrets> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<DeploymentSecret>>>;
+
+    fn handle(&mut self, msg: GenerateDeploymentSecrets, _ctx: &mut Self::Context) -> Self::Result {
+        let GenerateDeploymentSecrets(deployment, entity_selectors) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let deployment_secrets = block(move || {
+                    connection.transaction::<_, anyhow::Error, _>(|connection| {
+                        let secret_definitions: Vec<SecretDefinition> =
+                            SecretDefinition::by_exercise_id(deployment.exercise_id)
+                                .load(connection)?;
+                        let existing_secrets: Vec<DeploymentSecret> =
+                            DeploymentSecret::by_deployment_id(deployment.id).load(connection)?;
+
+                        for secret_definition in secret_definitions {
+                            let secret_entity_selectors = match secret_definition.per_entity {
+                                true => entity_selectors.iter().cloned().map(Some).collect(),
+                                false => vec![None],
+                            };
+                            for entity_selector in secret_entity_selectors {
+                                let is_generated = existing_secrets.iter().any(|secret| {
+                                    secret.secret_definition_id == secret_definition.id
+                                        && secret.entity_selector == entity_selector
+                                });
+                                if !is_generated {
+                                    NewDeploymentSecret::new(
+                                        deployment.id,
+                                        &secret_definition,
+                                        entity_selector,
+                                    )
+                                    .create_insert()
+                                    .execute(connection)?;
+                                }
+                            }
+                        }
+
+                        let deployment_secrets =
+                            DeploymentSecret::by_deployment_id(deployment.id).load(connection)?;
+
+                        Ok(deployment_secrets)
+                    })
+                })
+                .await??;
+
+                Ok(deployment_secrets)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<DeploymentSecret>>")]
+pub struct GetDeploymentSecrets(pub Uuid);
+
+impl Handler<GetDeploymentSecrets> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<DeploymentSecret>>>;
+
+    fn handle(&mut self, msg: GetDeploymentSecrets, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let deployment_secrets = block(move || {
+                    let deployment_secrets =
+                        DeploymentSecret::by_deployment_id(deployment_id).load(&mut connection)?;
+
+                    Ok(deployment_secrets)
+                })
+                .await??;
+
+                Ok(deployment_secrets)
+            }
+            .into_actor(self),
+        )
+    }
+}
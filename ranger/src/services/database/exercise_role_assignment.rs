@@ -0,0 +1,162 @@
+use super::Database;
+use crate::models::{helpers::uuid::Uuid, ExerciseRoleAssignment, NewExerciseRoleAssignment};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{Connection, OptionalExtension, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<ExerciseRoleAssignment>")]
+pub struct CreateExerciseRoleAssignment(pub NewExerciseRoleAssignment);
+
+impl Handler<CreateExerciseRoleAssignment> for Database {
+    type Result = ResponseActFuture<Self, Result<ExerciseRoleAssignment>>;
+
+    fn handle(
+        &mut self,
+        msg: CreateExerciseRoleAssignment,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let new_exercise_role_assignment = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let exercise_role_assignment = block(move || {
+                    let exercise_role_assignment =
+                        connection.transaction::<_, anyhow::Error, _>(|connection| {
+                            let existing_assignments: Vec<ExerciseRoleAssignment> =
+                                ExerciseRoleAssignment::by_exercise_id_and_user_id(
+                                    new_exercise_role_assignment.exercise_id,
+                                    new_exercise_role_assignment.user_id.clone(),
+                                )
+                                .load(connection)?;
+                            for existing_assignment in existing_assignments {
+                                existing_assignment.soft_delete().execute(connection)?;
+                            }
+                            new_exercise_role_assignment
+                                .create_insert()
+                                .execute(connection)?;
+                            let exercise_role_assignment: ExerciseRoleAssignment =
+                                ExerciseRoleAssignment::by_id(new_exercise_role_assignment.id)
+                                    .first(connection)?;
+
+                            Ok(exercise_role_assignment)
+                        })?;
+
+                    Ok(exercise_role_assignment)
+                })
+                .await??;
+
+                Ok(exercise_role_assignment)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ExerciseRoleAssignment>>")]
+pub struct GetExerciseRoleAssignments(pub Uuid);
+
+impl Handler<GetExerciseRoleAssignments> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ExerciseRoleAssignment>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetExerciseRoleAssignments,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let exercise_role_assignments = block(move || {
+                    let exercise_role_assignments: Vec<ExerciseRoleAssignment> =
+                        ExerciseRoleAssignment::by_exercise_id(exercise_id)
+                            .load(&mut connection)?;
+
+                    Ok(exercise_role_assignments)
+                })
+                .await??;
+
+                Ok(exercise_role_assignments)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Option<ExerciseRoleAssignment>>")]
+pub struct GetExerciseRoleAssignmentByUser(pub Uuid, pub String);
+
+impl Handler<GetExerciseRoleAssignmentByUser> for Database {
+    type Result = ResponseActFuture<Self, Result<Option<ExerciseRoleAssignment>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetExerciseRoleAssignmentByUser,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let GetExerciseRoleAssignmentByUser(exercise_id, user_id) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let exercise_role_assignment = block(move || {
+                    let exercise_role_assignment: Option<ExerciseRoleAssignment> =
+                        ExerciseRoleAssignment::by_exercise_id_and_user_id(exercise_id, user_id)
+                            .first(&mut connection)
+                            .optional()?;
+
+                    Ok(exercise_role_assignment)
+                })
+                .await??;
+
+                Ok(exercise_role_assignment)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteExerciseRoleAssignment(pub Uuid);
+
+impl Handler<DeleteExerciseRoleAssignment> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(
+        &mut self,
+        msg: DeleteExerciseRoleAssignment,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let exercise_role_assignment: ExerciseRoleAssignment =
+                        ExerciseRoleAssignment::by_id(id).first(&mut connection)?;
+                    exercise_role_assignment
+                        .soft_delete()
+                        .execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,272 @@
+use super::{metric::send_metric_score, metric_grading::grade_metric, Database};
+use crate::{
+    constants::{FLAG_ATTEMPT_LIMIT, FLAG_GRADER_ID},
+    errors::RangerError,
+    models::{
+        helpers::uuid::Uuid, metric::NewMetric, Deployment, DeploymentSecret, Flag, FlagAttempt,
+        FlagSubmissionResource, FlagSubmissionResult, Metric, NewFlag, NewFlagAttempt,
+        UpdateMetric,
+    },
+    schema::{flag_attempts, metrics},
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use chrono::NaiveDateTime;
+use diesel::{Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<Flag>")]
+pub struct CreateFlag(pub NewFlag);
+
+impl Handler<CreateFlag> for Database {
+    type Result = ResponseActFuture<Self, Result<Flag>>;
+
+    fn handle(&mut self, msg: CreateFlag, _ctx: &mut Self::Context) -> Self::Result {
+        let new_flag = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let flag = block(move || {
+                    new_flag.create_insert().execute(&mut connection)?;
+                    let flag = Flag::by_id(new_flag.id).first(&mut connection)?;
+
+                    Ok(flag)
+                })
+                .await??;
+
+                Ok(flag)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Flag>>")]
+pub struct GetFlags(pub Uuid);
+
+impl Handler<GetFlags> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Flag>>>;
+
+    fn handle(&mut self, msg: GetFlags, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let flags = block(move || {
+                    let flags = Flag::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(flags)
+                })
+                .await??;
+
+                Ok(flags)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteFlag(pub Uuid);
+
+impl Handler<DeleteFlag> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteFlag, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let flag: Flag = Flag::by_id(id).first(&mut connection)?;
+                    flag.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<FlagAttempt>>")]
+pub struct GetFlagAttempts(pub Uuid);
+
+impl Handler<GetFlagAttempts> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<FlagAttempt>>>;
+
+    fn handle(&mut self, msg: GetFlagAttempts, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let flag_attempts = block(move || {
+                    let flag_attempts = FlagAttempt::by_deployment_id(deployment_id)
+                        .order_by(flag_attempts::created_at.desc())
+                        .load(&mut connection)?;
+
+                    Ok(flag_attempts)
+                })
+                .await??;
+
+                Ok(flag_attempts)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<FlagSubmissionResult>")]
+pub struct SubmitFlag(
+    pub NewMetric,
+    pub FlagSubmissionResource,
+    pub Option<String>,
+    pub NaiveDateTime,
+);
+
+impl Handler<SubmitFlag> for Database {
+    type Result = ResponseActFuture<Self, Result<FlagSubmissionResult>>;
+
+    fn handle(&mut self, msg: SubmitFlag, _ctx: &mut Self::Context) -> Self::Result {
+        let SubmitFlag(new_metric, flag_submission, submitter_id, attempt_window_start) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let flag_submission_result = block(move || {
+                    let (flag_submission_result, is_graded) = connection
+                        .transaction::<_, anyhow::Error, _>(|connection| {
+                            Deployment::by_id(new_metric.deployment_id)
+                                .for_update()
+                                .first::<Deployment>(connection)?;
+                            let attempt_count: i64 =
+                                FlagAttempt::recent_by_entity_selector_and_metric_key(
+                                    new_metric.deployment_id,
+                                    new_metric.entity_selector.clone(),
+                                    new_metric.sdl_key.clone(),
+                                    attempt_window_start,
+                                )
+                                .count()
+                                .get_result(connection)?;
+                            if attempt_count >= FLAG_ATTEMPT_LIMIT {
+                                return Err(RangerError::FlagAttemptLimitReached.into());
+                            }
+
+                            let flags: Vec<Flag> = Flag::by_exercise_id_and_metric_key(
+                                new_metric.exercise_id,
+                                new_metric.sdl_key.clone(),
+                            )
+                            .load(connection)?;
+                            let deployment_secrets: Vec<DeploymentSecret> = match flags
+                                .iter()
+                                .any(|flag| flag.secret_definition_id.is_some())
+                            {
+                                true => {
+                                    DeploymentSecret::by_deployment_id(new_metric.deployment_id)
+                                        .load(connection)?
+                                }
+                                false => vec![],
+                            };
+                            let is_correct = flags.iter().any(|flag| {
+                                flag.applies_to(&new_metric.entity_selector)
+                                    && flag.matches(
+                                        &flag_submission.answer,
+                                        &new_metric.entity_selector,
+                                        &deployment_secrets,
+                                    )
+                            });
+                            NewFlagAttempt::new(
+                                new_metric.exercise_id,
+                                new_metric.deployment_id,
+                                new_metric.entity_selector.clone(),
+                                submitter_id,
+                                &flag_submission,
+                                is_correct,
+                            )
+                            .create_insert()
+                            .execute(connection)?;
+                            if !is_correct {
+                                return Ok((
+                                    FlagSubmissionResult {
+                                        is_correct,
+                                        metric: None,
+                                    },
+                                    false,
+                                ));
+                            }
+
+                            let existing_metric: Option<Metric> =
+                                Metric::by_deployment_id(new_metric.deployment_id)
+                                    .filter(
+                                        metrics::entity_selector.eq(&new_metric.entity_selector),
+                                    )
+                                    .filter(metrics::sdl_key.eq(&new_metric.sdl_key))
+                                    .first(connection)
+                                    .optional()?;
+                            let metric_id = match existing_metric {
+                                Some(existing_metric) if existing_metric.score.is_some() => {
+                                    return Ok((
+                                        FlagSubmissionResult {
+                                            is_correct,
+                                            metric: Some(existing_metric),
+                                        },
+                                        false,
+                                    ));
+                                }
+                                Some(existing_metric) => existing_metric.id,
+                                None => {
+                                    new_metric.create_insert().execute(connection)?;
+                                    new_metric.id
+                                }
+                            };
+                            let metric = grade_metric(
+                                connection,
+                                metric_id,
+                                &UpdateMetric {
+                                    text_submission: None,
+                                    score: Some(new_metric.max_score),
+                                },
+                                FLAG_GRADER_ID.to_string(),
+                                None,
+                            )?;
+
+                            Ok((
+                                FlagSubmissionResult {
+                                    is_correct,
+                                    metric: Some(metric),
+                                },
+                                true,
+                            ))
+                        })?;
+                    if let (Some(metric), true) = (&flag_submission_result.metric, is_graded) {
+                        send_metric_score(&mut connection, &websocket_manager, metric)?;
+                    }
+
+                    Ok(flag_submission_result)
+                })
+                .await??;
+
+                Ok(flag_submission_result)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,322 @@
+use super::{metric::send_metric_score, metric_grading::grade_metric, Database};
+use crate::models::{
+    helpers::uuid::Uuid, AnonymisedSubmission, GradingAssignment, GradingReconciliation,
+    GradingReconciliationStatus, Metric, NewGradingAssignment, NewGradingAssignmentsResource,
+    SubmitGradingAssignment, SubmitGradingAssignmentResource, UpdateGradingAssignment,
+    UpdateMetric,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{Connection, MysqlConnection, RunQueryDsl};
+use std::collections::HashMap;
+
+fn load_grading_reconciliation(
+    connection: &mut MysqlConnection,
+    metric_id: Uuid,
+) -> Result<GradingReconciliation> {
+    let assignments: Vec<GradingAssignment> =
+        GradingAssignment::by_metric_id(metric_id).load(connection)?;
+
+    Ok(GradingReconciliation::new(metric_id, assignments))
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<GradingReconciliation>")]
+pub struct AssignGraders(pub Metric, pub NewGradingAssignmentsResource);
+
+impl Handler<AssignGraders> for Database {
+    type Result = ResponseActFuture<Self, Result<GradingReconciliation>>;
+
+    fn handle(&mut self, msg: AssignGraders, _ctx: &mut Self::Context) -> Self::Result {
+        let AssignGraders(metric, new_assignments) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let grading_reconciliation = block(move || {
+                    let grading_reconciliation =
+                        connection.transaction::<_, anyhow::Error, _>(|connection| {
+                            let existing_assignments: Vec<GradingAssignment> =
+                                GradingAssignment::by_metric_id(metric.id).load(connection)?;
+                            for assignment in &existing_assignments {
+                                if !new_assignments.grader_ids.contains(&assignment.grader_id) {
+                                    assignment.soft_delete().execute(connection)?;
+                                }
+                            }
+                            for grader_id in &new_assignments.grader_ids {
+                                if !existing_assignments
+                                    .iter()
+                                    .any(|assignment| &assignment.grader_id == grader_id)
+                                {
+                                    NewGradingAssignment::new(
+                                        &metric,
+                                        grader_id.to_owned(),
+                                        new_assignments.disagreement_threshold,
+                                    )
+                                    .create_insert()
+                                    .execute(connection)?;
+                                }
+                            }
+                            UpdateGradingAssignment {
+                                disagreement_threshold: new_assignments.disagreement_threshold,
+                            }
+                            .create_update_by_metric_id(metric.id)
+                            .execute(connection)?;
+
+                            load_grading_reconciliation(connection, metric.id)
+                        })?;
+
+                    Ok(grading_reconciliation)
+                })
+                .await??;
+
+                Ok(grading_reconciliation)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<GradingReconciliation>")]
+pub struct GetGradingReconciliation(pub Uuid);
+
+impl Handler<GetGradingReconciliation> for Database {
+    type Result = ResponseActFuture<Self, Result<GradingReconciliation>>;
+
+    fn handle(&mut self, msg: GetGradingReconciliation, _ctx: &mut Self::Context) -> Self::Result {
+        let metric_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let grading_reconciliation =
+                    block(move || load_grading_reconciliation(&mut connection, metric_id))
+                        .await??;
+
+                Ok(grading_reconciliation)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<GradingReconciliation>>")]
+pub struct GetGradingReconciliations(pub Uuid);
+
+impl Handler<GetGradingReconciliations> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<GradingReconciliation>>>;
+
+    fn handle(&mut self, msg: GetGradingReconciliations, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let grading_reconciliations = block(move || {
+                    let assignments: Vec<GradingAssignment> =
+                        GradingAssignment::by_deployment_id(deployment_id).load(&mut connection)?;
+                    let mut assignments_by_metric: HashMap<Uuid, Vec<GradingAssignment>> =
+                        HashMap::new();
+                    for assignment in assignments {
+                        assignments_by_metric
+                            .entry(assignment.metric_id)
+                            .or_default()
+                            .push(assignment);
+                    }
+                    let grading_reconciliations = assignments_by_metric
+                        .into_iter()
+                        .map(|(metric_id, assignments)| {
+                            GradingReconciliation::new(metric_id, assignments)
+                        })
+                        .collect::<Vec<GradingReconciliation>>();
+
+                    Ok(grading_reconciliations)
+                })
+                .await??;
+
+                Ok(grading_reconciliations)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<GradingAssignment>")]
+pub struct GetGradingAssignment(pub Uuid);
+
+impl Handler<GetGradingAssignment> for Database {
+    type Result = ResponseActFuture<Self, Result<GradingAssignment>>;
+
+    fn handle(&mut self, msg: GetGradingAssignment, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let grading_assignment = block(move || {
+                    let grading_assignment = GradingAssignment::by_id(id).first(&mut connection)?;
+
+                    Ok(grading_assignment)
+                })
+                .await??;
+
+                Ok(grading_assignment)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<AnonymisedSubmission>>")]
+pub struct GetGradingQueue(pub Uuid, pub String);
+
+impl Handler<GetGradingQueue> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<AnonymisedSubmission>>>;
+
+    fn handle(&mut self, msg: GetGradingQueue, _ctx: &mut Self::Context) -> Self::Result {
+        let GetGradingQueue(deployment_id, grader_id) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let submissions = block(move || {
+                    let assignments: Vec<GradingAssignment> =
+                        GradingAssignment::by_deployment_id_and_grader_id(deployment_id, grader_id)
+                            .load(&mut connection)?;
+                    let mut submissions = Vec::new();
+                    for assignment in assignments {
+                        let metric: Metric =
+                            Metric::by_id(assignment.metric_id).first(&mut connection)?;
+                        submissions.push(AnonymisedSubmission::from((assignment, metric)));
+                    }
+                    submissions.sort_by_key(|submission| submission.submitted_at);
+
+                    Ok(submissions)
+                })
+                .await??;
+
+                Ok(submissions)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Uuid>>")]
+pub struct GetPendingGradingMetricIds(pub Uuid, pub String);
+
+impl Handler<GetPendingGradingMetricIds> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Uuid>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetPendingGradingMetricIds,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let GetPendingGradingMetricIds(deployment_id, grader_id) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric_ids = block(move || {
+                    let assignments: Vec<GradingAssignment> =
+                        GradingAssignment::by_deployment_id_and_grader_id(deployment_id, grader_id)
+                            .load(&mut connection)?;
+                    let metric_ids = assignments
+                        .into_iter()
+                        .filter(|assignment| !assignment.is_graded())
+                        .map(|assignment| assignment.metric_id)
+                        .collect::<Vec<Uuid>>();
+
+                    Ok(metric_ids)
+                })
+                .await??;
+
+                Ok(metric_ids)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<AnonymisedSubmission>")]
+pub struct SubmitGrade(pub Uuid, pub SubmitGradingAssignmentResource);
+
+impl Handler<SubmitGrade> for Database {
+    type Result = ResponseActFuture<Self, Result<AnonymisedSubmission>>;
+
+    fn handle(&mut self, msg: SubmitGrade, _ctx: &mut Self::Context) -> Self::Result {
+        let SubmitGrade(assignment_id, submit_grading_assignment) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let submission = block(move || {
+                    let (assignment, metric, is_reconciled) = connection
+                        .transaction::<_, anyhow::Error, _>(|connection| {
+                            SubmitGradingAssignment::from(submit_grading_assignment)
+                                .create_update(assignment_id)
+                                .execute(connection)?;
+                            let assignment: GradingAssignment =
+                                GradingAssignment::by_id(assignment_id).first(connection)?;
+                            let metric: Metric =
+                                Metric::by_id(assignment.metric_id).first(connection)?;
+
+                            let grading_reconciliation =
+                                load_grading_reconciliation(connection, metric.id)?;
+                            let (metric, is_reconciled) =
+                                match (grading_reconciliation.status, grading_reconciliation.score)
+                                {
+                                    (GradingReconciliationStatus::Reconciled, Some(score))
+                                        if metric.score != Some(score) =>
+                                    {
+                                        let update_metric = UpdateMetric {
+                                            text_submission: None,
+                                            score: Some(score),
+                                        };
+                                        let metric = grade_metric(
+                                            connection,
+                                            metric.id,
+                                            &update_metric,
+                                            assignment.grader_id.clone(),
+                                            Some(format!(
+                                                "Reconciled from {} independent grades",
+                                                grading_reconciliation.assignments.len()
+                                            )),
+                                        )?;
+                                        (metric, true)
+                                    }
+                                    _ => (metric, false),
+                                };
+
+                            Ok((assignment, metric, is_reconciled))
+                        })?;
+                    if is_reconciled {
+                        send_metric_score(&mut connection, &websocket_manager, &metric)?;
+                    }
+
+                    Ok(AnonymisedSubmission::from((assignment, metric)))
+                })
+                .await??;
+
+                Ok(submission)
+            }
+            .into_actor(self),
+        )
+    }
+}
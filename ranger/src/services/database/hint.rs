@@ -0,0 +1,275 @@
+use super::{
+    penalty::{create_penalty, send_penalty_score},
+    Database,
+};
+use crate::{
+    constants::BIG_DECIMAL_ZERO,
+    models::{
+        helpers::uuid::Uuid, Deployment, Event, Hint, HintRelease, NewHint, NewHintRelease,
+        NewPenalty, NewPenaltyResource, ParticipantHint,
+    },
+    schema::hint_releases,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use chrono::Utc;
+use diesel::{Connection, ExpressionMethods, OptionalExtension, QueryDsl, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<Hint>")]
+pub struct CreateHint(pub NewHint);
+
+impl Handler<CreateHint> for Database {
+    type Result = ResponseActFuture<Self, Result<Hint>>;
+
+    fn handle(&mut self, msg: CreateHint, _ctx: &mut Self::Context) -> Self::Result {
+        let new_hint = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let hint = block(move || {
+                    new_hint.create_insert().execute(&mut connection)?;
+                    let hint = Hint::by_id(new_hint.id).first(&mut connection)?;
+
+                    Ok(hint)
+                })
+                .await??;
+
+                Ok(hint)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Hint>>")]
+pub struct GetHints(pub Uuid);
+
+impl Handler<GetHints> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Hint>>>;
+
+    fn handle(&mut self, msg: GetHints, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let hints = block(move || {
+                    let hints = Hint::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(hints)
+                })
+                .await??;
+
+                Ok(hints)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteHint(pub Uuid);
+
+impl Handler<DeleteHint> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteHint, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let hint: Hint = Hint::by_id(id).first(&mut connection)?;
+                    hint.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<HintRelease>>")]
+pub struct GetHintReleases(pub Uuid);
+
+impl Handler<GetHintReleases> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<HintRelease>>>;
+
+    fn handle(&mut self, msg: GetHintReleases, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let hint_releases = block(move || {
+                    let hint_releases =
+                        HintRelease::by_deployment_id(deployment_id).load(&mut connection)?;
+
+                    Ok(hint_releases)
+                })
+                .await??;
+
+                Ok(hint_releases)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ParticipantHint>>")]
+pub struct GetParticipantHints(pub Deployment, pub String);
+
+impl Handler<GetParticipantHints> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ParticipantHint>>>;
+
+    fn handle(&mut self, msg: GetParticipantHints, _ctx: &mut Self::Context) -> Self::Result {
+        let GetParticipantHints(deployment, entity_selector) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let participant_hints = block(move || {
+                    let now = Utc::now().naive_utc();
+                    let hints: Vec<Hint> =
+                        Hint::by_exercise_id(deployment.exercise_id).load(&mut connection)?;
+                    let events: Vec<Event> =
+                        Event::by_deployment_id(deployment.id).load(&mut connection)?;
+                    let hint_releases: Vec<HintRelease> =
+                        HintRelease::by_deployment_id_and_entity_selector(
+                            deployment.id,
+                            entity_selector,
+                        )
+                        .load(&mut connection)?;
+                    let participant_hints = hints
+                        .into_iter()
+                        .map(|hint| {
+                            let hint_release = hint_releases
+                                .iter()
+                                .find(|hint_release| hint_release.hint_id == hint.id);
+                            ParticipantHint::new(hint, &deployment, &events, hint_release, now)
+                        })
+                        .collect::<Vec<_>>();
+
+                    Ok(participant_hints)
+                })
+                .await??;
+
+                Ok(participant_hints)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<ParticipantHint>")]
+pub struct TakeHint(pub Deployment, pub Uuid, pub String, pub Option<String>);
+
+impl Handler<TakeHint> for Database {
+    type Result = ResponseActFuture<Self, Result<ParticipantHint>>;
+
+    fn handle(&mut self, msg: TakeHint, _ctx: &mut Self::Context) -> Self::Result {
+        let TakeHint(deployment, hint_id, entity_selector, requested_by) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let participant_hint = block(move || {
+                    let (participant_hint, penalty_score) = connection
+                        .transaction::<_, anyhow::Error, _>(|connection| {
+                            let now = Utc::now().naive_utc();
+                            let hint: Hint = Hint::by_id(hint_id).first(connection)?;
+                            let events: Vec<Event> =
+                                Event::by_deployment_id(deployment.id).load(connection)?;
+                            let existing_release: Option<HintRelease> =
+                                HintRelease::by_deployment_id_and_entity_selector(
+                                    deployment.id,
+                                    entity_selector.clone(),
+                                )
+                                .filter(hint_releases::hint_id.eq(hint.id))
+                                .first(connection)
+                                .optional()?;
+                            let (hint_release, penalty_score) = match existing_release {
+                                Some(existing_release) => (existing_release, None),
+                                None => {
+                                    let (penalty_id, penalty_score) = match &hint.cost {
+                                        Some(cost) if *cost > *BIG_DECIMAL_ZERO => {
+                                            let (penalty, score) = create_penalty(
+                                                connection,
+                                                &NewPenalty::new(
+                                                    NewPenaltyResource {
+                                                        entity_selector: entity_selector.clone(),
+                                                        value: cost.clone(),
+                                                        reason: format!(
+                                                            "Hint \"{}\" taken",
+                                                            hint.title
+                                                        ),
+                                                    },
+                                                    deployment.exercise_id,
+                                                    deployment.id,
+                                                    None,
+                                                ),
+                                            )?;
+                                            (Some(penalty.id), Some(score))
+                                        }
+                                        _ => (None, None),
+                                    };
+                                    let new_hint_release = NewHintRelease::new(
+                                        hint.id,
+                                        deployment.id,
+                                        entity_selector,
+                                        penalty_id,
+                                        requested_by,
+                                    );
+                                    new_hint_release.create_insert().execute(connection)?;
+                                    let hint_release: HintRelease = HintRelease::all()
+                                        .filter(hint_releases::id.eq(new_hint_release.id))
+                                        .first(connection)?;
+                                    (hint_release, penalty_score)
+                                }
+                            };
+
+                            Ok((
+                                ParticipantHint::new(
+                                    hint,
+                                    &deployment,
+                                    &events,
+                                    Some(&hint_release),
+                                    now,
+                                ),
+                                penalty_score,
+                            ))
+                        })?;
+                    if let Some(score) = penalty_score {
+                        send_penalty_score(&websocket_manager, score);
+                    }
+
+                    Ok(participant_hint)
+                })
+                .await??;
+
+                Ok(participant_hint)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -1,13 +1,13 @@
-use super::Database;
+use super::{metric_submission::record_metric_submission, Database};
 use crate::constants::RECORD_NOT_FOUND;
 use crate::models::metric::Metric;
-use crate::models::Score;
 use crate::models::{helpers::uuid::Uuid, metric::NewMetric};
-use crate::services::websocket::SocketScoring;
-use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use crate::models::{EvaluationUpdate, NewScore, Score};
+use crate::services::websocket::{SocketEvaluation, SocketScoring, WebSocketManager};
+use actix::{Addr, Handler, Message, ResponseActFuture, WrapFuture};
 use actix_web::web::block;
 use anyhow::{anyhow, Ok, Result};
-use diesel::RunQueryDsl;
+use diesel::{MysqlConnection, RunQueryDsl};
 
 #[derive(Message)]
 #[rtype(result = "Result<Metric>")]
@@ -25,8 +25,14 @@ impl Handler<CreateMetric> for Database {
                 let mut connection = connection_result?;
                 let manual_metric = block(move || {
                     new_manual_metric.create_insert().execute(&mut connection)?;
-                    let manual_metric =
+                    if new_manual_metric.text_submission.is_some() {
+                        record_metric_submission(&mut connection, new_manual_metric.id, None)?;
+                    }
+                    let manual_metric: Metric =
                         Metric::by_id(new_manual_metric.id).first(&mut connection)?;
+                    NewScore::from(manual_metric.clone())
+                        .create_insert_or_replace()
+                        .execute(&mut connection)?;
 
                     Ok(manual_metric)
                 })
@@ -39,6 +45,54 @@ impl Handler<CreateMetric> for Database {
     }
 }
 
+pub(super) fn apply_metric_update(
+    connection: &mut MysqlConnection,
+    uuid: Uuid,
+    update_metric: &crate::models::UpdateMetric,
+) -> Result<Metric> {
+    let updated_rows = update_metric.create_update(uuid).execute(connection)?;
+    if updated_rows != 1 {
+        return Err(anyhow!(RECORD_NOT_FOUND));
+    }
+    let metric: Metric = Metric::by_id(uuid).first(connection)?;
+    NewScore::from(metric.clone())
+        .create_insert_or_replace()
+        .execute(connection)?;
+
+    Ok(metric)
+}
+
+pub(super) fn send_metric_score(
+    connection: &mut MysqlConnection,
+    websocket_manager: &Addr<WebSocketManager>,
+    metric: &Metric,
+) -> Result<()> {
+    if metric.score.is_none() {
+        return Ok(());
+    }
+    let score: Score = Score::by_id(metric.id).first(connection)?;
+
+    let scoring_msg = SocketScoring(
+        score.exercise_id,
+        (score.id, score.exercise_id, score).into(),
+    );
+    websocket_manager.do_send(scoring_msg);
+
+    websocket_manager.do_send(SocketEvaluation(
+        metric.exercise_id,
+        (
+            metric.exercise_id,
+            metric.deployment_id,
+            EvaluationUpdate {
+                deployment_id: metric.deployment_id,
+            },
+        )
+            .into(),
+    ));
+
+    Ok(())
+}
+
 #[derive(Message)]
 #[rtype(result = "Result<Metric>")]
 pub struct UpdateMetric(pub Uuid, pub crate::models::UpdateMetric);
@@ -55,22 +109,12 @@ impl Handler<UpdateMetric> for Database {
             async move {
                 let mut connection = connection_result?;
                 let metric = block(move || {
-                    let updated_rows = update_manual_metric
-                        .create_update(uuid)
-                        .execute(&mut connection)?;
-                    if updated_rows != 1 {
-                        return Err(anyhow!(RECORD_NOT_FOUND));
-                    }
-                    let metric = Metric::by_id(uuid).first(&mut connection)?;
-                    if metric.score.is_some() {
-                        let score: Score = metric.clone().into();
-
-                        let scoring_msg = SocketScoring(
-                            score.exercise_id,
-                            (score.id, score.exercise_id, score).into(),
-                        );
-                        websocket_manager.do_send(scoring_msg);
+                    let metric = apply_metric_update(&mut connection, uuid, &update_manual_metric)?;
+                    if update_manual_metric.text_submission.is_some() {
+                        record_metric_submission(&mut connection, uuid, None)?;
                     }
+                    send_metric_score(&mut connection, &websocket_manager, &metric)?;
+
                     Ok(metric)
                 })
                 .await??;
@@ -158,6 +202,7 @@ impl Handler<DeleteMetric> for Database {
                         .map_err(|error| anyhow!("Error locking Mutex connection: {:?}", error))?;
                     let manual_metric = Metric::by_id(id).first(&mut *connection)?;
                     manual_metric.soft_delete().execute(&mut *connection)?;
+                    Score::soft_delete_by_metric_id(manual_metric.id).execute(&mut *connection)?;
 
                     Ok(id)
                 })
@@ -0,0 +1,193 @@
+use super::{
+    metric::{apply_metric_update, send_metric_score},
+    Database,
+};
+use crate::{
+    constants::RECORD_NOT_FOUND,
+    models::{helpers::uuid::Uuid, Metric, MetricGrading, NewMetricGrading, UpdateMetric},
+    schema::metric_gradings,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{Connection, ExpressionMethods, MysqlConnection, QueryDsl, RunQueryDsl};
+
+pub(super) fn grade_metric(
+    connection: &mut MysqlConnection,
+    metric_id: Uuid,
+    update_metric: &UpdateMetric,
+    grader_id: String,
+    comment: Option<String>,
+) -> Result<Metric> {
+    connection.transaction::<_, anyhow::Error, _>(|connection| {
+        let previous_metric: Metric = Metric::by_id(metric_id).first(connection)?;
+        let metric = apply_metric_update(connection, metric_id, update_metric)?;
+        if let Some(score) = metric.score {
+            if previous_metric.score != metric.score || comment.is_some() {
+                NewMetricGrading::new(metric_id, grader_id, previous_metric.score, score, comment)
+                    .create_insert()
+                    .execute(connection)?;
+            }
+        }
+
+        Ok(metric)
+    })
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Metric>")]
+pub struct GradeMetric(pub Uuid, pub UpdateMetric, pub String, pub Option<String>);
+
+impl Handler<GradeMetric> for Database {
+    type Result = ResponseActFuture<Self, Result<Metric>>;
+
+    fn handle(&mut self, msg: GradeMetric, _ctx: &mut Self::Context) -> Self::Result {
+        let GradeMetric(metric_id, update_metric, grader_id, comment) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric = block(move || {
+                    let metric = grade_metric(
+                        &mut connection,
+                        metric_id,
+                        &update_metric,
+                        grader_id,
+                        comment,
+                    )?;
+                    send_metric_score(&mut connection, &websocket_manager, &metric)?;
+
+                    Ok(metric)
+                })
+                .await??;
+
+                Ok(metric)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<MetricGrading>>")]
+pub struct GetMetricGradings(pub Uuid);
+
+impl Handler<GetMetricGradings> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<MetricGrading>>>;
+
+    fn handle(&mut self, msg: GetMetricGradings, _ctx: &mut Self::Context) -> Self::Result {
+        let metric_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric_gradings = block(move || {
+                    let metric_gradings = MetricGrading::by_metric_id(metric_id)
+                        .order_by(metric_gradings::created_at.desc())
+                        .load(&mut connection)?;
+
+                    Ok(metric_gradings)
+                })
+                .await??;
+
+                Ok(metric_gradings)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<MetricGrading>>")]
+pub struct GetMetricGradingsByDeploymentId(pub Uuid);
+
+impl Handler<GetMetricGradingsByDeploymentId> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<MetricGrading>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetMetricGradingsByDeploymentId,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric_gradings = block(move || {
+                    let metrics: Vec<Metric> =
+                        Metric::by_deployment_id(deployment_id).load(&mut connection)?;
+                    let metric_ids = metrics
+                        .iter()
+                        .map(|metric| metric.id)
+                        .collect::<Vec<Uuid>>();
+                    let metric_gradings = MetricGrading::all()
+                        .filter(metric_gradings::metric_id.eq_any(metric_ids))
+                        .order_by(metric_gradings::created_at.asc())
+                        .load(&mut connection)?;
+
+                    Ok(metric_gradings)
+                })
+                .await??;
+
+                Ok(metric_gradings)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Metric>")]
+pub struct RevertMetricGrading(pub Uuid, pub Uuid, pub String, pub Option<String>);
+
+impl Handler<RevertMetricGrading> for Database {
+    type Result = ResponseActFuture<Self, Result<Metric>>;
+
+    fn handle(&mut self, msg: RevertMetricGrading, _ctx: &mut Self::Context) -> Self::Result {
+        let RevertMetricGrading(metric_id, metric_grading_id, grader_id, comment) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric = block(move || {
+                    let metric_grading: MetricGrading =
+                        MetricGrading::by_id(metric_grading_id).first(&mut connection)?;
+                    if metric_grading.metric_id != metric_id {
+                        return Err(anyhow!(RECORD_NOT_FOUND));
+                    }
+                    let comment = comment.or_else(|| {
+                        Some(format!(
+                            "Reverted to grading {metric_grading_id} by {}",
+                            metric_grading.grader_id
+                        ))
+                    });
+                    let update_metric = UpdateMetric {
+                        text_submission: None,
+                        score: Some(metric_grading.score),
+                    };
+                    let metric = grade_metric(
+                        &mut connection,
+                        metric_id,
+                        &update_metric,
+                        grader_id,
+                        comment,
+                    )?;
+                    send_metric_score(&mut connection, &websocket_manager, &metric)?;
+
+                    Ok(metric)
+                })
+                .await??;
+
+                Ok(metric)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use super::Database;
+use crate::{
+    models::{helpers::uuid::Uuid, Metric, MetricSubmission, NewMetricSubmission},
+    schema::metric_submissions,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use diesel::{ExpressionMethods, MysqlConnection, OptionalExtension, QueryDsl, RunQueryDsl};
+
+pub(super) fn record_metric_submission(
+    connection: &mut MysqlConnection,
+    metric_id: Uuid,
+    artifact_id: Option<Uuid>,
+) -> Result<()> {
+    let metric: Metric = Metric::by_id(metric_id).first(connection)?;
+    let latest_submission: Option<MetricSubmission> = MetricSubmission::by_metric_id(metric_id)
+        .order_by(metric_submissions::version.desc())
+        .first(connection)
+        .optional()?;
+    let (version, artifact_id) = match latest_submission {
+        Some(latest_submission) => (
+            latest_submission.version + 1,
+            artifact_id.or(latest_submission.artifact_id),
+        ),
+        None => (1, artifact_id),
+    };
+
+    NewMetricSubmission::new(metric_id, version, metric.text_submission, artifact_id)
+        .create_insert()
+        .execute(connection)?;
+
+    Ok(())
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<MetricSubmission>>")]
+pub struct GetMetricSubmissions(pub Uuid);
+
+impl Handler<GetMetricSubmissions> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<MetricSubmission>>>;
+
+    fn handle(&mut self, msg: GetMetricSubmissions, _ctx: &mut Self::Context) -> Self::Result {
+        let metric_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric_submissions = block(move || {
+                    let metric_submissions = MetricSubmission::by_metric_id(metric_id)
+                        .order_by(metric_submissions::version.asc())
+                        .load(&mut connection)?;
+
+                    Ok(metric_submissions)
+                })
+                .await??;
+
+                Ok(metric_submissions)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<MetricSubmission>")]
+pub struct GetMetricSubmission(pub Uuid, pub u32);
+
+impl Handler<GetMetricSubmission> for Database {
+    type Result = ResponseActFuture<Self, Result<MetricSubmission>>;
+
+    fn handle(&mut self, msg: GetMetricSubmission, _ctx: &mut Self::Context) -> Self::Result {
+        let GetMetricSubmission(metric_id, version) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric_submission = block(move || {
+                    let metric_submission =
+                        MetricSubmission::by_metric_id_and_version(metric_id, version)
+                            .first(&mut connection)?;
+
+                    Ok(metric_submission)
+                })
+                .await??;
+
+                Ok(metric_submission)
+            }
+            .into_actor(self),
+        )
+    }
+}
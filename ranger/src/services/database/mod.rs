@@ -1,15 +1,30 @@
 pub(crate) mod account;
+pub(crate) mod api_token;
 pub(crate) mod banner;
+pub(crate) mod certificate;
 pub(crate) mod condition;
 pub(crate) mod deployment;
+pub(crate) mod deployment_secret;
 pub(crate) mod email;
 pub(crate) mod email_status;
 pub(crate) mod event;
 pub(crate) mod event_info;
 pub(crate) mod exercise;
+pub(crate) mod exercise_role_assignment;
+pub(crate) mod flag;
+pub(crate) mod grading_assignment;
+pub(crate) mod hint;
 pub(crate) mod metric;
+pub(crate) mod metric_grading;
+pub(crate) mod metric_submission;
 pub(crate) mod order;
 pub(crate) mod participant;
+pub(crate) mod penalty;
+pub(crate) mod questionnaire;
+pub(crate) mod rubric;
+pub(crate) mod score;
+pub(crate) mod scoring_strategy;
+pub(crate) mod submission_window;
 pub(crate) mod upload;
 
 use crate::{models::helpers::uuid::Uuid, utilities::run_migrations};
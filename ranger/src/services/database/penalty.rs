@@ -0,0 +1,139 @@
+use super::Database;
+use crate::models::{helpers::uuid::Uuid, NewPenalty, NewScore, Penalty, Score};
+use crate::services::websocket::{SocketScoring, WebSocketManager};
+use actix::{Addr, Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{Ok, Result};
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use diesel::{Connection, MysqlConnection, OptionalExtension, RunQueryDsl};
+
+pub(super) fn create_penalty(
+    connection: &mut MysqlConnection,
+    new_penalty: &NewPenalty,
+) -> Result<(Penalty, Score)> {
+    connection.transaction::<_, anyhow::Error, _>(|connection| {
+        new_penalty.create_insert().execute(connection)?;
+        let penalty: Penalty = Penalty::by_id(new_penalty.id).first(connection)?;
+        NewScore::from(penalty.clone())
+            .create_insert_or_replace()
+            .execute(connection)?;
+        let score: Score = Score::by_id(penalty.id).first(connection)?;
+
+        Ok((penalty, score))
+    })
+}
+
+pub(super) fn send_penalty_score(websocket_manager: &Addr<WebSocketManager>, score: Score) {
+    let scoring_msg = SocketScoring(
+        score.exercise_id,
+        (score.id, score.exercise_id, score).into(),
+    );
+    websocket_manager.do_send(scoring_msg);
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Penalty>")]
+pub struct CreatePenalty(pub NewPenalty);
+
+impl Handler<CreatePenalty> for Database {
+    type Result = ResponseActFuture<Self, Result<Penalty>>;
+
+    fn handle(&mut self, msg: CreatePenalty, _ctx: &mut Self::Context) -> Self::Result {
+        let new_penalty = msg.0;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let penalty = block(move || {
+                    let (penalty, score) = create_penalty(&mut connection, &new_penalty)?;
+                    send_penalty_score(&websocket_manager, score);
+
+                    Ok(penalty)
+                })
+                .await??;
+
+                Ok(penalty)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Penalty>>")]
+pub struct GetPenalties(pub Uuid);
+
+impl Handler<GetPenalties> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Penalty>>>;
+
+    fn handle(&mut self, msg: GetPenalties, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let penalties = block(move || {
+                    let penalties =
+                        Penalty::by_deployment_id(deployment_id).load(&mut connection)?;
+
+                    Ok(penalties)
+                })
+                .await??;
+
+                Ok(penalties)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeletePenalty(pub Uuid);
+
+impl Handler<DeletePenalty> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeletePenalty, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let score = connection.transaction::<_, anyhow::Error, _>(|connection| {
+                        let penalty: Penalty = Penalty::by_id(id).first(connection)?;
+                        let score: Option<Score> =
+                            Score::by_id(penalty.id).first(connection).optional()?;
+                        penalty.soft_delete().execute(connection)?;
+                        Score::soft_delete_by_metric_id(penalty.id).execute(connection)?;
+
+                        Ok(score)
+                    })?;
+                    if let Some(score) = score {
+                        send_penalty_score(
+                            &websocket_manager,
+                            Score {
+                                value: BigDecimal::from(0),
+                                timestamp: Utc::now().naive_utc(),
+                                ..score
+                            },
+                        );
+                    }
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,275 @@
+use super::{
+    metric::send_metric_score, metric_grading::grade_metric,
+    metric_submission::record_metric_submission, Database,
+};
+use crate::{
+    constants::{QUESTIONNAIRE_GRADER_ID, RECORD_NOT_FOUND},
+    errors::RangerError,
+    models::{
+        helpers::uuid::Uuid, metric::NewMetric, Deployment, Metric, NewQuestionnaire,
+        Questionnaire, QuestionnaireChoice, QuestionnaireChoiceRest, QuestionnaireQuestion,
+        QuestionnaireQuestionRest, QuestionnaireRest, QuestionnaireSubmissionResource,
+        QuestionnaireSubmissionResult, UpdateMetric,
+    },
+    schema::metrics,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{
+    Connection, ExpressionMethods, MysqlConnection, OptionalExtension, QueryDsl, RunQueryDsl,
+};
+
+fn load_questionnaire_rest(
+    connection: &mut MysqlConnection,
+    questionnaire: Questionnaire,
+) -> Result<QuestionnaireRest> {
+    let mut questions: Vec<QuestionnaireQuestion> =
+        QuestionnaireQuestion::by_questionnaire(&questionnaire).load(connection)?;
+    questions.sort_by_key(|question| question.position);
+
+    let mut questions_rest = Vec::new();
+    for question in questions {
+        let mut choices: Vec<QuestionnaireChoice> =
+            QuestionnaireChoice::by_question(&question).load(connection)?;
+        choices.sort_by_key(|choice| choice.position);
+        let choices = choices
+            .into_iter()
+            .map(|choice| choice.into())
+            .collect::<Vec<QuestionnaireChoiceRest>>();
+        questions_rest.push(QuestionnaireQuestionRest::from((question, choices)));
+    }
+
+    Ok(QuestionnaireRest::from((questionnaire, questions_rest)))
+}
+
+fn load_questionnaire_by_metric_key(
+    connection: &mut MysqlConnection,
+    exercise_id: Uuid,
+    metric_key: String,
+) -> Result<Option<QuestionnaireRest>> {
+    let questionnaire: Option<Questionnaire> =
+        Questionnaire::by_exercise_id_and_metric_key(exercise_id, metric_key)
+            .first(connection)
+            .optional()?;
+
+    questionnaire
+        .map(|questionnaire| load_questionnaire_rest(connection, questionnaire))
+        .transpose()
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<QuestionnaireRest>")]
+pub struct CreateQuestionnaire(pub Uuid, pub QuestionnaireRest);
+
+impl Handler<CreateQuestionnaire> for Database {
+    type Result = ResponseActFuture<Self, Result<QuestionnaireRest>>;
+
+    fn handle(&mut self, msg: CreateQuestionnaire, _ctx: &mut Self::Context) -> Self::Result {
+        let CreateQuestionnaire(exercise_id, questionnaire_rest) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let questionnaire = block(move || {
+                    let questionnaire =
+                        connection.transaction::<_, anyhow::Error, _>(|connection| {
+                            Questionnaire::soft_delete_by_exercise_id_and_metric_key(
+                                exercise_id,
+                                questionnaire_rest.metric_key.clone(),
+                            )
+                            .execute(connection)?;
+                            let new_questionnaire =
+                                NewQuestionnaire::new(&questionnaire_rest, exercise_id);
+                            new_questionnaire.create_insert().execute(connection)?;
+
+                            let mut questions = Vec::new();
+                            let mut choices = Vec::new();
+                            for (question_position, question_rest) in
+                                questionnaire_rest.questions.iter().enumerate()
+                            {
+                                let question = QuestionnaireQuestion::new(
+                                    new_questionnaire.id,
+                                    question_position as u32,
+                                    question_rest,
+                                );
+                                choices.extend(question_rest.choices.iter().enumerate().map(
+                                    |(choice_position, choice_rest)| {
+                                        QuestionnaireChoice::new(
+                                            question.id,
+                                            choice_position as u32,
+                                            choice_rest,
+                                        )
+                                    },
+                                ));
+                                questions.push(question);
+                            }
+                            QuestionnaireQuestion::batch_insert(questions).execute(connection)?;
+                            QuestionnaireChoice::batch_insert(choices).execute(connection)?;
+
+                            let questionnaire: Questionnaire =
+                                Questionnaire::by_id(new_questionnaire.id).first(connection)?;
+                            load_questionnaire_rest(connection, questionnaire)
+                        })?;
+
+                    Ok(questionnaire)
+                })
+                .await??;
+
+                Ok(questionnaire)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<QuestionnaireRest>>")]
+pub struct GetQuestionnaires(pub Uuid);
+
+impl Handler<GetQuestionnaires> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<QuestionnaireRest>>>;
+
+    fn handle(&mut self, msg: GetQuestionnaires, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let questionnaires = block(move || {
+                    let questionnaires: Vec<Questionnaire> =
+                        Questionnaire::by_exercise_id(exercise_id).load(&mut connection)?;
+                    let questionnaires = questionnaires
+                        .into_iter()
+                        .map(|questionnaire| {
+                            load_questionnaire_rest(&mut connection, questionnaire)
+                        })
+                        .collect::<Result<Vec<QuestionnaireRest>>>()?;
+
+                    Ok(questionnaires)
+                })
+                .await??;
+
+                Ok(questionnaires)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteQuestionnaire(pub Uuid);
+
+impl Handler<DeleteQuestionnaire> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteQuestionnaire, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let questionnaire: Questionnaire =
+                        Questionnaire::by_id(id).first(&mut connection)?;
+                    questionnaire.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<QuestionnaireSubmissionResult>")]
+pub struct SubmitQuestionnaire(pub NewMetric, pub QuestionnaireSubmissionResource);
+
+impl Handler<SubmitQuestionnaire> for Database {
+    type Result = ResponseActFuture<Self, Result<QuestionnaireSubmissionResult>>;
+
+    fn handle(&mut self, msg: SubmitQuestionnaire, _ctx: &mut Self::Context) -> Self::Result {
+        let SubmitQuestionnaire(new_metric, questionnaire_submission) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let questionnaire_submission_result = block(move || {
+                    let questionnaire_submission_result = connection
+                        .transaction::<_, anyhow::Error, _>(|connection| {
+                            Deployment::by_id(new_metric.deployment_id)
+                                .for_update()
+                                .first::<Deployment>(connection)?;
+                            let existing_metric: Option<Metric> =
+                                Metric::by_deployment_id(new_metric.deployment_id)
+                                    .filter(
+                                        metrics::entity_selector.eq(&new_metric.entity_selector),
+                                    )
+                                    .filter(metrics::sdl_key.eq(&new_metric.sdl_key))
+                                    .first(connection)
+                                    .optional()?;
+                            if existing_metric
+                                .as_ref()
+                                .is_some_and(|existing_metric| existing_metric.score.is_some())
+                            {
+                                return Err(RangerError::MetricAlreadyScored.into());
+                            }
+
+                            let questionnaire = load_questionnaire_by_metric_key(
+                                connection,
+                                new_metric.exercise_id,
+                                new_metric.sdl_key.clone(),
+                            )?
+                            .ok_or_else(|| anyhow!(RECORD_NOT_FOUND))?;
+                            let results = questionnaire.grade(&questionnaire_submission.answers);
+                            let score = questionnaire
+                                .calculate_score(&results, new_metric.max_score)
+                                .ok_or_else(|| anyhow!("Questionnaire has no points to score"))?;
+
+                            let metric_id = match existing_metric {
+                                Some(existing_metric) => existing_metric.id,
+                                None => {
+                                    new_metric.create_insert().execute(connection)?;
+                                    new_metric.id
+                                }
+                            };
+                            let metric = grade_metric(
+                                connection,
+                                metric_id,
+                                &UpdateMetric {
+                                    text_submission: new_metric.text_submission.clone(),
+                                    score: Some(score),
+                                },
+                                QUESTIONNAIRE_GRADER_ID.to_string(),
+                                None,
+                            )?;
+                            record_metric_submission(connection, metric_id, None)?;
+
+                            Ok(QuestionnaireSubmissionResult { metric, results })
+                        })?;
+                    send_metric_score(
+                        &mut connection,
+                        &websocket_manager,
+                        &questionnaire_submission_result.metric,
+                    )?;
+
+                    Ok(questionnaire_submission_result)
+                })
+                .await??;
+
+                Ok(questionnaire_submission_result)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,295 @@
+use super::{metric::send_metric_score, metric_grading::grade_metric, Database};
+use crate::{
+    constants::RECORD_NOT_FOUND,
+    models::{
+        helpers::uuid::Uuid, Metric, NewRubric, NewRubricGrade, Rubric, RubricCriterion,
+        RubricCriterionRest, RubricFeedback, RubricGrade, RubricGradeFeedback,
+        RubricGradingResource, RubricLevel, RubricLevelRest, RubricRest, UpdateMetric,
+    },
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{Connection, MysqlConnection, OptionalExtension, RunQueryDsl};
+
+fn load_rubric_rest(connection: &mut MysqlConnection, rubric: Rubric) -> Result<RubricRest> {
+    let mut criteria: Vec<RubricCriterion> =
+        RubricCriterion::by_rubric(&rubric).load(connection)?;
+    criteria.sort_by_key(|criterion| criterion.position);
+
+    let mut criteria_rest = Vec::new();
+    for criterion in criteria {
+        let mut levels: Vec<RubricLevel> =
+            RubricLevel::by_criterion(&criterion).load(connection)?;
+        levels.sort_by_key(|level| level.position);
+        let levels = levels
+            .into_iter()
+            .map(|level| level.into())
+            .collect::<Vec<RubricLevelRest>>();
+        criteria_rest.push(RubricCriterionRest::from((criterion, levels)));
+    }
+
+    Ok(RubricRest::from((rubric, criteria_rest)))
+}
+
+fn load_rubric_by_metric_key(
+    connection: &mut MysqlConnection,
+    exercise_id: Uuid,
+    metric_key: String,
+) -> Result<Option<RubricRest>> {
+    let rubric: Option<Rubric> = Rubric::by_exercise_id_and_metric_key(exercise_id, metric_key)
+        .first(connection)
+        .optional()?;
+
+    rubric
+        .map(|rubric| load_rubric_rest(connection, rubric))
+        .transpose()
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<RubricRest>")]
+pub struct CreateRubric(pub Uuid, pub RubricRest);
+
+impl Handler<CreateRubric> for Database {
+    type Result = ResponseActFuture<Self, Result<RubricRest>>;
+
+    fn handle(&mut self, msg: CreateRubric, _ctx: &mut Self::Context) -> Self::Result {
+        let CreateRubric(exercise_id, rubric_rest) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let rubric = block(move || {
+                    let rubric = connection.transaction::<_, anyhow::Error, _>(|connection| {
+                        Rubric::soft_delete_by_exercise_id_and_metric_key(
+                            exercise_id,
+                            rubric_rest.metric_key.clone(),
+                        )
+                        .execute(connection)?;
+                        let new_rubric = NewRubric::new(&rubric_rest, exercise_id);
+                        new_rubric.create_insert().execute(connection)?;
+
+                        let mut criteria = Vec::new();
+                        let mut levels = Vec::new();
+                        for (criterion_position, criterion_rest) in
+                            rubric_rest.criteria.iter().enumerate()
+                        {
+                            let criterion = RubricCriterion::new(
+                                new_rubric.id,
+                                criterion_position as u32,
+                                criterion_rest,
+                            );
+                            levels.extend(criterion_rest.levels.iter().enumerate().map(
+                                |(level_position, level_rest)| {
+                                    RubricLevel::new(
+                                        criterion.id,
+                                        level_position as u32,
+                                        level_rest,
+                                    )
+                                },
+                            ));
+                            criteria.push(criterion);
+                        }
+                        RubricCriterion::batch_insert(criteria).execute(connection)?;
+                        RubricLevel::batch_insert(levels).execute(connection)?;
+
+                        let rubric: Rubric = Rubric::by_id(new_rubric.id).first(connection)?;
+                        load_rubric_rest(connection, rubric)
+                    })?;
+
+                    Ok(rubric)
+                })
+                .await??;
+
+                Ok(rubric)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<RubricRest>>")]
+pub struct GetRubrics(pub Uuid);
+
+impl Handler<GetRubrics> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<RubricRest>>>;
+
+    fn handle(&mut self, msg: GetRubrics, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let rubrics = block(move || {
+                    let rubrics: Vec<Rubric> =
+                        Rubric::by_exercise_id(exercise_id).load(&mut connection)?;
+                    let rubrics = rubrics
+                        .into_iter()
+                        .map(|rubric| load_rubric_rest(&mut connection, rubric))
+                        .collect::<Result<Vec<RubricRest>>>()?;
+
+                    Ok(rubrics)
+                })
+                .await??;
+
+                Ok(rubrics)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Option<RubricRest>>")]
+pub struct GetRubricByMetricKey(pub Uuid, pub String);
+
+impl Handler<GetRubricByMetricKey> for Database {
+    type Result = ResponseActFuture<Self, Result<Option<RubricRest>>>;
+
+    fn handle(&mut self, msg: GetRubricByMetricKey, _ctx: &mut Self::Context) -> Self::Result {
+        let GetRubricByMetricKey(exercise_id, metric_key) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let rubric = block(move || {
+                    load_rubric_by_metric_key(&mut connection, exercise_id, metric_key)
+                })
+                .await??;
+
+                Ok(rubric)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteRubric(pub Uuid);
+
+impl Handler<DeleteRubric> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteRubric, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let rubric: Rubric = Rubric::by_id(id).first(&mut connection)?;
+                    rubric.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Metric>")]
+pub struct GradeMetricWithRubric(pub Uuid, pub RubricGradingResource, pub String, pub u32);
+
+impl Handler<GradeMetricWithRubric> for Database {
+    type Result = ResponseActFuture<Self, Result<Metric>>;
+
+    fn handle(&mut self, msg: GradeMetricWithRubric, _ctx: &mut Self::Context) -> Self::Result {
+        let GradeMetricWithRubric(metric_id, rubric_grading, grader_id, score) = msg;
+        let connection_result = self.get_connection();
+        let websocket_manager = self.websocket_manager_address.clone();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let metric = block(move || {
+                    let metric = connection.transaction::<_, anyhow::Error, _>(|connection| {
+                        RubricGrade::soft_delete_by_metric_id(metric_id).execute(connection)?;
+                        let rubric_grades = rubric_grading
+                            .grades
+                            .into_iter()
+                            .map(|criterion_grade| {
+                                NewRubricGrade::new(metric_id, grader_id.clone(), criterion_grade)
+                            })
+                            .collect::<Vec<NewRubricGrade>>();
+                        NewRubricGrade::batch_insert(rubric_grades).execute(connection)?;
+
+                        let update_metric = UpdateMetric {
+                            text_submission: None,
+                            score: Some(score),
+                        };
+                        grade_metric(
+                            connection,
+                            metric_id,
+                            &update_metric,
+                            grader_id,
+                            rubric_grading.comment,
+                        )
+                    })?;
+                    send_metric_score(&mut connection, &websocket_manager, &metric)?;
+
+                    Ok(metric)
+                })
+                .await??;
+
+                Ok(metric)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<RubricFeedback>")]
+pub struct GetRubricFeedback(pub Metric);
+
+impl Handler<GetRubricFeedback> for Database {
+    type Result = ResponseActFuture<Self, Result<RubricFeedback>>;
+
+    fn handle(&mut self, msg: GetRubricFeedback, _ctx: &mut Self::Context) -> Self::Result {
+        let metric = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let rubric_feedback = block(move || {
+                    let rubric = load_rubric_by_metric_key(
+                        &mut connection,
+                        metric.exercise_id,
+                        metric.sdl_key.clone(),
+                    )?
+                    .ok_or_else(|| anyhow!(RECORD_NOT_FOUND))?;
+                    let rubric_grades: Vec<RubricGrade> =
+                        RubricGrade::by_metric_id(metric.id).load(&mut connection)?;
+                    let grades = rubric_grades
+                        .into_iter()
+                        .map(|rubric_grade| rubric_grade.into())
+                        .collect::<Vec<RubricGradeFeedback>>();
+
+                    Ok(RubricFeedback {
+                        metric_id: metric.id,
+                        rubric,
+                        grades,
+                        score: metric.score,
+                        max_score: metric.max_score,
+                    })
+                })
+                .await??;
+
+                Ok(rubric_feedback)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,203 @@
+use super::Database;
+use crate::constants::NAIVEDATETIME_DEFAULT_VALUE;
+use crate::models::{helpers::uuid::Uuid, Deployment, NewScore, Score};
+use crate::schema::{condition_messages, metrics, penalties, scores};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{
+    sql_query,
+    sql_types::{Binary, Timestamp},
+    Connection, QueryDsl, RunQueryDsl,
+};
+use std::collections::HashSet;
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Score>>")]
+pub struct GetScoresByDeploymentId(pub Uuid);
+
+impl Handler<GetScoresByDeploymentId> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Score>>>;
+
+    fn handle(&mut self, msg: GetScoresByDeploymentId, _ctx: &mut Self::Context) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let scores = block(move || {
+                    let scores = Score::by_deployment_id(deployment_id)
+                        .order_by(scores::timestamp)
+                        .load(&mut connection)?;
+
+                    Ok(scores)
+                })
+                .await??;
+
+                Ok(scores)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Score>>")]
+pub struct ReplaceDeploymentScores(pub Uuid, pub Vec<NewScore>);
+
+impl Handler<ReplaceDeploymentScores> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Score>>>;
+
+    fn handle(&mut self, msg: ReplaceDeploymentScores, _ctx: &mut Self::Context) -> Self::Result {
+        let ReplaceDeploymentScores(deployment_id, new_scores) = msg;
+        let connection_result = self.get_shared_connection();
+
+        Box::pin(
+            async move {
+                let scores = block(move || {
+                    let mutex_connection = connection_result?;
+                    let mut connection = mutex_connection
+                        .lock()
+                        .map_err(|error| anyhow!("Error locking Mutex connection: {:?}", error))?;
+
+                    let scores =
+                        connection.transaction::<_, diesel::result::Error, _>(|connection| {
+                            Score::soft_delete_by_deployment_id(deployment_id)
+                                .execute(connection)?;
+                            for new_score in new_scores.iter() {
+                                new_score.create_insert_or_replace().execute(connection)?;
+                            }
+
+                            Score::by_deployment_id(deployment_id)
+                                .order_by(scores::timestamp)
+                                .load(connection)
+                        })?;
+
+                    Ok(scores)
+                })
+                .await??;
+
+                Ok(scores)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Score>>")]
+pub struct GetLatestScoresByDeploymentId(pub Uuid);
+
+impl Handler<GetLatestScoresByDeploymentId> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Score>>>;
+
+    fn handle(
+        &mut self,
+        msg: GetLatestScoresByDeploymentId,
+        _ctx: &mut Self::Context,
+    ) -> Self::Result {
+        let deployment_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let scores = block(move || {
+                    let scores = sql_query(
+                        r#"
+                        SELECT scores.*
+                        FROM scores
+                        WHERE scores.deployment_id = ?
+                        AND scores.deleted_at = ?
+                        AND NOT EXISTS (
+                            SELECT 1
+                            FROM scores AS newer_scores
+                            WHERE newer_scores.deployment_id = scores.deployment_id
+                            AND newer_scores.deleted_at = scores.deleted_at
+                            AND newer_scores.metric_key = scores.metric_key
+                            AND newer_scores.vm_name = scores.vm_name
+                            AND newer_scores.metric_id <=> scores.metric_id
+                            AND (
+                                newer_scores.timestamp > scores.timestamp
+                                OR (
+                                    newer_scores.timestamp = scores.timestamp
+                                    AND newer_scores.id > scores.id
+                                )
+                            )
+                        )
+                        "#,
+                    )
+                    .bind::<Binary, _>(deployment_id)
+                    .bind::<Timestamp, _>(*NAIVEDATETIME_DEFAULT_VALUE)
+                    .load::<Score>(&mut connection)?;
+
+                    Ok(scores)
+                })
+                .await??;
+
+                Ok(scores)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<Deployment>>")]
+pub struct GetUnprojectedDeployments;
+
+impl Handler<GetUnprojectedDeployments> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<Deployment>>>;
+
+    fn handle(&mut self, _: GetUnprojectedDeployments, _ctx: &mut Self::Context) -> Self::Result {
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let deployments = block(move || {
+                    let projected_deployment_ids: HashSet<Uuid> = scores::table
+                        .select(scores::deployment_id)
+                        .distinct()
+                        .load::<Uuid>(&mut connection)?
+                        .into_iter()
+                        .collect();
+                    let mut scored_deployment_ids: HashSet<Uuid> = condition_messages::table
+                        .select(condition_messages::deployment_id)
+                        .distinct()
+                        .load::<Uuid>(&mut connection)?
+                        .into_iter()
+                        .collect();
+                    scored_deployment_ids.extend(
+                        metrics::table
+                            .select(metrics::deployment_id)
+                            .distinct()
+                            .load::<Uuid>(&mut connection)?,
+                    );
+                    scored_deployment_ids.extend(
+                        penalties::table
+                            .select(penalties::deployment_id)
+                            .distinct()
+                            .load::<Uuid>(&mut connection)?,
+                    );
+
+                    let deployments: Vec<Deployment> = Deployment::all()
+                        .load::<Deployment>(&mut connection)?
+                        .into_iter()
+                        .filter(|deployment| {
+                            scored_deployment_ids.contains(&deployment.id)
+                                && !projected_deployment_ids.contains(&deployment.id)
+                        })
+                        .collect();
+
+                    Ok(deployments)
+                })
+                .await??;
+
+                Ok(deployments)
+            }
+            .into_actor(self),
+        )
+    }
+}
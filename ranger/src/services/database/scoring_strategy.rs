@@ -0,0 +1,107 @@
+use super::Database;
+use crate::models::{helpers::uuid::Uuid, NewScoringStrategy, ScoringStrategy};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{Connection, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<ScoringStrategy>")]
+pub struct CreateScoringStrategy(pub NewScoringStrategy);
+
+impl Handler<CreateScoringStrategy> for Database {
+    type Result = ResponseActFuture<Self, Result<ScoringStrategy>>;
+
+    fn handle(&mut self, msg: CreateScoringStrategy, _ctx: &mut Self::Context) -> Self::Result {
+        let new_scoring_strategy = msg.0;
+        let connection_result = self.get_shared_connection();
+
+        Box::pin(
+            async move {
+                let scoring_strategy = block(move || {
+                    let mutex_connection = connection_result?;
+                    let mut connection = mutex_connection
+                        .lock()
+                        .map_err(|error| anyhow!("Error locking Mutex connection: {:?}", error))?;
+
+                    let scoring_strategy =
+                        connection.transaction::<_, diesel::result::Error, _>(|connection| {
+                            ScoringStrategy::soft_delete_by_exercise_id_and_metric_key(
+                                new_scoring_strategy.exercise_id,
+                                new_scoring_strategy.metric_key.clone(),
+                            )
+                            .execute(connection)?;
+                            new_scoring_strategy.create_insert().execute(connection)?;
+
+                            ScoringStrategy::by_id(new_scoring_strategy.id).first(connection)
+                        })?;
+
+                    Ok(scoring_strategy)
+                })
+                .await??;
+
+                Ok(scoring_strategy)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<ScoringStrategy>>")]
+pub struct GetScoringStrategies(pub Uuid);
+
+impl Handler<GetScoringStrategies> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<ScoringStrategy>>>;
+
+    fn handle(&mut self, msg: GetScoringStrategies, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let scoring_strategies = block(move || {
+                    let scoring_strategies =
+                        ScoringStrategy::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(scoring_strategies)
+                })
+                .await??;
+
+                Ok(scoring_strategies)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteScoringStrategy(pub Uuid);
+
+impl Handler<DeleteScoringStrategy> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteScoringStrategy, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let scoring_strategy: ScoringStrategy =
+                        ScoringStrategy::by_id(id).first(&mut connection)?;
+                    scoring_strategy.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
@@ -0,0 +1,156 @@
+use super::Database;
+use crate::models::{
+    helpers::uuid::Uuid, Deployment, Event, NewSubmissionWindow, SubmissionPeriod, SubmissionWindow,
+};
+use actix::{Handler, Message, ResponseActFuture, WrapFuture};
+use actix_web::web::block;
+use anyhow::{anyhow, Ok, Result};
+use diesel::{Connection, OptionalExtension, RunQueryDsl};
+
+#[derive(Message)]
+#[rtype(result = "Result<SubmissionWindow>")]
+pub struct CreateSubmissionWindow(pub NewSubmissionWindow);
+
+impl Handler<CreateSubmissionWindow> for Database {
+    type Result = ResponseActFuture<Self, Result<SubmissionWindow>>;
+
+    fn handle(&mut self, msg: CreateSubmissionWindow, _ctx: &mut Self::Context) -> Self::Result {
+        let new_submission_window = msg.0;
+        let connection_result = self.get_shared_connection();
+
+        Box::pin(
+            async move {
+                let submission_window = block(move || {
+                    let mutex_connection = connection_result?;
+                    let mut connection = mutex_connection
+                        .lock()
+                        .map_err(|error| anyhow!("Error locking Mutex connection: {:?}", error))?;
+
+                    let submission_window =
+                        connection.transaction::<_, diesel::result::Error, _>(|connection| {
+                            SubmissionWindow::soft_delete_by_exercise_id_and_metric_key(
+                                new_submission_window.exercise_id,
+                                new_submission_window.metric_key.clone(),
+                            )
+                            .execute(connection)?;
+                            new_submission_window.create_insert().execute(connection)?;
+
+                            SubmissionWindow::by_id(new_submission_window.id).first(connection)
+                        })?;
+
+                    Ok(submission_window)
+                })
+                .await??;
+
+                Ok(submission_window)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<SubmissionWindow>>")]
+pub struct GetSubmissionWindows(pub Uuid);
+
+impl Handler<GetSubmissionWindows> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<SubmissionWindow>>>;
+
+    fn handle(&mut self, msg: GetSubmissionWindows, _ctx: &mut Self::Context) -> Self::Result {
+        let exercise_id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let submission_windows = block(move || {
+                    let submission_windows =
+                        SubmissionWindow::by_exercise_id(exercise_id).load(&mut connection)?;
+
+                    Ok(submission_windows)
+                })
+                .await??;
+
+                Ok(submission_windows)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Uuid>")]
+pub struct DeleteSubmissionWindow(pub Uuid);
+
+impl Handler<DeleteSubmissionWindow> for Database {
+    type Result = ResponseActFuture<Self, Result<Uuid>>;
+
+    fn handle(&mut self, msg: DeleteSubmissionWindow, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let id = block(move || {
+                    let submission_window: SubmissionWindow =
+                        SubmissionWindow::by_id(id).first(&mut connection)?;
+                    submission_window.soft_delete().execute(&mut connection)?;
+
+                    Ok(id)
+                })
+                .await??;
+
+                Ok(id)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Vec<SubmissionPeriod>>")]
+pub struct GetSubmissionPeriods(pub Deployment, pub Vec<String>);
+
+impl Handler<GetSubmissionPeriods> for Database {
+    type Result = ResponseActFuture<Self, Result<Vec<SubmissionPeriod>>>;
+
+    fn handle(&mut self, msg: GetSubmissionPeriods, _ctx: &mut Self::Context) -> Self::Result {
+        let GetSubmissionPeriods(deployment, metric_keys) = msg;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let submission_periods = block(move || {
+                    let events = Event::by_deployment_id(deployment.id).load(&mut connection)?;
+                    let submission_periods = metric_keys
+                        .into_iter()
+                        .map(|metric_key| {
+                            let submission_window =
+                                SubmissionWindow::by_exercise_id_and_metric_key(
+                                    deployment.exercise_id,
+                                    metric_key.clone(),
+                                )
+                                .first(&mut connection)
+                                .optional()?;
+
+                            Ok(SubmissionPeriod::new(
+                                metric_key,
+                                submission_window,
+                                &deployment,
+                                &events,
+                            ))
+                        })
+                        .collect::<Result<Vec<SubmissionPeriod>>>()?;
+
+                    Ok(submission_periods)
+                })
+                .await??;
+
+                Ok(submission_periods)
+            }
+            .into_actor(self),
+        )
+    }
+}
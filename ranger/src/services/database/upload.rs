@@ -1,12 +1,15 @@
-use super::Database;
-use crate::models::{
-    helpers::uuid::Uuid,
-    upload::{Artifact, NewArtifact},
+use super::{metric_submission::record_metric_submission, Database};
+use crate::{
+    models::{
+        helpers::uuid::Uuid,
+        upload::{Artifact, NewArtifact},
+    },
+    schema::artifacts,
 };
 use actix::{Handler, Message, ResponseActFuture, WrapFuture};
 use actix_web::web::block;
 use anyhow::{Ok, Result};
-use diesel::RunQueryDsl;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl};
 
 #[derive(Message)]
 #[rtype(result = "Result<Uuid>")]
@@ -26,6 +29,11 @@ impl Handler<UploadArtifact> for Database {
                     new_file
                         .create_insert_or_replace()
                         .execute(&mut connection)?;
+                    record_metric_submission(
+                        &mut connection,
+                        new_file.metric_id,
+                        Some(new_file.id),
+                    )?;
 
                     Ok(new_file.id)
                 })
@@ -52,7 +60,35 @@ impl Handler<GetArtifactByMetricId> for Database {
             async move {
                 let mut connection = connection_result?;
                 let artifact = block(move || {
-                    let artifact = Artifact::by_metric_id(metric_id).first(&mut connection)?;
+                    let artifact = Artifact::by_metric_id(metric_id)
+                        .order_by(artifacts::created_at.desc())
+                        .first(&mut connection)?;
+                    Ok(artifact)
+                })
+                .await??;
+                Ok(artifact)
+            }
+            .into_actor(self),
+        )
+    }
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Artifact>")]
+pub struct GetArtifact(pub Uuid);
+
+impl Handler<GetArtifact> for Database {
+    type Result = ResponseActFuture<Self, Result<Artifact>>;
+
+    fn handle(&mut self, msg: GetArtifact, _ctx: &mut Self::Context) -> Self::Result {
+        let id = msg.0;
+        let connection_result = self.get_connection();
+
+        Box::pin(
+            async move {
+                let mut connection = connection_result?;
+                let artifact = block(move || {
+                    let artifact = Artifact::by_id(id).first(&mut connection)?;
                     Ok(artifact)
                 })
                 .await??;
@@ -6,8 +6,8 @@ use super::{
 };
 use crate::services::{
     client::{
-        ConditionClient, DeploymentClientResponse, DeputyQueryClient, DeputyQueryDeploymentClient,
-        EventInfoClient, FeatureClient, InjectClient,
+        ConditionClient, CreateConditionStream, DeploymentClientResponse, DeputyQueryClient,
+        DeputyQueryDeploymentClient, EventInfoClient, FeatureClient, InjectClient,
     },
     deployer::DeployerConnections,
 };
@@ -17,7 +17,8 @@ use actix::{
 use anyhow::{anyhow, Ok, Result};
 use futures::future::try_join_all;
 use ranger_grpc::{
-    capabilities::DeployerType as GrpcDeployerType, DeputyStreamResponse, Package, Source,
+    capabilities::DeployerType as GrpcDeployerType, ConditionStreamResponse,
+    DeputyStreamResponse, Identifier, Package, Source,
 };
 use std::collections::HashMap;
 use tonic::Streaming;
@@ -34,6 +35,7 @@ impl Actor for DeployerDistribution {
 
 type ClientTuple = (Box<dyn DeploymentClient<Box<dyn DeploymentInfo>>>, String);
 type DeputyClientTuple = (Box<Addr<DeputyQueryClient>>, String);
+type ConditionClientTuple = (Addr<ConditionClient>, String);
 
 impl DeployerDistribution {
     fn book_best_deployer(
@@ -183,6 +185,25 @@ impl DeployerDistribution {
         ))
     }
 
+    fn get_condition_client(
+        &mut self,
+        potential_deployers: Vec<String>,
+    ) -> Result<ConditionClientTuple> {
+        let best_deployer =
+            self.book_best_deployer(potential_deployers, GrpcDeployerType::Condition)?;
+        let connections = self
+            .deployers
+            .get(&best_deployer)
+            .ok_or_else(|| anyhow!("No deployer found"))?;
+        Ok((
+            connections
+                .condition_client
+                .clone()
+                .ok_or_else(|| anyhow!("No condition deployer found"))?,
+            best_deployer,
+        ))
+    }
+
     pub async fn new(factory: Addr<DeployerFactory>, deployers: Vec<String>) -> Result<Self> {
         let deployers = try_join_all(deployers.iter().map(|deployer_name| async {
             let connections = factory
@@ -257,6 +278,34 @@ impl Handler<UnDeploy> for DeployerDistribution {
     }
 }
 
+#[derive(Message)]
+#[rtype(result = "Result<Streaming<ConditionStreamResponse>>")]
+pub struct ReconnectConditionStream(pub Identifier, pub Vec<String>);
+
+impl Handler<ReconnectConditionStream> for DeployerDistribution {
+    type Result = ResponseActFuture<Self, Result<Streaming<ConditionStreamResponse>>>;
+
+    fn handle(&mut self, msg: ReconnectConditionStream, _ctx: &mut Self::Context) -> Self::Result {
+        let identifier = msg.0;
+        let potential_deployers = msg.1;
+
+        let client_result = self.get_condition_client(potential_deployers);
+
+        Box::pin(
+            async move {
+                let (condition_client, best_deployer) = client_result?;
+                let stream = condition_client
+                    .send(CreateConditionStream(identifier))
+                    .await??;
+
+                Ok((stream, best_deployer))
+            }
+            .into_actor(self)
+            .map(Self::release_deployer_closure),
+        )
+    }
+}
+
 #[derive(Message)]
 #[rtype(result = "Result<Vec<Package>>")]
 pub struct DeputyPackageQueryByType(pub String, pub Vec<String>);
@@ -1,12 +1,15 @@
 use super::event::DeploymentEvent;
 use super::node::{NodeDeploymentInfo, NodeProperties};
 use super::Database;
-use crate::models::helpers::uuid::Uuid;
+use crate::constants::{
+    CONDITION_STREAM_SILENCE_INTERVAL_MULTIPLIER, CONDITION_STREAM_SILENCE_THRESHOLD,
+};
+use crate::models::helpers::{deployer_type::DeployerType, uuid::Uuid};
 use crate::models::{DeploymentElement, ElementStatus, Exercise};
 use crate::services::client::{ConditionResponse, ConditionStream};
 use crate::services::database::account::GetAccount;
 use crate::services::database::deployment::{CreateDeploymentElement, UpdateDeploymentElement};
-use crate::services::deployer::Deploy;
+use crate::services::deployer::{Deploy, UnDeploy};
 use crate::utilities::scenario::get_metric_by_condition;
 use crate::utilities::try_some;
 use crate::Addressor;
@@ -22,7 +25,7 @@ use ranger_grpc::{
 };
 use sdl_parser::{condition::Condition, metric::Metrics, node::NodeType, node::Role, Scenario};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 #[async_trait]
 pub trait DeployableConditions {
@@ -40,6 +43,16 @@ pub trait DeployableConditions {
         deployed_nodes: &[NodeDeploymentInfo],
     ) -> Result<()>;
 }
+#[async_trait]
+pub trait RemoveableConditions {
+    async fn undeploy_conditions(
+        &self,
+        addressor: &Addressor,
+        deployers: &[String],
+        exercise_id: &Uuid,
+    ) -> Result<()>;
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct ConditionProperties {
     pub name: String,
@@ -160,6 +173,82 @@ impl DeployableConditions for Scenario {
     }
 }
 
+#[async_trait]
+impl RemoveableConditions for Vec<DeploymentElement> {
+    async fn undeploy_conditions(
+        &self,
+        addressor: &Addressor,
+        deployers: &[String],
+        exercise_id: &Uuid,
+    ) -> Result<()> {
+        try_join_all(
+            self.iter()
+                .filter(|element| {
+                    element.deployer_type == DeployerType(GrpcDeployerType::Condition)
+                        && !matches!(
+                            element.status,
+                            ElementStatus::ConditionClosed
+                                | ElementStatus::Failed
+                                | ElementStatus::Removed
+                        )
+                })
+                .map(|element| async move {
+                    let mut element_update = element.clone();
+                    let handler_reference = try_some(
+                        element.handler_reference.clone(),
+                        "Condition handler reference not found",
+                    )?;
+
+                    debug!(
+                        "Closing condition '{condition_name}'",
+                        condition_name = element.scenario_reference
+                    );
+                    match addressor
+                        .distributor
+                        .send(UnDeploy(
+                            GrpcDeployerType::Condition,
+                            handler_reference,
+                            deployers.to_owned(),
+                        ))
+                        .await?
+                    {
+                        Result::Ok(_) => {
+                            element_update.status = ElementStatus::ConditionClosed;
+                            element_update.error_message = None;
+                            addressor
+                                .database
+                                .send(UpdateDeploymentElement(
+                                    exercise_id.to_owned(),
+                                    element_update,
+                                    true,
+                                ))
+                                .await??;
+                            Ok(())
+                        }
+                        Err(error) => {
+                            element_update.status = ElementStatus::RemoveFailed;
+                            element_update.error_message = Some(format!(
+                                "Handler returned an error while deleting a condition: {}",
+                                error
+                            ));
+                            addressor
+                                .database
+                                .send(UpdateDeploymentElement(
+                                    exercise_id.to_owned(),
+                                    element_update,
+                                    true,
+                                ))
+                                .await??;
+                            Err(error)
+                        }
+                    }
+                }),
+        )
+        .await?;
+        Ok(())
+    }
+}
+
 pub struct ConditionAggregator();
 
 impl ConditionAggregator {
@@ -295,6 +384,10 @@ impl Handler<DeployConditions> for ConditionAggregator {
                                             database_address: addressor.database.clone(),
                                             condition_stream,
                                             condition_metric,
+                                            deployers: deployers.to_owned(),
+                                            silence_threshold: get_condition_silence_threshold(
+                                                condition,
+                                            ),
                                         })
                                         .await??;
                                 }
@@ -333,6 +426,17 @@ impl Handler<DeployConditions> for ConditionAggregator {
     }
 }
 
+fn get_condition_silence_threshold(condition: &Condition) -> Duration {
+    let interval_threshold = Duration::from_secs(u64::from(
+        condition
+            .interval
+            .unwrap_or_default()
+            .saturating_mul(CONDITION_STREAM_SILENCE_INTERVAL_MULTIPLIER),
+    ));
+
+    CONDITION_STREAM_SILENCE_THRESHOLD.max(interval_threshold)
+}
+
 pub async fn create_condition_request(
     database_address: &Addr<Database>,
     virtual_machine_id: &str,
@@ -4,8 +4,8 @@ use super::Database;
 use crate::constants::{EVENT_POLLER_RETRY_DURATION, NAIVEDATETIME_DEFAULT_VALUE};
 use crate::models::{helpers::uuid::Uuid, Deployment, ElementStatus, Exercise};
 use crate::services::database::{
-    deployment::GetDeploymentElementByEventId,
-    event::{CreateEvent, UpdateEvent},
+    deployment::{GetDeployment, GetDeploymentElementByEventId},
+    event::{CreateEvent, GetEvent, UpdateEvent},
 };
 use crate::services::deployment::inject::InjectDeployment;
 use crate::utilities::{event::await_event_start, try_some};
@@ -151,6 +151,7 @@ async fn poll_event(
                         template_id: node_deployment_info.node_properties.template_id,
                         inject_key: inject_property.name.to_owned(),
                         inject: inject_property.inject.clone(),
+                        entity_selectors: inject_property.role.entities.clone().unwrap_or_default(),
                     };
 
                     let deployment_future = inject_deployment.deploy_inject();
@@ -338,6 +339,37 @@ impl Handler<StartPolling> for EventPoller {
                     debug!("Starting Polling for Event '{}'", event.name);
                     loop {
                         let current_time = Utc::now().naive_utc();
+                        let deployment_result = database_address
+                            .send(GetDeployment(event.deployment_id))
+                            .await?;
+                        match deployment_result {
+                            anyhow::Result::Ok(deployment) if deployment.end < current_time => {
+                                debug!(
+                                    "Deployment '{}' has ended, stopping polling for Event '{}'",
+                                    deployment.name, event.name
+                                );
+                                has_succeeded = false;
+                                break;
+                            }
+                            Err(_) => {
+                                debug!(
+                                    "Deployment for Event '{}' has been removed, stopping polling",
+                                    event.name
+                                );
+                                return Ok(false);
+                            }
+                            _ => {}
+                        }
+
+                        let current_event = database_address.send(GetEvent(event.id)).await??;
+                        if current_event.has_triggered {
+                            debug!("Event '{}' has been triggered manually", event.name);
+                            updated_event.has_triggered = true;
+                            updated_event.triggered_at = current_event.triggered_at;
+                            has_succeeded = true;
+                            break;
+                        }
+
                         let condition_deployment_elements = database_address
                             .send(GetDeploymentElementByEventId(event.id, true))
                             .await??;
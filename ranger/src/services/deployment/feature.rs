@@ -1,8 +1,9 @@
 use super::node::NodeProperties;
 use crate::models::helpers::uuid::Uuid;
-use crate::models::{DeploymentElement, ElementStatus, Exercise};
+use crate::models::{DeploymentElement, DeploymentSecret, ElementStatus, Exercise};
 use crate::services::database::account::GetAccount;
 use crate::services::database::deployment::{CreateDeploymentElement, UpdateDeploymentElement};
+use crate::services::database::deployment_secret::GetDeploymentSecrets;
 use crate::services::deployer::Deploy;
 use crate::services::scheduler::CreateFeatureDeploymentSchedule;
 use crate::Addressor;
@@ -77,6 +78,11 @@ impl DeployableNodeFeatures for (Addressor, Vec<String>, Scenario, Uuid, &NodePr
                 node.clone(),
             ))
             .await??;
+        let deployment_secrets = addressor
+            .database
+            .send(GetDeploymentSecrets(deployment_element.deployment_id))
+            .await??;
+        let deployment_secrets = &deployment_secrets;
 
         for tranche in deployment_schedule.iter() {
             try_join_all(
@@ -129,6 +135,12 @@ impl DeployableNodeFeatures for (Addressor, Vec<String>, Scenario, Uuid, &NodePr
                             .send(GetAccount(*template_id, role.username.to_owned()))
                             .await??;
 
+                        let mut environment = feature.environment.clone().unwrap_or_default();
+                        environment.extend(DeploymentSecret::get_environment(
+                            deployment_secrets,
+                            &role.entities.clone().unwrap_or_default(),
+                        ));
+
                         let feature_deployment = Box::new(GrpcFeature {
                             name: feature_name.to_owned(),
                             virtual_machine_id: virtual_machine_id_string,
@@ -142,7 +154,7 @@ impl DeployableNodeFeatures for (Addressor, Vec<String>, Scenario, Uuid, &NodePr
                                 name: feature_source.name,
                                 version: feature_source.version,
                             }),
-                            environment: feature.environment.clone().unwrap_or_default(),
+                            environment,
                         });
 
                         {
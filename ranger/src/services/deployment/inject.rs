@@ -1,7 +1,8 @@
 use crate::models::helpers::uuid::Uuid;
-use crate::models::{DeploymentElement, ElementStatus};
+use crate::models::{DeploymentElement, DeploymentSecret, ElementStatus};
 use crate::services::database::account::GetAccount;
 use crate::services::database::deployment::{CreateDeploymentElement, UpdateDeploymentElement};
+use crate::services::database::deployment_secret::GetDeploymentSecrets;
 use crate::services::deployer::Deploy;
 use crate::utilities::try_some;
 use crate::Addressor;
@@ -33,6 +34,7 @@ pub struct InjectDeployment {
     pub template_id: Uuid,
     pub inject_key: String,
     pub inject: Inject,
+    pub entity_selectors: Vec<String>,
 }
 
 #[async_trait]
@@ -51,6 +53,7 @@ impl DeployableInject for InjectDeployment {
             template_id,
             inject_key,
             inject,
+            entity_selectors,
         } = self;
 
         debug!(
@@ -70,6 +73,16 @@ impl DeployableInject for InjectDeployment {
             .send(GetAccount(template_id, username.to_owned()))
             .await??;
 
+        let deployment_secrets = addressor
+            .database
+            .send(GetDeploymentSecrets(deployment_element.deployment_id))
+            .await??;
+        let mut environment = inject.environment.clone().unwrap_or_default();
+        environment.extend(DeploymentSecret::get_environment(
+            &deployment_secrets,
+            &entity_selectors,
+        ));
+
         let mut inject_deployment_element = addressor
             .database
             .send(CreateDeploymentElement(
@@ -98,7 +111,7 @@ impl DeployableInject for InjectDeployment {
                 private_key: template_account.private_key.unwrap_or_default(),
             }),
             to_entities: inject.to_entities.clone().unwrap_or_default(),
-            environment: inject.environment.clone().unwrap_or_default(),
+            environment,
         });
 
         {
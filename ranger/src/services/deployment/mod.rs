@@ -6,8 +6,11 @@ mod inject;
 pub mod node;
 mod template;
 
-use self::node::RemoveableNodes;
-use super::database::{deployment::GetDeploymentElementByDeploymentId, Database};
+use self::{condition::RemoveableConditions, node::RemoveableNodes};
+use super::database::{
+    deployment::GetDeploymentElementByDeploymentId, deployment_secret::GenerateDeploymentSecrets,
+    Database,
+};
 use crate::{
     models::{helpers::uuid::Uuid, Deployment, Exercise},
     services::deployment::{
@@ -20,7 +23,7 @@ use crate::{
 use actix::{Actor, ActorFutureExt, Context, Handler, Message, ResponseActFuture, WrapFuture};
 use anyhow::{anyhow, Ok, Result};
 use log::{error, info};
-use sdl_parser::Scenario;
+use sdl_parser::{entity::Flatten, Scenario};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -57,6 +60,19 @@ impl DeploymentManager {
             .deploy_nodes(addressor, exercise, deployment, deployers)
             .await?;
 
+        let entity_selectors = scenario
+            .entities
+            .clone()
+            .map(|entities| entities.flatten().into_keys().collect())
+            .unwrap_or_default();
+        addressor
+            .database
+            .send(GenerateDeploymentSecrets(
+                deployment.clone(),
+                entity_selectors,
+            ))
+            .await??;
+
         scenario
             .deploy_scenario_features(addressor, exercise, deployers, &deployed_nodes)
             .await?;
@@ -150,6 +166,17 @@ impl Handler<RemoveDeployment> for DeploymentManager {
                     .database
                     .send(GetDeploymentElementByDeploymentId(deployment.id, false))
                     .await??;
+                let undeploy_conditions_result = deployment_elements
+                    .undeploy_conditions(&addressor, &deployers, &exercise_id)
+                    .await;
+
+                if let Err(error) = &undeploy_conditions_result {
+                    error!(
+                        "Error closing conditions for deployment '{deplyoment_name}': '{error}'",
+                        deplyoment_name = &deployment.name
+                    );
+                }
+
                 let undeploy_result = deployment_elements
                     .undeploy_nodes(&addressor, &deployers, &exercise_id)
                     .await;
@@ -0,0 +1,266 @@
+use crate::constants::{
+    JSON_WEB_KEY_SET_MAXIMUM_AGE, JSON_WEB_KEY_SET_MINIMUM_REFRESH_INTERVAL,
+    JSON_WEB_KEY_SET_REQUEST_TIMEOUT,
+};
+use actix::{Actor, ActorFutureExt, Context, Handler, Message, ResponseActFuture, WrapFuture};
+use anyhow::{anyhow, Ok, Result};
+use futures::future::{FutureExt, LocalBoxFuture, Shared};
+use jsonwebtoken::jwk::{Jwk, JwkSet};
+use log::debug;
+use reqwest::Client;
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Deserialize)]
+struct OidcDiscoveryDocument {
+    jwks_uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum JsonWebKeySource {
+    Discovery(String),
+    KeySet(String),
+}
+
+impl JsonWebKeySource {
+    async fn fetch_key_set(&self) -> Result<JwkSet> {
+        let client = Client::builder()
+            .timeout(JSON_WEB_KEY_SET_REQUEST_TIMEOUT)
+            .build()?;
+        let jwks_uri = match self {
+            Self::Discovery(issuer_url) => {
+                client
+                    .get(format!(
+                        "{}/.well-known/openid-configuration",
+                        issuer_url.trim_end_matches('/')
+                    ))
+                    .send()
+                    .await?
+                    .error_for_status()?
+                    .json::<OidcDiscoveryDocument>()
+                    .await?
+                    .jwks_uri
+            }
+            Self::KeySet(jwks_uri) => jwks_uri.clone(),
+        };
+
+        Ok(client
+            .get(jwks_uri)
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<JwkSet>()
+            .await?)
+    }
+}
+
+type KeySetRefresh = Shared<LocalBoxFuture<'static, Result<JwkSet, String>>>;
+
+#[derive(Debug, PartialEq)]
+enum CachedKeyLookup {
+    Found(Jwk),
+    Unknown,
+    Refresh,
+}
+
+struct CachedKeySet {
+    key_set: JwkSet,
+    refreshed_at: Instant,
+}
+
+impl CachedKeySet {
+    fn lookup(&self, key_id: Option<&str>, key_set_age: Duration) -> CachedKeyLookup {
+        if key_set_age < JSON_WEB_KEY_SET_MAXIMUM_AGE {
+            if let Some(key) = JsonWebKeyStore::find_key(&self.key_set, key_id) {
+                return CachedKeyLookup::Found(key);
+            }
+        }
+        match key_set_age < JSON_WEB_KEY_SET_MINIMUM_REFRESH_INTERVAL {
+            true => CachedKeyLookup::Unknown,
+            false => CachedKeyLookup::Refresh,
+        }
+    }
+}
+
+pub struct JsonWebKeyStore {
+    key_sets: HashMap<JsonWebKeySource, CachedKeySet>,
+    refreshes: HashMap<JsonWebKeySource, KeySetRefresh>,
+}
+
+impl JsonWebKeyStore {
+    pub fn new() -> Self {
+        Self {
+            key_sets: HashMap::new(),
+            refreshes: HashMap::new(),
+        }
+    }
+
+    fn find_key(key_set: &JwkSet, key_id: Option<&str>) -> Option<Jwk> {
+        match key_id {
+            Some(key_id) => key_set.find(key_id).cloned(),
+            None => match key_set.keys.as_slice() {
+                [key] => Some(key.clone()),
+                _ => None,
+            },
+        }
+    }
+
+    fn get_refresh(&mut self, source: &JsonWebKeySource) -> KeySetRefresh {
+        self.refreshes
+            .entry(source.clone())
+            .or_insert_with(|| {
+                let source = source.clone();
+                async move {
+                    debug!("Refreshing json web key set from {source:?}");
+                    source
+                        .fetch_key_set()
+                        .await
+                        .map_err(|error| error.to_string())
+                }
+                .boxed_local()
+                .shared()
+            })
+            .clone()
+    }
+}
+
+impl Default for JsonWebKeyStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Actor for JsonWebKeyStore {
+    type Context = Context<Self>;
+}
+
+#[derive(Message)]
+#[rtype(result = "Result<Jwk>")]
+pub struct GetJsonWebKey(pub JsonWebKeySource, pub Option<String>);
+
+impl Handler<GetJsonWebKey> for JsonWebKeyStore {
+    type Result = ResponseActFuture<Self, Result<Jwk>>;
+
+    fn handle(&mut self, msg: GetJsonWebKey, _ctx: &mut Self::Context) -> Self::Result {
+        let GetJsonWebKey(source, key_id) = msg;
+
+        if let Some(cached_key_set) = self.key_sets.get(&source) {
+            match cached_key_set.lookup(key_id.as_deref(), cached_key_set.refreshed_at.elapsed()) {
+                CachedKeyLookup::Found(key) => {
+                    return Box::pin(async move { Ok(key) }.into_actor(self));
+                }
+                CachedKeyLookup::Unknown => {
+                    return Box::pin(
+                        async move { Err(anyhow!("Unknown key id {key_id:?}")) }.into_actor(self),
+                    );
+                }
+                CachedKeyLookup::Refresh => {}
+            }
+        }
+
+        let refresh = self.get_refresh(&source);
+        Box::pin(
+            async move { refresh.await }
+                .into_actor(self)
+                .map(move |result, actor, _| {
+                    let is_own_refresh = actor.refreshes.remove(&source).is_some();
+                    let key_set = result.map_err(|error| anyhow!(error))?;
+                    let key = Self::find_key(&key_set, key_id.as_deref());
+                    if is_own_refresh {
+                        actor.key_sets.insert(
+                            source,
+                            CachedKeySet {
+                                key_set,
+                                refreshed_at: Instant::now(),
+                            },
+                        );
+                    }
+
+                    key.ok_or_else(|| anyhow!("Unknown key id {key_id:?}"))
+                }),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CachedKeyLookup, CachedKeySet, JsonWebKeyStore};
+    use crate::constants::{
+        JSON_WEB_KEY_SET_MAXIMUM_AGE, JSON_WEB_KEY_SET_MINIMUM_REFRESH_INTERVAL,
+    };
+    use jsonwebtoken::jwk::JwkSet;
+    use serde_json::json;
+    use std::time::{Duration, Instant};
+
+    fn create_key_set(key_ids: &[&str]) -> JwkSet {
+        serde_json::from_value(json!({
+            "keys": key_ids
+                .iter()
+                .map(|key_id| json!({ "kty": "RSA", "kid": key_id, "n": "AQAB", "e": "AQAB" }))
+                .collect::<Vec<_>>(),
+        }))
+        .unwrap()
+    }
+
+    fn create_cached_key_set(key_ids: &[&str]) -> CachedKeySet {
+        CachedKeySet {
+            key_set: create_key_set(key_ids),
+            refreshed_at: Instant::now(),
+        }
+    }
+
+    #[test]
+    fn finds_keys_by_key_id() {
+        let key_set = create_key_set(&["first", "second"]);
+        let key = JsonWebKeyStore::find_key(&key_set, Some("second")).unwrap();
+
+        assert_eq!(key.common.key_id.as_deref(), Some("second"));
+        assert!(JsonWebKeyStore::find_key(&key_set, Some("third")).is_none());
+    }
+
+    #[test]
+    fn only_a_single_key_matches_without_key_id() {
+        let single_key_set = create_key_set(&["first"]);
+        let multiple_key_set = create_key_set(&["first", "second"]);
+
+        assert!(JsonWebKeyStore::find_key(&single_key_set, None).is_some());
+        assert!(JsonWebKeyStore::find_key(&multiple_key_set, None).is_none());
+    }
+
+    #[test]
+    fn fresh_key_sets_serve_known_keys() {
+        let cached_key_set = create_cached_key_set(&["first"]);
+
+        assert!(matches!(
+            cached_key_set.lookup(Some("first"), Duration::ZERO),
+            CachedKeyLookup::Found(_)
+        ));
+    }
+
+    #[test]
+    fn unknown_keys_wait_for_the_minimum_refresh_interval() {
+        let cached_key_set = create_cached_key_set(&["first"]);
+
+        assert_eq!(
+            cached_key_set.lookup(Some("second"), Duration::ZERO),
+            CachedKeyLookup::Unknown
+        );
+        assert_eq!(
+            cached_key_set.lookup(Some("second"), JSON_WEB_KEY_SET_MINIMUM_REFRESH_INTERVAL),
+            CachedKeyLookup::Refresh
+        );
+    }
+
+    #[test]
+    fn expired_key_sets_are_refreshed() {
+        let cached_key_set = create_cached_key_set(&["first"]);
+
+        assert_eq!(
+            cached_key_set.lookup(Some("first"), JSON_WEB_KEY_SET_MAXIMUM_AGE),
+            CachedKeyLookup::Refresh
+        );
+    }
+}
@@ -0,0 +1,193 @@
+use super::{
+    key_store::{JsonWebKeySource, JsonWebKeyStore},
+    validate_json_web_token, GroupAdapter, IdentityGroup, IdentityProvider, IdentityUser,
+};
+use crate::{
+    configuration::{KeycloakAdminConfiguration, KeycloakConfiguration},
+    constants::default_role_claim_path,
+    errors::RangerError,
+    middleware::authentication::Token,
+};
+use actix::Addr;
+use async_trait::async_trait;
+use keycloak::{
+    types::{RoleRepresentation, UserRepresentation},
+    KeycloakAdmin, KeycloakServiceAccountAdminTokenRetriever,
+};
+use log::error;
+
+impl From<RoleRepresentation> for IdentityGroup {
+    fn from(role: RoleRepresentation) -> Self {
+        Self {
+            id: role.id,
+            name: role.name,
+            description: role.description,
+        }
+    }
+}
+
+impl From<UserRepresentation> for IdentityUser {
+    fn from(user: UserRepresentation) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            email: user.email,
+        }
+    }
+}
+
+pub struct KeycloakGroupAdapter {
+    service_user: KeycloakAdmin<KeycloakServiceAccountAdminTokenRetriever>,
+    realm: String,
+    client_id: String,
+}
+
+impl KeycloakGroupAdapter {
+    pub async fn try_new(
+        keycloak_configuration: &KeycloakAdminConfiguration,
+    ) -> Result<Self, RangerError> {
+        let client = reqwest::Client::new();
+        let token = KeycloakServiceAccountAdminTokenRetriever::create_with_custom_realm(
+            &keycloak_configuration.client_id,
+            &keycloak_configuration.client_secret,
+            &keycloak_configuration.realm,
+            client,
+        );
+        let client = reqwest::Client::new();
+        let service_user = KeycloakAdmin::new(&keycloak_configuration.base_url, token, client);
+        let keycloak_clients = service_user
+            .realm_clients_get(
+                &keycloak_configuration.realm,
+                Some(keycloak_configuration.client_id.clone()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .await
+            .map_err(|error| {
+                error!("Failed to get keycloak clients: {error}");
+                RangerError::KeycloakQueryFailed
+            })?;
+
+        let keycloak_client_id = keycloak_clients
+            .first()
+            .ok_or_else(|| {
+                error!("Failed to get keycloak client");
+                RangerError::KeycloakQueryFailed
+            })?
+            .clone()
+            .id
+            .ok_or_else(|| {
+                error!("Failed to get keycloak client id");
+                RangerError::KeycloakQueryFailed
+            })?;
+        Ok(Self {
+            service_user,
+            realm: keycloak_configuration.realm.clone(),
+            client_id: keycloak_client_id,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl GroupAdapter for KeycloakGroupAdapter {
+    async fn get_groups(&self) -> Result<Vec<IdentityGroup>, RangerError> {
+        let roles = self
+            .service_user
+            .realm_clients_with_id_roles_get(&self.realm, &self.client_id, None, None, None, None)
+            .await
+            .map_err(|error| {
+                error!("Failed to get keycloak roles: {error}");
+                RangerError::KeycloakQueryFailed
+            })?;
+
+        Ok(roles.into_iter().map(IdentityGroup::from).collect())
+    }
+
+    async fn get_group_users(&self, group_name: &str) -> Result<Vec<IdentityUser>, RangerError> {
+        let users = self
+            .service_user
+            .realm_clients_with_id_roles_with_role_name_users_get(
+                &self.realm,
+                &self.client_id,
+                group_name,
+                None,
+                None,
+            )
+            .await
+            .map_err(|error| {
+                error!("Failed to get keycloak users: {error}");
+                RangerError::KeycloakQueryFailed
+            })?;
+
+        Ok(users.into_iter().map(IdentityUser::from).collect())
+    }
+}
+
+pub struct KeycloakIdentityProvider {
+    json_web_key_source: JsonWebKeySource,
+    issuer_url: String,
+    audience: String,
+    group_adapter: KeycloakGroupAdapter,
+    json_web_key_store_address: Addr<JsonWebKeyStore>,
+}
+
+impl KeycloakIdentityProvider {
+    pub async fn try_new(
+        keycloak_configuration: &KeycloakConfiguration,
+        json_web_key_store_address: Addr<JsonWebKeyStore>,
+    ) -> Result<Self, RangerError> {
+        let group_adapter = KeycloakGroupAdapter::try_new(&KeycloakAdminConfiguration::from(
+            keycloak_configuration,
+        ))
+        .await?;
+        let realm_url = format!(
+            "{}/realms/{}",
+            keycloak_configuration.base_url.trim_end_matches('/'),
+            keycloak_configuration.realm
+        );
+
+        Ok(Self {
+            json_web_key_source: JsonWebKeySource::KeySet(format!(
+                "{realm_url}/protocol/openid-connect/certs"
+            )),
+            issuer_url: keycloak_configuration
+                .issuer_url
+                .clone()
+                .unwrap_or(realm_url),
+            audience: keycloak_configuration.audience.clone(),
+            group_adapter,
+            json_web_key_store_address,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl IdentityProvider for KeycloakIdentityProvider {
+    async fn validate_token(&self, token: &str) -> Result<Token, RangerError> {
+        validate_json_web_token(
+            &self.json_web_key_store_address,
+            self.json_web_key_source.clone(),
+            token,
+            &self.issuer_url,
+            Some(&self.audience),
+        )
+        .await
+    }
+
+    fn role_claim_path(&self) -> &str {
+        default_role_claim_path()
+    }
+
+    fn groups_claim_path(&self) -> Option<&str> {
+        None
+    }
+
+    fn group_adapter(&self) -> Option<&dyn GroupAdapter> {
+        Some(&self.group_adapter)
+    }
+}
@@ -0,0 +1,127 @@
+pub mod key_store;
+pub mod keycloak;
+pub mod oidc;
+
+use crate::{
+    errors::RangerError,
+    middleware::authentication::{Token, User},
+    utilities::create_mailbox_error_handler,
+    AppState,
+};
+use actix::Addr;
+use async_trait::async_trait;
+use jsonwebtoken::decode_header;
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::rc::Rc;
+
+use self::{
+    key_store::{GetJsonWebKey, JsonWebKeySource, JsonWebKeyStore},
+    keycloak::KeycloakIdentityProvider,
+    oidc::OidcIdentityProvider,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityUser {
+    pub id: Option<String>,
+    pub username: Option<String>,
+    pub first_name: Option<String>,
+    pub last_name: Option<String>,
+    pub email: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityGroup {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[async_trait(?Send)]
+pub trait GroupAdapter {
+    async fn get_groups(&self) -> Result<Vec<IdentityGroup>, RangerError>;
+
+    async fn get_group_users(&self, group_name: &str) -> Result<Vec<IdentityUser>, RangerError>;
+}
+
+#[async_trait(?Send)]
+pub trait IdentityProvider {
+    async fn validate_token(&self, token: &str) -> Result<Token, RangerError>;
+
+    fn role_claim_path(&self) -> &str;
+
+    fn groups_claim_path(&self) -> Option<&str>;
+
+    fn group_adapter(&self) -> Option<&dyn GroupAdapter>;
+
+    async fn get_groups(&self) -> Result<Vec<IdentityGroup>, RangerError> {
+        match self.group_adapter() {
+            Some(group_adapter) => group_adapter.get_groups().await,
+            None => Err(RangerError::GroupListingNotSupported),
+        }
+    }
+
+    async fn get_group_users(&self, group_name: &str) -> Result<Vec<IdentityUser>, RangerError> {
+        match self.group_adapter() {
+            Some(group_adapter) => group_adapter.get_group_users(group_name).await,
+            None => Err(RangerError::GroupListingNotSupported),
+        }
+    }
+
+    async fn is_group_member(&self, group_name: &str, user: &User) -> Result<bool, RangerError> {
+        match (self.group_adapter(), self.groups_claim_path()) {
+            (Some(group_adapter), _) => Ok(group_adapter
+                .get_group_users(group_name)
+                .await?
+                .iter()
+                .any(|group_user| group_user.id.as_ref() == Some(&user.id))),
+            (None, Some(_)) => Ok(user.groups.iter().any(|group| group == group_name)),
+            (None, None) => Err(RangerError::GroupListingNotSupported),
+        }
+    }
+}
+
+async fn validate_json_web_token(
+    json_web_key_store_address: &Addr<JsonWebKeyStore>,
+    json_web_key_source: JsonWebKeySource,
+    token: &str,
+    issuer: &str,
+    audience: Option<&str>,
+) -> Result<Token, RangerError> {
+    let header = decode_header(token).map_err(|error| {
+        error!("Failed to decode JWT header: {error}");
+        RangerError::NotAuthorized
+    })?;
+    let json_web_key = json_web_key_store_address
+        .send(GetJsonWebKey(json_web_key_source, header.kid))
+        .await
+        .map_err(create_mailbox_error_handler("Json web key store"))?
+        .map_err(|error| {
+            error!("Failed to get json web key: {error}");
+            RangerError::NotAuthorized
+        })?;
+
+    Token::try_new(token, &json_web_key, issuer, audience)
+}
+
+pub async fn create_identity_provider(
+    app_state: &AppState,
+) -> Result<Rc<dyn IdentityProvider>, RangerError> {
+    let configuration = &app_state.configuration;
+    let json_web_key_store_address = app_state.json_web_key_store_address.clone();
+    match (&configuration.oidc, &configuration.keycloak) {
+        (Some(oidc_configuration), _) => Ok(Rc::new(
+            OidcIdentityProvider::try_new(oidc_configuration, json_web_key_store_address).await?,
+        )),
+        (None, Some(keycloak_configuration)) => Ok(Rc::new(
+            KeycloakIdentityProvider::try_new(keycloak_configuration, json_web_key_store_address)
+                .await?,
+        )),
+        (None, None) => {
+            error!("Neither oidc nor keycloak is configured");
+            Err(RangerError::IdentityProviderNotConfigured)
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use super::{
+    key_store::{JsonWebKeySource, JsonWebKeyStore},
+    keycloak::KeycloakGroupAdapter,
+    validate_json_web_token, GroupAdapter, IdentityProvider,
+};
+use crate::{
+    configuration::{GroupAdapterConfiguration, OidcConfiguration},
+    errors::RangerError,
+    middleware::authentication::Token,
+};
+use actix::Addr;
+use async_trait::async_trait;
+
+pub struct OidcIdentityProvider {
+    configuration: OidcConfiguration,
+    group_adapter: Option<Box<dyn GroupAdapter>>,
+    json_web_key_store_address: Addr<JsonWebKeyStore>,
+}
+
+impl OidcIdentityProvider {
+    pub async fn try_new(
+        oidc_configuration: &OidcConfiguration,
+        json_web_key_store_address: Addr<JsonWebKeyStore>,
+    ) -> Result<Self, RangerError> {
+        let group_adapter: Option<Box<dyn GroupAdapter>> = match &oidc_configuration.group_adapter {
+            Some(GroupAdapterConfiguration::Keycloak(keycloak_configuration)) => Some(Box::new(
+                KeycloakGroupAdapter::try_new(keycloak_configuration).await?,
+            )),
+            None => None,
+        };
+
+        Ok(Self {
+            configuration: oidc_configuration.clone(),
+            group_adapter,
+            json_web_key_store_address,
+        })
+    }
+}
+
+#[async_trait(?Send)]
+impl IdentityProvider for OidcIdentityProvider {
+    async fn validate_token(&self, token: &str) -> Result<Token, RangerError> {
+        validate_json_web_token(
+            &self.json_web_key_store_address,
+            JsonWebKeySource::Discovery(self.configuration.issuer_url.clone()),
+            token,
+            &self.configuration.issuer_url,
+            Some(
+                self.configuration
+                    .audience
+                    .as_deref()
+                    .unwrap_or(&self.configuration.client_id),
+            ),
+        )
+        .await
+    }
+
+    fn role_claim_path(&self) -> &str {
+        &self.configuration.role_claim_path
+    }
+
+    fn groups_claim_path(&self) -> Option<&str> {
+        self.configuration.groups_claim_path.as_deref()
+    }
+
+    fn group_adapter(&self) -> Option<&dyn GroupAdapter> {
+        self.group_adapter.as_deref()
+    }
+}
@@ -2,6 +2,7 @@ mod client;
 pub mod database;
 pub mod deployer;
 pub mod deployment;
+pub mod identity;
 pub mod mailer;
 pub mod scheduler;
 pub mod websocket;
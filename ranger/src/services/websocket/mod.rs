@@ -3,7 +3,7 @@ mod logger;
 
 use crate::models::{
     helpers::{uuid::Uuid, websocket_wrapper::WebsocketWrapper},
-    Deployment, DeploymentElement, Event, Score, UpdateExercise,
+    Deployment, DeploymentElement, EvaluationUpdate, Event, Score, UpdateExercise,
 };
 use actix::{Actor, Context, Handler, Message, Recipient};
 use anyhow::{anyhow, Result};
@@ -222,6 +222,24 @@ impl Handler<SocketScoring> for WebSocketManager {
     }
 }
 
+#[derive(Message)]
+#[rtype(result = "Result<()>")]
+pub struct SocketEvaluation(pub Uuid, pub WebsocketWrapper<EvaluationUpdate>);
+
+impl Handler<SocketEvaluation> for WebSocketManager {
+    type Result = Result<()>;
+
+    fn handle(&mut self, msg: SocketEvaluation, _: &mut Context<Self>) -> Self::Result {
+        let SocketEvaluation(exercise_uuid, evaluation) = msg;
+        let targets = self.get_exercise_targets(exercise_uuid);
+        for target in targets {
+            target.do_send(WebsocketStringMessage(serde_json::to_string(&evaluation)?));
+        }
+
+        Ok(())
+    }
+}
+
 #[derive(Message)]
 #[rtype(result = "Result<()>")]
 pub struct SocketEvent(pub Uuid, pub WebsocketWrapper<Event>);
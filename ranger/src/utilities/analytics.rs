@@ -0,0 +1,56 @@
+use crate::{
+    models::{AnalyticsMetric, Deployment, DeploymentAnalyticsData},
+    services::database::{
+        event::GetEventsByDeploymentId, score::GetLatestScoresByDeploymentId, Database,
+    },
+    utilities::evaluation::calculate_entity_evaluations,
+};
+use actix::Addr;
+use anyhow::Result;
+use bigdecimal::BigDecimal;
+use log::error;
+use sdl_parser::parse_sdl;
+
+/// Returns `None` for deployments whose SDL no longer parses so a single broken
+/// deployment does not hide the analytics of the rest of the exercise.
+pub async fn get_deployment_analytics_data(
+    database_address: &Addr<Database>,
+    deployment: &Deployment,
+) -> Result<Option<DeploymentAnalyticsData>> {
+    let scenario = match parse_sdl(&deployment.sdl_schema) {
+        Ok(scenario) => scenario,
+        Err(error) => {
+            error!(
+                "Skipping analytics for deployment {}, failed to parse sdl: {error}",
+                deployment.id
+            );
+            return Ok(None);
+        }
+    };
+    let latest_scores = database_address
+        .send(GetLatestScoresByDeploymentId(deployment.id))
+        .await??;
+    let events = database_address
+        .send(GetEventsByDeploymentId(deployment.id))
+        .await??;
+
+    let metrics = scenario
+        .metrics
+        .iter()
+        .flatten()
+        .map(|(metric_key, metric)| AnalyticsMetric {
+            key: metric_key.to_owned(),
+            name: metric.name.clone(),
+            max_score: BigDecimal::from(metric.max_score),
+        })
+        .collect::<Vec<AnalyticsMetric>>();
+
+    Ok(Some(DeploymentAnalyticsData {
+        deployment_id: deployment.id,
+        deployment_start: deployment.start,
+        metrics,
+        evaluations: calculate_entity_evaluations(&scenario, &latest_scores),
+        latest_scores,
+        events,
+    }))
+}
@@ -0,0 +1,137 @@
+use crate::{
+    errors::RangerError,
+    models::{
+        Certificate, CertificateRecipient, Deployment, DeploymentEvaluation,
+        IssueCertificatesResource, Participant,
+    },
+    services::{
+        database::participant::GetParticipants,
+        identity::{IdentityProvider, IdentityUser},
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+    AppState,
+};
+
+pub type ParticipantUser = (Participant, Option<IdentityUser>);
+
+pub async fn get_participant_users(
+    app_state: &AppState,
+    identity_provider: &dyn IdentityProvider,
+    deployment: &Deployment,
+) -> Result<Vec<ParticipantUser>, RangerError> {
+    let participants = app_state
+        .database_address
+        .send(GetParticipants(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get participants"))?;
+    let users = match &deployment.group_name {
+        Some(group_name) => match identity_provider.get_group_users(group_name).await {
+            Ok(users) => users,
+            Err(RangerError::GroupListingNotSupported) => vec![],
+            Err(error) => return Err(error),
+        },
+        None => vec![],
+    };
+
+    Ok(participants
+        .into_iter()
+        .map(|participant| {
+            let user = users
+                .iter()
+                .find(|user| user.id.as_ref() == Some(&participant.user_id))
+                .cloned();
+            (participant, user)
+        })
+        .collect())
+}
+
+fn get_user_display_name(participant: &Participant, user: Option<&IdentityUser>) -> String {
+    let full_name = user
+        .map(|user| {
+            [user.first_name.as_deref(), user.last_name.as_deref()]
+                .into_iter()
+                .flatten()
+                .filter(|name| !name.trim().is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .unwrap_or_default();
+
+    match full_name.is_empty() {
+        false => full_name,
+        true => user
+            .and_then(|user| user.username.clone())
+            .unwrap_or_else(|| participant.user_id.clone()),
+    }
+}
+
+pub fn get_certificate_recipients(
+    evaluation: &DeploymentEvaluation,
+    participant_users: &[ParticipantUser],
+    issue_certificates: &IssueCertificatesResource,
+) -> Vec<CertificateRecipient> {
+    evaluation
+        .entities
+        .iter()
+        .filter(|entity_evaluation| entity_evaluation.passed)
+        .filter(|entity_evaluation| {
+            issue_certificates
+                .entity_selectors
+                .as_ref()
+                .map_or(true, |entity_selectors| {
+                    entity_selectors.contains(&entity_evaluation.entity_selector)
+                })
+        })
+        .flat_map(|entity_evaluation| {
+            let training_objectives = entity_evaluation
+                .tlos
+                .iter()
+                .map(|tlo| tlo.tlo_name.clone().unwrap_or_else(|| tlo.tlo_key.clone()))
+                .collect::<Vec<String>>();
+            let entity_name = entity_evaluation.entity_name.clone();
+
+            match issue_certificates.per_participant {
+                true => participant_users
+                    .iter()
+                    .filter(|(participant, _)| {
+                        participant.selector == entity_evaluation.entity_selector
+                    })
+                    .map(|(participant, user)| CertificateRecipient {
+                        entity_selector: entity_evaluation.entity_selector.clone(),
+                        entity_name: entity_name.clone(),
+                        user_id: Some(participant.user_id.clone()),
+                        name: get_user_display_name(participant, user.as_ref()),
+                        email: user.as_ref().and_then(|user| user.email.clone()),
+                        training_objectives: training_objectives.clone(),
+                    })
+                    .collect::<Vec<CertificateRecipient>>(),
+                false => vec![CertificateRecipient {
+                    entity_selector: entity_evaluation.entity_selector.clone(),
+                    entity_name: entity_name.clone(),
+                    user_id: None,
+                    name: entity_name
+                        .clone()
+                        .unwrap_or_else(|| entity_evaluation.entity_selector.clone()),
+                    email: None,
+                    training_objectives,
+                }],
+            }
+        })
+        .collect()
+}
+
+pub fn get_certificate_email_addresses(
+    certificate: &Certificate,
+    participant_users: &[ParticipantUser],
+) -> Vec<String> {
+    match (&certificate.user_id, &certificate.recipient_email) {
+        (_, Some(recipient_email)) => vec![recipient_email.clone()],
+        (Some(_), None) => vec![],
+        (None, None) => participant_users
+            .iter()
+            .filter(|(participant, _)| participant.selector == certificate.entity_selector)
+            .filter_map(|(_, user)| user.as_ref().and_then(|user| user.email.clone()))
+            .collect(),
+    }
+}
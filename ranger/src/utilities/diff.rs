@@ -0,0 +1,179 @@
+use crate::{
+    constants::MAX_DIFF_TABLE_CELLS,
+    models::{DiffLine, DiffLineKind},
+};
+
+fn create_line(kind: DiffLineKind, content: &str) -> DiffLine {
+    DiffLine {
+        kind,
+        content: content.to_owned(),
+    }
+}
+
+fn diff_changed_lines(old_lines: &[&str], new_lines: &[&str], lines: &mut Vec<DiffLine>) {
+    let table_cells = (old_lines.len() + 1).saturating_mul(new_lines.len() + 1);
+    if table_cells > MAX_DIFF_TABLE_CELLS {
+        lines.extend(
+            old_lines
+                .iter()
+                .map(|line| create_line(DiffLineKind::Removed, line)),
+        );
+        lines.extend(
+            new_lines
+                .iter()
+                .map(|line| create_line(DiffLineKind::Added, line)),
+        );
+        return;
+    }
+
+    let width = new_lines.len() + 1;
+    let mut common_lengths = vec![0u32; table_cells];
+    for old_index in (0..old_lines.len()).rev() {
+        for new_index in (0..new_lines.len()).rev() {
+            common_lengths[old_index * width + new_index] =
+                match old_lines[old_index] == new_lines[new_index] {
+                    true => common_lengths[(old_index + 1) * width + new_index + 1] + 1,
+                    false => common_lengths[(old_index + 1) * width + new_index]
+                        .max(common_lengths[old_index * width + new_index + 1]),
+                };
+        }
+    }
+
+    let (mut old_index, mut new_index) = (0, 0);
+    while old_index < old_lines.len() && new_index < new_lines.len() {
+        if old_lines[old_index] == new_lines[new_index] {
+            lines.push(create_line(DiffLineKind::Unchanged, old_lines[old_index]));
+            old_index += 1;
+            new_index += 1;
+        } else if common_lengths[(old_index + 1) * width + new_index]
+            >= common_lengths[old_index * width + new_index + 1]
+        {
+            lines.push(create_line(DiffLineKind::Removed, old_lines[old_index]));
+            old_index += 1;
+        } else {
+            lines.push(create_line(DiffLineKind::Added, new_lines[new_index]));
+            new_index += 1;
+        }
+    }
+    lines.extend(
+        old_lines[old_index..]
+            .iter()
+            .map(|line| create_line(DiffLineKind::Removed, line)),
+    );
+    lines.extend(
+        new_lines[new_index..]
+            .iter()
+            .map(|line| create_line(DiffLineKind::Added, line)),
+    );
+}
+
+/// Line based diff of two submissions. The common prefix and suffix are
+/// matched directly and only the changed middle is compared line by line,
+/// falling back to a plain removal and addition when that comparison would
+/// exceed `MAX_DIFF_TABLE_CELLS`.
+pub fn diff_lines(old_text: &str, new_text: &str) -> Vec<DiffLine> {
+    let old_lines = old_text.lines().collect::<Vec<&str>>();
+    let new_lines = new_text.lines().collect::<Vec<&str>>();
+
+    let prefix_length = old_lines
+        .iter()
+        .zip(new_lines.iter())
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+    let suffix_length = old_lines[prefix_length..]
+        .iter()
+        .rev()
+        .zip(new_lines[prefix_length..].iter().rev())
+        .take_while(|(old_line, new_line)| old_line == new_line)
+        .count();
+
+    let mut lines = Vec::new();
+    lines.extend(
+        old_lines[..prefix_length]
+            .iter()
+            .map(|line| create_line(DiffLineKind::Unchanged, line)),
+    );
+    diff_changed_lines(
+        &old_lines[prefix_length..old_lines.len() - suffix_length],
+        &new_lines[prefix_length..new_lines.len() - suffix_length],
+        &mut lines,
+    );
+    lines.extend(
+        old_lines[old_lines.len() - suffix_length..]
+            .iter()
+            .map(|line| create_line(DiffLineKind::Unchanged, line)),
+    );
+
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::diff_lines;
+    use crate::models::DiffLineKind;
+
+    fn kinds(old_text: &str, new_text: &str) -> Vec<(DiffLineKind, String)> {
+        diff_lines(old_text, new_text)
+            .into_iter()
+            .map(|line| (line.kind, line.content))
+            .collect()
+    }
+
+    #[test]
+    fn identical_texts_are_unchanged() {
+        assert_eq!(
+            kinds("a\nb", "a\nb"),
+            vec![
+                (DiffLineKind::Unchanged, "a".to_string()),
+                (DiffLineKind::Unchanged, "b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_added_and_removed_lines() {
+        assert_eq!(
+            kinds("a\nb\nc\nd", "a\nx\nc\nd\ne"),
+            vec![
+                (DiffLineKind::Unchanged, "a".to_string()),
+                (DiffLineKind::Removed, "b".to_string()),
+                (DiffLineKind::Added, "x".to_string()),
+                (DiffLineKind::Unchanged, "c".to_string()),
+                (DiffLineKind::Unchanged, "d".to_string()),
+                (DiffLineKind::Added, "e".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn keeps_common_lines_inside_the_changed_region() {
+        assert_eq!(
+            kinds("a\nb\nc", "b\nc\na"),
+            vec![
+                (DiffLineKind::Removed, "a".to_string()),
+                (DiffLineKind::Unchanged, "b".to_string()),
+                (DiffLineKind::Unchanged, "c".to_string()),
+                (DiffLineKind::Added, "a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn large_inputs_fall_back_to_removal_and_addition() {
+        let old_text = (0..5_000)
+            .map(|index| format!("old {index}\n"))
+            .collect::<String>();
+        let new_text = (0..5_000)
+            .map(|index| format!("new {index}\n"))
+            .collect::<String>();
+        let lines = diff_lines(&old_text, &new_text);
+
+        assert_eq!(lines.len(), 10_000);
+        assert!(lines[..5_000]
+            .iter()
+            .all(|line| line.kind == DiffLineKind::Removed));
+        assert!(lines[5_000..]
+            .iter()
+            .all(|line| line.kind == DiffLineKind::Added));
+    }
+}
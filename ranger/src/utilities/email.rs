@@ -0,0 +1,78 @@
+use crate::{
+    configuration::MailerConfiguration,
+    errors::RangerError,
+    models::{helpers::uuid::Uuid, Email, EmailResource, NewEmail, NewEmailStatus},
+    services::{
+        database::{email::CreateEmail, email_status::CreateEmailStatus, Database},
+        mailer::Mailer,
+    },
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+};
+use actix::Addr;
+use log::error;
+
+pub async fn send_and_record_email(
+    database_address: &Addr<Database>,
+    mailer_configuration: MailerConfiguration,
+    exercise_id: Uuid,
+    email_resource: EmailResource,
+) -> Result<Email, RangerError> {
+    let mailer = Mailer::new(mailer_configuration.clone());
+    let new_email = NewEmail::new(
+        email_resource.clone(),
+        mailer_configuration.from_address.clone(),
+        exercise_id,
+    );
+    let email_status_pending = NewEmailStatus::new_pending(new_email.id);
+
+    let email = database_address
+        .send(CreateEmail(new_email.clone()))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create email"))?;
+
+    database_address
+        .send(CreateEmailStatus(email_status_pending))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Create email status"))?;
+
+    let message = match email_resource.create_message(mailer_configuration.from_address) {
+        Ok(message) => message,
+        Err(error) => {
+            error!("Failed to create message: {error}");
+            let email_status_error = NewEmailStatus::new_failed(new_email.id, error.to_string());
+
+            database_address
+                .send(CreateEmailStatus(email_status_error))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Create email status"))?;
+
+            return Err(RangerError::EmailMessageCreationFailed);
+        }
+    };
+
+    match mailer.send_message(message) {
+        Ok(response) => {
+            let email_status_sent = NewEmailStatus::new_sent(new_email.id, response.first_line());
+            database_address
+                .send(CreateEmailStatus(email_status_sent))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Create email status"))?;
+        }
+        Err(error) => {
+            error!("Failed to send email: {error}");
+            let email_status_error = NewEmailStatus::new_failed(new_email.id, error.to_string());
+
+            database_address
+                .send(CreateEmailStatus(email_status_error))
+                .await
+                .map_err(create_mailbox_error_handler("Database"))?
+                .map_err(create_database_error_handler("Create email status"))?;
+        }
+    }
+
+    Ok(email)
+}
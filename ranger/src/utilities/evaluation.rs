@@ -0,0 +1,133 @@
+use super::score::get_latest_scores_by_entity;
+use crate::{
+    models::{
+        Deployment, DeploymentEvaluation, EntityEvaluation, GoalEvaluation, Score, TloEvaluation,
+    },
+    services::database::{score::GetScoresByDeploymentId, Database},
+};
+use actix::Addr;
+use anyhow::{anyhow, Result};
+use bigdecimal::BigDecimal;
+use sdl_parser::{evaluation::Evaluation, parse_sdl, Scenario};
+
+pub fn get_evaluation_min_score(evaluation: &Evaluation, max_score: &BigDecimal) -> BigDecimal {
+    match (
+        evaluation.min_score.percentage,
+        evaluation.min_score.absolute,
+    ) {
+        (Some(percentage), _) => BigDecimal::from(percentage) * max_score / BigDecimal::from(100),
+        (None, Some(absolute)) => BigDecimal::from(absolute),
+        (None, None) => BigDecimal::from(0),
+    }
+}
+
+pub fn evaluate_tlo(
+    scenario: &Scenario,
+    tlo_key: &str,
+    entity_scores: &[&Score],
+) -> Option<TloEvaluation> {
+    let tlo = scenario.tlos.as_ref()?.get(tlo_key)?;
+    let evaluation = scenario.evaluations.as_ref()?.get(&tlo.evaluation)?;
+
+    let max_score = evaluation
+        .metrics
+        .iter()
+        .filter_map(|metric_key| scenario.metrics.as_ref()?.get(metric_key))
+        .fold(BigDecimal::from(0), |sum, metric| {
+            sum + BigDecimal::from(metric.max_score)
+        });
+    let score = entity_scores
+        .iter()
+        .filter(|score| evaluation.metrics.contains(&score.metric_key))
+        .fold(BigDecimal::from(0), |sum, score| sum + &score.value);
+    let min_score = get_evaluation_min_score(evaluation, &max_score);
+    let passed = score >= min_score;
+
+    Some(TloEvaluation {
+        tlo_key: tlo_key.to_owned(),
+        tlo_name: tlo.name.clone(),
+        evaluation_key: tlo.evaluation.clone(),
+        evaluation_name: evaluation.name.clone(),
+        metric_keys: evaluation.metrics.clone(),
+        score,
+        max_score,
+        min_score,
+        passed,
+    })
+}
+
+pub fn calculate_entity_evaluations(
+    scenario: &Scenario,
+    scores: &[Score],
+) -> Vec<EntityEvaluation> {
+    let mut entity_evaluations = get_latest_scores_by_entity(scenario, scores)
+        .into_iter()
+        .filter_map(|(entity_selector, entity, entity_scores)| {
+            let tlo_keys = entity.tlos.clone().unwrap_or_default();
+            if tlo_keys.is_empty() {
+                return None;
+            }
+
+            let tlos = tlo_keys
+                .iter()
+                .filter_map(|tlo_key| evaluate_tlo(scenario, tlo_key, &entity_scores))
+                .collect::<Vec<TloEvaluation>>();
+
+            let mut goals = scenario
+                .goals
+                .iter()
+                .flatten()
+                .filter(|(_, goal)| goal.tlos.iter().any(|tlo_key| tlo_keys.contains(tlo_key)))
+                .map(|(goal_key, goal)| GoalEvaluation {
+                    goal_key: goal_key.to_owned(),
+                    goal_name: goal.name.clone(),
+                    tlo_keys: goal.tlos.clone(),
+                    passed: goal.tlos.iter().all(|tlo_key| {
+                        evaluate_tlo(scenario, tlo_key, &entity_scores)
+                            .is_some_and(|tlo_evaluation| tlo_evaluation.passed)
+                    }),
+                })
+                .collect::<Vec<GoalEvaluation>>();
+            goals.sort_by(|goal, other_goal| goal.goal_key.cmp(&other_goal.goal_key));
+
+            Some(EntityEvaluation::new(
+                entity_selector,
+                entity.name,
+                entity.role,
+                tlos,
+                goals,
+            ))
+        })
+        .collect::<Vec<EntityEvaluation>>();
+    entity_evaluations.sort_by(|entity_evaluation, other_entity_evaluation| {
+        entity_evaluation
+            .entity_selector
+            .cmp(&other_entity_evaluation.entity_selector)
+    });
+
+    entity_evaluations
+}
+
+pub fn calculate_deployment_evaluation(
+    deployment: &Deployment,
+    scores: &[Score],
+) -> Result<DeploymentEvaluation> {
+    let scenario = parse_sdl(&deployment.sdl_schema)
+        .map_err(|error| anyhow!("Failed to parse sdl: {error}"))?;
+
+    Ok(DeploymentEvaluation {
+        deployment_id: deployment.id,
+        entities: calculate_entity_evaluations(&scenario, scores),
+    })
+}
+
+pub async fn get_deployment_evaluation(
+    database_address: &Addr<Database>,
+    deployment: &Deployment,
+) -> Result<DeploymentEvaluation> {
+    let scores = database_address
+        .send(GetScoresByDeploymentId(deployment.id))
+        .await??;
+
+    calculate_deployment_evaluation(deployment, &scores)
+}
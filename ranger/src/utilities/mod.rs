@@ -1,5 +1,13 @@
+pub mod analytics;
+pub mod certificate;
+pub mod diff;
+pub mod email;
+pub mod evaluation;
 pub mod event;
+pub mod report;
 pub mod scenario;
+pub mod score;
+pub mod submission;
 mod validation;
 
 use crate::{
@@ -19,6 +27,8 @@ use std::{collections::HashMap, path::Path, io::Write};
 use actix_multipart::Multipart;
 use actix_web::web;
 use futures::{StreamExt, TryStreamExt};
+use serde::Serialize;
+use serde_json::Value as JsonValue;
 
 pub fn create_mailbox_error_handler(actor_name: &str) -> impl Fn(MailboxError) -> RangerError + '_ {
     move |err| {
@@ -113,3 +123,103 @@ pub async fn save_file(mut payload: Multipart, file_path: &Path) -> Result<()> {
 
     Ok(())
 }
+
+fn escape_csv_field(field: &str) -> String {
+    let field = match field.starts_with(['=', '+', '-', '@', '\t', '\r'])
+        && !field.parse::<f64>().is_ok_and(f64::is_finite)
+    {
+        true => format!("'{field}"),
+        false => field.to_owned(),
+    };
+    if field.contains([',', '"', '\n', '\r']) {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+    field
+}
+
+pub fn serialize_to_csv<T: Serialize>(records: &[T]) -> Result<String> {
+    let rows = records
+        .iter()
+        .map(|record| match serde_json::to_value(record)? {
+            JsonValue::Object(row) => Ok(row),
+            _ => Err(anyhow!("CSV record must serialize to an object")),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let headers = match rows.first() {
+        Some(row) => row.keys().cloned().collect::<Vec<String>>(),
+        None => return Ok(String::new()),
+    };
+
+    let mut csv = headers
+        .iter()
+        .map(|header| escape_csv_field(header))
+        .collect::<Vec<String>>()
+        .join(",");
+    csv.push('\n');
+    for row in rows {
+        let fields = headers
+            .iter()
+            .map(|header| match row.get(header) {
+                None | Some(JsonValue::Null) => String::new(),
+                Some(JsonValue::String(value)) => escape_csv_field(value),
+                Some(value) => escape_csv_field(&value.to_string()),
+            })
+            .collect::<Vec<String>>();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    Ok(csv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::serialize_to_csv;
+    use serde_json::json;
+
+    #[test]
+    fn serializes_records_with_headers() {
+        let records = vec![
+            json!({ "name": "alpha", "score": 10, "reason": null }),
+            json!({ "name": "beta", "score": -2.5, "reason": "late" }),
+        ];
+
+        assert_eq!(
+            serialize_to_csv(&records).unwrap(),
+            "name,reason,score\nalpha,,10\nbeta,late,-2.5\n"
+        );
+    }
+
+    #[test]
+    fn quotes_fields_with_separators() {
+        let records = vec![json!({ "reason": "said \"hi\", twice\nagain" })];
+
+        assert_eq!(
+            serialize_to_csv(&records).unwrap(),
+            "reason\n\"said \"\"hi\"\", twice\nagain\"\n"
+        );
+    }
+
+    #[test]
+    fn neutralizes_formula_cells() {
+        let records = vec![
+            json!({ "cell": "=HYPERLINK(\"http://example.com\")" }),
+            json!({ "cell": "+1+1" }),
+            json!({ "cell": "-1+1" }),
+            json!({ "cell": "@SUM(A1)" }),
+            json!({ "cell": "-3.5" }),
+        ];
+
+        assert_eq!(
+            serialize_to_csv(&records).unwrap(),
+            "cell\n\"'=HYPERLINK(\"\"http://example.com\"\")\"\n'+1+1\n'-1+1\n'@SUM(A1)\n-3.5\n"
+        );
+    }
+
+    #[test]
+    fn empty_records_serialize_to_nothing() {
+        let records: Vec<serde_json::Value> = vec![];
+
+        assert_eq!(serialize_to_csv(&records).unwrap(), "");
+    }
+}
@@ -0,0 +1,117 @@
+use crate::{
+    configuration::PdfRendererConfiguration,
+    constants::PDF_RENDERER_TIMEOUT,
+    errors::RangerError,
+    models::{AfterActionReport, Deployment, Exercise},
+    services::database::{
+        deployment::GetDeploymentElementByDeploymentId, event::GetEventsByDeploymentId,
+        metric::GetMetrics, metric_grading::GetMetricGradingsByDeploymentId,
+        score::GetScoresByDeploymentId, Database,
+    },
+    utilities::{
+        create_database_error_handler, create_mailbox_error_handler,
+        evaluation::calculate_entity_evaluations, score::calculate_entity_scores,
+    },
+};
+use actix::Addr;
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use log::error;
+use sdl_parser::parse_sdl;
+use std::process::Stdio;
+use tokio::{io::AsyncWriteExt, process::Command, time::timeout, try_join};
+
+pub async fn create_after_action_report(
+    database_address: &Addr<Database>,
+    exercise: &Exercise,
+    deployment: &Deployment,
+) -> Result<AfterActionReport, RangerError> {
+    let scenario = parse_sdl(&deployment.sdl_schema).map_err(|error| {
+        error!("Failed to parse sdl: {error}");
+        RangerError::ScenarioParsingFailed
+    })?;
+    let events = database_address
+        .send(GetEventsByDeploymentId(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get events"))?;
+    let deployment_elements = database_address
+        .send(GetDeploymentElementByDeploymentId(deployment.id, false))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get deployment elements"))?;
+    let scores = database_address
+        .send(GetScoresByDeploymentId(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get scores"))?;
+    let metrics = database_address
+        .send(GetMetrics(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metrics"))?;
+    let metric_gradings = database_address
+        .send(GetMetricGradingsByDeploymentId(deployment.id))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get metric gradings"))?;
+
+    Ok(AfterActionReport::new(
+        exercise,
+        deployment,
+        events,
+        &deployment_elements,
+        calculate_entity_scores(&scenario, &scores),
+        calculate_entity_evaluations(&scenario, &scores),
+        &metrics,
+        &metric_gradings,
+        Utc::now().naive_utc(),
+    ))
+}
+
+pub async fn render_pdf(
+    pdf_renderer: &PdfRendererConfiguration,
+    html: String,
+) -> Result<Vec<u8>, RangerError> {
+    let render = async {
+        let mut renderer_process = Command::new(&pdf_renderer.command)
+            .args(&pdf_renderer.arguments)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()?;
+        let mut stdin = renderer_process
+            .stdin
+            .take()
+            .ok_or_else(|| anyhow!("PDF renderer stdin is not available"))?;
+        let write_html = async move {
+            stdin.write_all(html.as_bytes()).await?;
+            drop(stdin);
+            Ok::<_, anyhow::Error>(())
+        };
+        let (_, output) = try_join!(write_html, async {
+            Ok::<_, anyhow::Error>(renderer_process.wait_with_output().await?)
+        })?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "PDF renderer exited with {}: {}",
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            ));
+        }
+
+        Ok::<_, anyhow::Error>(output.stdout)
+    };
+
+    timeout(PDF_RENDERER_TIMEOUT, render)
+        .await
+        .map_err(|_| {
+            error!("PDF renderer timed out after {PDF_RENDERER_TIMEOUT:?}");
+            RangerError::ReportGenerationFailed
+        })?
+        .map_err(|error| {
+            error!("Failed to render PDF: {error}");
+            RangerError::ReportGenerationFailed
+        })
+}
@@ -0,0 +1,442 @@
+use crate::{
+    models::{
+        helpers::uuid::Uuid, ConditionMessage, ConditionScoringHistory, Deployment,
+        DeploymentElement, EntityScore, EntityScoreHistory, Metric, MetricScoreHistory, NewScore,
+        Penalty, Score, ScoreHistoryPoint, ScoringStrategy,
+    },
+    services::database::{
+        condition::GetConditionMessagesByDeploymentId,
+        deployment::{GetDeploymentElementByDeploymentId, GetDeployments},
+        metric::GetMetrics,
+        penalty::GetPenalties,
+        score::{
+            GetLatestScoresByDeploymentId, GetUnprojectedDeployments, ReplaceDeploymentScores,
+        },
+        scoring_strategy::GetScoringStrategies,
+        Database,
+    },
+};
+use actix::Addr;
+use anyhow::{anyhow, Result};
+use bigdecimal::BigDecimal;
+use chrono::{Duration, NaiveDateTime};
+use log::{error, info};
+use ranger_grpc::capabilities::DeployerType as GrpcDeployerType;
+use sdl_parser::{
+    entity::{Entity, Flatten},
+    metric::Metrics,
+    node::NodeType,
+    parse_sdl, Scenario,
+};
+use std::collections::{BTreeMap, HashMap};
+
+use super::try_some;
+
+pub fn calculate_deployment_scores(
+    deployment: &Deployment,
+    scenario_metrics: &Option<Metrics>,
+    deployment_elements: &[DeploymentElement],
+    condition_messages: Vec<ConditionMessage>,
+    manual_metrics: Vec<Metric>,
+    penalties: Vec<Penalty>,
+    scoring_strategies: &[ScoringStrategy],
+) -> Result<Vec<NewScore>> {
+    let scenario_metrics = match scenario_metrics {
+        Some(metrics) => metrics,
+        None => return Ok(vec![]),
+    };
+
+    let vm_scenario_refs_by_id = deployment_elements
+        .iter()
+        .filter(|element| {
+            matches!(element.deployer_type.0, GrpcDeployerType::VirtualMachine)
+                && element.handler_reference.is_some()
+        })
+        .map(|element| {
+            let vm_id = try_some(
+                element.handler_reference.to_owned(),
+                "VM element missing handler reference",
+            )?;
+            Ok((vm_id, element.scenario_reference.to_owned()))
+        })
+        .collect::<Result<HashMap<String, String>>>()?;
+
+    let mut scores: Vec<NewScore> = manual_metrics.into_iter().map(Into::into).collect();
+    scores.extend(penalties.into_iter().map(NewScore::from));
+    let mut condition_histories: HashMap<(Uuid, Uuid), ConditionScoringHistory> = HashMap::new();
+    let mut first_successful_vms: HashMap<String, Uuid> = HashMap::new();
+
+    let mut condition_messages = condition_messages
+        .into_iter()
+        .filter(|condition_message| {
+            condition_message.created_at > deployment.start
+                && condition_message.created_at < deployment.end
+        })
+        .collect::<Vec<ConditionMessage>>();
+    condition_messages.sort_by_key(|condition_message| condition_message.created_at);
+
+    for condition_message in condition_messages {
+        let history = condition_histories
+            .entry((
+                condition_message.condition_id,
+                condition_message.virtual_machine_id,
+            ))
+            .or_default();
+        if ScoringStrategy::is_success(&condition_message.value) {
+            history
+                .first_success_at
+                .get_or_insert(condition_message.created_at);
+            first_successful_vms
+                .entry(condition_message.condition_name.to_owned())
+                .or_insert(condition_message.virtual_machine_id);
+        }
+        history.is_first_success_in_deployment = first_successful_vms
+            .get(&condition_message.condition_name)
+            .is_some_and(|virtual_machine_id| {
+                *virtual_machine_id == condition_message.virtual_machine_id
+            });
+
+        if let Some((metric_key, metric)) = scenario_metrics
+            .iter()
+            .find(|(_, metric)| metric.condition == Some(condition_message.condition_name.clone()))
+        {
+            if let Some(vm_name) =
+                vm_scenario_refs_by_id.get(&condition_message.virtual_machine_id.to_string())
+            {
+                let value = condition_message.value.clone();
+                let timestamp = condition_message.created_at;
+                let condition_name = condition_message.condition_name.clone();
+                let mut new_score = NewScore::from_conditionmessage_and_metric(
+                    condition_message,
+                    (metric_key.to_owned(), metric.clone()),
+                    vm_name.to_owned(),
+                );
+                if let Some(scoring_strategy) = scoring_strategies
+                    .iter()
+                    .find(|scoring_strategy| scoring_strategy.metric_key == *metric_key)
+                {
+                    new_score.value = scoring_strategy.calculate_score(
+                        metric.max_score,
+                        &value,
+                        timestamp,
+                        deployment.start,
+                        history,
+                    );
+                    new_score.reason = scoring_strategy.get_reason(&condition_name, &value);
+                }
+
+                history.previous_message = Some((timestamp, value));
+                history.previous_score = Some(new_score.value.clone());
+                scores.push(new_score);
+            }
+        }
+    }
+
+    Ok(scores)
+}
+
+pub fn get_latest_scores(scores: &[Score]) -> Vec<&Score> {
+    scores
+        .iter()
+        .fold(
+            HashMap::new(),
+            |mut latest_scores: HashMap<(&str, &str, Option<Uuid>), &Score>, score| {
+                let key = (
+                    score.metric_key.as_str(),
+                    score.vm_name.as_str(),
+                    score.metric_id,
+                );
+                match latest_scores.get(&key) {
+                    Some(latest_score) if latest_score.timestamp > score.timestamp => {}
+                    _ => {
+                        latest_scores.insert(key, score);
+                    }
+                }
+                latest_scores
+            },
+        )
+        .into_values()
+        .collect()
+}
+
+pub fn get_vm_entity_selectors(scenario: &Scenario) -> HashMap<String, Vec<String>> {
+    scenario
+        .nodes
+        .iter()
+        .flatten()
+        .filter_map(|(node_name, node)| match &node.type_field {
+            NodeType::VM(vm_node) => Some((node_name, vm_node)),
+            _ => None,
+        })
+        .map(|(node_name, vm_node)| {
+            let entity_selectors = vm_node
+                .roles
+                .iter()
+                .flatten()
+                .flat_map(|(_, role)| role.entities.clone().unwrap_or_default())
+                .collect::<Vec<String>>();
+            (node_name.to_owned(), entity_selectors)
+        })
+        .collect()
+}
+
+pub fn get_score_entity_selectors(
+    score: &Score,
+    vm_entity_selectors: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    match score.metric_id {
+        Some(_) => vec![score.vm_name.to_owned()],
+        None => vm_entity_selectors
+            .get(&score.vm_name)
+            .cloned()
+            .unwrap_or_default(),
+    }
+}
+
+pub fn is_entity_or_descendant(entity_selector: &str, parent_selector: &str) -> bool {
+    entity_selector == parent_selector
+        || entity_selector.starts_with(&format!("{parent_selector}."))
+}
+
+pub fn get_latest_scores_by_entity<'a>(
+    scenario: &Scenario,
+    scores: &'a [Score],
+) -> Vec<(String, Entity, Vec<&'a Score>)> {
+    let flattened_entities = match &scenario.entities {
+        Some(entities) => entities.flatten(),
+        None => return vec![],
+    };
+    let vm_entity_selectors = get_vm_entity_selectors(scenario);
+    let latest_scores = get_latest_scores(scores)
+        .into_iter()
+        .map(|score| {
+            (
+                score,
+                get_score_entity_selectors(score, &vm_entity_selectors),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    flattened_entities
+        .into_iter()
+        .map(|(entity_selector, entity)| {
+            let entity_scores = latest_scores
+                .iter()
+                .filter(|(_, score_entity_selectors)| {
+                    score_entity_selectors.iter().any(|score_entity_selector| {
+                        is_entity_or_descendant(score_entity_selector, &entity_selector)
+                    })
+                })
+                .map(|(score, _)| *score)
+                .collect::<Vec<&Score>>();
+
+            (entity_selector, entity, entity_scores)
+        })
+        .collect()
+}
+
+pub fn calculate_entity_scores(scenario: &Scenario, scores: &[Score]) -> Vec<EntityScore> {
+    get_latest_scores_by_entity(scenario, scores)
+        .into_iter()
+        .map(|(entity_selector, entity, entity_scores)| EntityScore {
+            score: entity_scores
+                .iter()
+                .fold(BigDecimal::from(0), |sum, score| sum + &score.value),
+            last_scored_at: entity_scores.iter().map(|score| score.timestamp).max(),
+            entity_selector,
+            entity_name: entity.name,
+            role: entity.role,
+        })
+        .collect()
+}
+
+fn sum_scores<'a, 'b: 'a>(scores: impl Iterator<Item = &'a &'b Score>) -> BigDecimal {
+    scores.fold(BigDecimal::from(0), |sum, score| sum + &score.value)
+}
+
+pub fn calculate_entity_score_history(
+    scenario: &Scenario,
+    scores: &[Score],
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    resolution_seconds: i64,
+) -> Vec<EntityScoreHistory> {
+    let mut sorted_scores = scores.to_vec();
+    sorted_scores.sort_by_key(|score| score.timestamp);
+
+    let mut entity_histories: BTreeMap<String, EntityScoreHistory> =
+        get_latest_scores_by_entity(scenario, &sorted_scores)
+            .into_iter()
+            .map(|(entity_selector, entity, entity_scores)| {
+                let metric_names = entity_scores
+                    .iter()
+                    .map(|score| (score.metric_key.to_owned(), score.metric_name.to_owned()))
+                    .collect::<BTreeMap<String, Option<String>>>();
+
+                (
+                    entity_selector.to_owned(),
+                    EntityScoreHistory {
+                        entity_selector,
+                        entity_name: entity.name,
+                        role: entity.role,
+                        points: vec![],
+                        metrics: metric_names
+                            .into_iter()
+                            .map(|(metric_key, metric_name)| MetricScoreHistory {
+                                metric_key,
+                                metric_name,
+                                points: vec![],
+                            })
+                            .collect(),
+                    },
+                )
+            })
+            .collect();
+
+    let mut timestamps = vec![];
+    let mut timestamp = start;
+    while timestamp < end {
+        timestamps.push(timestamp);
+        timestamp += Duration::seconds(resolution_seconds);
+    }
+    timestamps.push(end);
+
+    let vm_entity_selectors = get_vm_entity_selectors(scenario);
+    let mut latest_scores: HashMap<(&str, &str, Option<Uuid>), (&Score, Vec<String>)> =
+        HashMap::new();
+    let mut next_score_index = 0;
+    for timestamp in timestamps {
+        while let Some(score) = sorted_scores
+            .get(next_score_index)
+            .filter(|score| score.timestamp <= timestamp)
+        {
+            latest_scores.insert(
+                (
+                    score.metric_key.as_str(),
+                    score.vm_name.as_str(),
+                    score.metric_id,
+                ),
+                (
+                    score,
+                    get_score_entity_selectors(score, &vm_entity_selectors),
+                ),
+            );
+            next_score_index += 1;
+        }
+
+        for entity_history in entity_histories.values_mut() {
+            let entity_scores = latest_scores
+                .values()
+                .filter(|(_, score_entity_selectors)| {
+                    score_entity_selectors.iter().any(|score_entity_selector| {
+                        is_entity_or_descendant(
+                            score_entity_selector,
+                            &entity_history.entity_selector,
+                        )
+                    })
+                })
+                .map(|(score, _)| *score)
+                .collect::<Vec<&Score>>();
+
+            entity_history.points.push(ScoreHistoryPoint {
+                timestamp,
+                score: sum_scores(entity_scores.iter()),
+            });
+            for metric_history in entity_history.metrics.iter_mut() {
+                metric_history.points.push(ScoreHistoryPoint {
+                    timestamp,
+                    score: sum_scores(
+                        entity_scores
+                            .iter()
+                            .filter(|score| score.metric_key == metric_history.metric_key),
+                    ),
+                });
+            }
+        }
+    }
+
+    entity_histories.into_values().collect()
+}
+
+pub async fn get_deployment_entity_scores(
+    database_address: &Addr<Database>,
+    deployment: &Deployment,
+) -> Result<Vec<EntityScore>> {
+    let scenario = parse_sdl(&deployment.sdl_schema)
+        .map_err(|error| anyhow!("Failed to parse sdl: {error}"))?;
+    let latest_scores = database_address
+        .send(GetLatestScoresByDeploymentId(deployment.id))
+        .await??;
+
+    Ok(calculate_entity_scores(&scenario, &latest_scores))
+}
+
+pub async fn rebuild_deployment_scores(
+    database_address: &Addr<Database>,
+    deployment: &Deployment,
+) -> Result<Vec<Score>> {
+    let scenario = parse_sdl(&deployment.sdl_schema)
+        .map_err(|error| anyhow!("Failed to parse sdl: {error}"))?;
+    let condition_messages = database_address
+        .send(GetConditionMessagesByDeploymentId(deployment.id))
+        .await??;
+    let deployment_elements = database_address
+        .send(GetDeploymentElementByDeploymentId(deployment.id, false))
+        .await??;
+    let manual_metrics = database_address.send(GetMetrics(deployment.id)).await??;
+    let penalties = database_address.send(GetPenalties(deployment.id)).await??;
+    let scoring_strategies = database_address
+        .send(GetScoringStrategies(deployment.exercise_id))
+        .await??;
+
+    let new_scores = calculate_deployment_scores(
+        deployment,
+        &scenario.metrics,
+        &deployment_elements,
+        condition_messages,
+        manual_metrics,
+        penalties,
+        &scoring_strategies,
+    )?;
+    let scores = database_address
+        .send(ReplaceDeploymentScores(deployment.id, new_scores))
+        .await??;
+    info!(
+        "Rebuilt {} scores for deployment {:?}",
+        scores.len(),
+        deployment.id
+    );
+
+    Ok(scores)
+}
+
+pub async fn rebuild_exercise_scores(
+    database_address: &Addr<Database>,
+    exercise_id: Uuid,
+) -> Result<()> {
+    let deployments = database_address.send(GetDeployments(exercise_id)).await??;
+    for deployment in deployments {
+        if let Err(error) = rebuild_deployment_scores(database_address, &deployment).await {
+            error!(
+                "Failed to rebuild scores for deployment {:?}: {error}",
+                deployment.id
+            );
+        }
+    }
+
+    Ok(())
+}
+
+pub async fn backfill_score_projection(database_address: &Addr<Database>) -> Result<()> {
+    let deployments = database_address.send(GetUnprojectedDeployments).await??;
+    for deployment in deployments {
+        if let Err(error) = rebuild_deployment_scores(database_address, &deployment).await {
+            error!(
+                "Failed to backfill scores for deployment {:?}: {error}",
+                deployment.id
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use crate::{
+    errors::RangerError,
+    models::{Deployment, Exercise, Metric, SubmissionPeriod},
+    services::database::{submission_window::GetSubmissionPeriods, Database},
+    utilities::{create_database_error_handler, create_mailbox_error_handler},
+};
+use actix::Addr;
+use chrono::Utc;
+
+pub async fn get_submission_period(
+    database_address: &Addr<Database>,
+    deployment: Deployment,
+    metric_key: String,
+) -> Result<SubmissionPeriod, RangerError> {
+    database_address
+        .send(GetSubmissionPeriods(deployment, vec![metric_key]))
+        .await
+        .map_err(create_mailbox_error_handler("Database"))?
+        .map_err(create_database_error_handler("Get submission period"))?
+        .pop()
+        .ok_or(RangerError::DatabaseRecordNotFound)
+}
+
+pub fn validate_submission(
+    metric: Option<&Metric>,
+    submission_period: &SubmissionPeriod,
+    exercise: &Exercise,
+) -> Result<(), RangerError> {
+    let now = Utc::now().naive_utc();
+    if !submission_period.has_opened(now) {
+        return Err(RangerError::SubmissionWindowNotOpen);
+    }
+    if submission_period.has_closed(now) {
+        let has_submission =
+            metric.is_some_and(|metric| metric.text_submission.is_some() || metric.has_artifact);
+        return match has_submission {
+            true if exercise.allow_late_resubmission => Ok(()),
+            true => Err(RangerError::ResubmissionNotAllowed),
+            false => Err(RangerError::SubmissionWindowClosed),
+        };
+    }
+
+    Ok(())
+}